@@ -0,0 +1,170 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Compares allocation-tracking strategies under multithreaded bounds checks.
+//!
+//! Run with `cargo bench --bench allocation_tracking`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use safe_lang::core::memory::safe;
+use safe_lang::core::memory::tracking::AllocationTable;
+
+const THREADS: usize = 8;
+const LOOKUPS_PER_THREAD: usize = 200_000;
+const BUFFER_LEN: usize = 4096;
+
+/// Previous tracking design: one process-wide mutex around the map.
+struct GlobalMutexTable(Mutex<HashMap<usize, usize>>);
+
+impl GlobalMutexTable {
+    fn get(&self, addr: usize) -> Option<usize> {
+        self.0.lock().expect("lock").get(&addr).copied()
+    }
+}
+
+/// Sharded map without the per-thread lookup cache.
+struct ShardedOnlyTable(Vec<RwLock<HashMap<usize, usize>>>);
+
+impl ShardedOnlyTable {
+    fn new(shards: usize) -> Self {
+        Self((0..shards).map(|_| RwLock::new(HashMap::new())).collect())
+    }
+
+    fn shard(&self, addr: usize) -> &RwLock<HashMap<usize, usize>> {
+        &self.0[((addr >> 4) ^ (addr >> 10)) % self.0.len()]
+    }
+
+    fn insert(&self, addr: usize, size: usize) {
+        self.shard(addr).write().expect("lock").insert(addr, size);
+    }
+
+    fn get(&self, addr: usize) -> Option<usize> {
+        self.shard(addr).read().expect("lock").get(&addr).copied()
+    }
+}
+
+fn run_threads<F>(work: F) -> Duration
+where
+    F: Fn(usize) + Send + Sync + 'static,
+{
+    let work = Arc::new(work);
+    let start = Instant::now();
+    let handles = (0..THREADS)
+        .map(|id| {
+            let work = Arc::clone(&work);
+            thread::spawn(move || work(id))
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().expect("bench thread panicked");
+    }
+    start.elapsed()
+}
+
+fn addresses(shared: bool) -> Vec<usize> {
+    (0..THREADS)
+        .map(|i| {
+            if shared {
+                0x10_0000
+            } else {
+                0x10_0000 + i * BUFFER_LEN
+            }
+        })
+        .collect()
+}
+
+fn bench_global_mutex(shared: bool) -> Duration {
+    let addrs = addresses(shared);
+    let table = Arc::new(GlobalMutexTable(Mutex::new(
+        addrs.iter().map(|a| (*a, BUFFER_LEN)).collect(),
+    )));
+    run_threads(move |id| {
+        let addr = addrs[id];
+        for offset in 0..LOOKUPS_PER_THREAD {
+            let size = table.get(addr).expect("tracked");
+            black_box(offset % BUFFER_LEN < size);
+        }
+    })
+}
+
+fn bench_sharded_only(shared: bool) -> Duration {
+    let addrs = addresses(shared);
+    let table = Arc::new(ShardedOnlyTable::new(64));
+    for addr in &addrs {
+        table.insert(*addr, BUFFER_LEN);
+    }
+    run_threads(move |id| {
+        let addr = addrs[id];
+        for offset in 0..LOOKUPS_PER_THREAD {
+            let size = table.get(addr).expect("tracked");
+            black_box(offset % BUFFER_LEN < size);
+        }
+    })
+}
+
+fn bench_allocation_table(shared: bool) -> Duration {
+    let addrs = addresses(shared);
+    let table = Arc::new(AllocationTable::new());
+    for addr in &addrs {
+        table.insert(*addr, BUFFER_LEN);
+    }
+    run_threads(move |id| {
+        let addr = addrs[id];
+        for offset in 0..LOOKUPS_PER_THREAD {
+            let size = table.get(addr).expect("tracked");
+            black_box(offset % BUFFER_LEN < size);
+        }
+    })
+}
+
+fn bench_runtime_read_byte() -> Duration {
+    run_threads(|_| {
+        let buf = safe::allocate_buffer(BUFFER_LEN);
+        for offset in 0..LOOKUPS_PER_THREAD {
+            black_box(safe::read_u8(buf, offset % BUFFER_LEN));
+        }
+        safe::deallocate_buffer(buf);
+    })
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let total = (THREADS * LOOKUPS_PER_THREAD) as f64;
+    println!(
+        "{name:<32} {:>10.2} ms {:>8.1} ns/lookup",
+        elapsed.as_secs_f64() * 1e3,
+        elapsed.as_nanos() as f64 / total
+    );
+}
+
+fn main() {
+    println!("threads={THREADS} lookups/thread={LOOKUPS_PER_THREAD}");
+    for shared in [false, true] {
+        let case = if shared {
+            "shared buffer"
+        } else {
+            "own buffers"
+        };
+        report(
+            &format!("global mutex ({case})"),
+            bench_global_mutex(shared),
+        );
+        report(
+            &format!("sharded only ({case})"),
+            bench_sharded_only(shared),
+        );
+        report(
+            &format!("AllocationTable ({case})"),
+            bench_allocation_table(shared),
+        );
+    }
+    report("runtime read_u8 (own buffers)", bench_runtime_read_byte());
+}
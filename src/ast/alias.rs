@@ -14,8 +14,39 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::Attribute;
+
+/// `alias name = target`. Names starting with an uppercase letter (after any
+/// namespace) alias types; all others alias functions. Type aliases may take
+/// generic parameters: `alias Res<T> = core::types::Result<T, i32>`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Alias {
     pub name: String,
     pub target: String,
+    /// Generic parameters of a type alias.
+    #[serde(default)]
+    pub params: Vec<String>,
+    /// Source line of the definition, 0 when unknown.
+    #[serde(default)]
+    pub line: usize,
+    /// File the alias was read from, relative to the project root. Set by
+    /// the CLI after parsing; used in diagnostics.
+    #[serde(default)]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl Alias {
+    pub fn is_type(&self) -> bool {
+        is_type_name(&self.name)
+    }
+}
+
+/// Whether the last `::` segment of `name` starts with an uppercase letter.
+pub fn is_type_name(name: &str) -> bool {
+    name.rsplit("::")
+        .next()
+        .and_then(|segment| segment.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
 }
@@ -0,0 +1,19 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use serde::{Deserialize, Serialize};
+
+/// `#[name]` or `#[name(arg, ...)]` before an item or a statement. Arguments
+/// are identifiers, integers or string literals, kept as their text. Molding
+/// checks them against the registry of known attributes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    pub name: String,
+    pub args: Vec<String>,
+    /// Source line of the `#`.
+    pub line: usize,
+}
@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{Expression, Type};
+use super::{Attribute, Expression, Pattern, Type};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Block {
@@ -25,6 +25,7 @@ pub struct Block {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Statement {
     Let(LetStatement),
+    LetTuple(LetTupleStatement),
     Const(ConstStatement),
     If(IfStatement),
     For(ForStatement),
@@ -33,11 +34,44 @@ pub enum Statement {
     Expr(Expression),
 }
 
+impl Statement {
+    /// Attributes written before the statement; always empty for `break`,
+    /// `continue` and expression statements.
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            Statement::Let(stmt) => &stmt.attributes,
+            Statement::LetTuple(stmt) => &stmt.attributes,
+            Statement::Const(stmt) => &stmt.attributes,
+            Statement::If(stmt) => &stmt.attributes,
+            Statement::For(stmt) => &stmt.attributes,
+            Statement::Break | Statement::Continue | Statement::Expr(_) => &[],
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LetStatement {
     pub name: String,
     pub ty: Option<Type>,
     pub value: Expression,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+    /// Source line of the keyword, 0 when unknown.
+    #[serde(default)]
+    pub line: usize,
+}
+
+/// `let (a, b, ...) = value`, binding each element of a tuple.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LetTupleStatement {
+    pub names: Vec<String>,
+    pub ty: Option<Type>,
+    pub value: Expression,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+    /// Source line of the keyword, 0 when unknown.
+    #[serde(default)]
+    pub line: usize,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -45,13 +79,24 @@ pub struct ConstStatement {
     pub name: String,
     pub ty: Option<Type>,
     pub value: Expression,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+    /// Source line of the keyword, 0 when unknown.
+    #[serde(default)]
+    pub line: usize,
 }
 
+/// `if cond { ... }`, or `if let pattern = condition { ... }` when
+/// `pattern` is set.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IfStatement {
+    #[serde(default)]
+    pub pattern: Option<Pattern>,
     pub condition: Expression,
     pub then_block: Block,
     pub else_block: Option<Block>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -61,4 +106,6 @@ pub struct ForStatement {
     pub end: Expression,
     pub inclusive: bool,
     pub body: Block,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
 }
@@ -0,0 +1,181 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use serde::{Deserialize, Serialize};
+
+use super::{Block, Expression, Pattern, Statement, Type};
+
+/// `|a, b: T| body`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Closure {
+    pub params: Vec<ClosureParam>,
+    pub body: Box<Expression>,
+}
+
+/// A closure parameter; without a type it is inferred from the context.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClosureParam {
+    pub name: String,
+    pub ty: Option<Type>,
+}
+
+/// How a closure holds a name from its surroundings. Ordered so that the
+/// strongest use of a name decides its mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaptureMode {
+    /// Every use is `&name` or a call `name(...)`.
+    Ref,
+    /// The value is moved into the closure, or copied if it is `Copy`.
+    Move,
+    /// Some use is `&mut name`.
+    RefMut,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture {
+    pub name: String,
+    pub mode: CaptureMode,
+    /// Some use is outside the `unsafe` blocks of the body.
+    pub outside_unsafe: bool,
+}
+
+impl Closure {
+    /// Names the body uses without binding them, in order of first use.
+    /// Function names are included; callers keep the ones that are local
+    /// bindings.
+    pub fn captures(&self) -> Vec<Capture> {
+        let mut walker = CaptureWalker {
+            scopes: vec![self.params.iter().map(|param| param.name.clone()).collect()],
+            found: Vec::new(),
+            in_unsafe: false,
+        };
+        walker.expr(&self.body);
+        walker.found
+    }
+}
+
+struct CaptureWalker {
+    scopes: Vec<Vec<String>>,
+    found: Vec<Capture>,
+    in_unsafe: bool,
+}
+
+impl CaptureWalker {
+    fn use_name(&mut self, name: &str, mode: CaptureMode) {
+        self.use_name_in(name, mode, !self.in_unsafe);
+    }
+
+    fn use_name_in(&mut self, name: &str, mode: CaptureMode, outside_unsafe: bool) {
+        if self.scopes.iter().flatten().any(|bound| bound == name) {
+            return;
+        }
+        match self.found.iter_mut().find(|capture| capture.name == name) {
+            Some(capture) => {
+                capture.mode = capture.mode.max(mode);
+                capture.outside_unsafe |= outside_unsafe;
+            }
+            None => self.found.push(Capture {
+                name: name.to_string(),
+                mode,
+                outside_unsafe,
+            }),
+        }
+    }
+
+    fn bind(&mut self, name: &str) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(name.to_string());
+        }
+    }
+
+    fn expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Variable(name) => self.use_name(name, CaptureMode::Move),
+            Expression::Ref { mutable, expr } => match expr.as_ref() {
+                Expression::Variable(name) => {
+                    let mode = if *mutable {
+                        CaptureMode::RefMut
+                    } else {
+                        CaptureMode::Ref
+                    };
+                    self.use_name(name, mode);
+                }
+                inner => self.expr(inner),
+            },
+            Expression::Call(call) => {
+                self.use_name(&call.func_name, CaptureMode::Ref);
+                call.args.iter().for_each(|arg| self.expr(arg));
+            }
+            Expression::Literal(_) => {}
+            Expression::Binary { left, right, .. } => {
+                self.expr(left);
+                self.expr(right);
+            }
+            Expression::Block(block) => self.block(block, Vec::new()),
+            Expression::Cast { expr, .. } | Expression::Field { expr, .. } => self.expr(expr),
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => {
+                elements.iter().for_each(|element| self.expr(element));
+            }
+            Expression::Match { value, arms } => {
+                self.expr(value);
+                for arm in arms {
+                    self.block(&arm.body, arm.pattern.bindings().cloned().collect());
+                }
+            }
+            Expression::Closure(closure) => {
+                for capture in closure.captures() {
+                    let outside_unsafe = capture.outside_unsafe && !self.in_unsafe;
+                    self.use_name_in(&capture.name, capture.mode, outside_unsafe);
+                }
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block, bound: Vec<String>) {
+        let in_unsafe = self.in_unsafe;
+        self.in_unsafe |= block.unsafe_block;
+        self.scopes.push(bound);
+        for stmt in &block.statements {
+            match stmt {
+                Statement::Let(l) => {
+                    self.expr(&l.value);
+                    self.bind(&l.name);
+                }
+                Statement::LetTuple(l) => {
+                    self.expr(&l.value);
+                    l.names.iter().for_each(|name| self.bind(name));
+                }
+                Statement::Const(c) => {
+                    self.expr(&c.value);
+                    self.bind(&c.name);
+                }
+                Statement::If(stmt) => {
+                    self.expr(&stmt.condition);
+                    let bound = stmt
+                        .pattern
+                        .iter()
+                        .flat_map(Pattern::bindings)
+                        .cloned()
+                        .collect();
+                    self.block(&stmt.then_block, bound);
+                    if let Some(else_block) = &stmt.else_block {
+                        self.block(else_block, Vec::new());
+                    }
+                }
+                Statement::For(stmt) => {
+                    self.expr(&stmt.start);
+                    self.expr(&stmt.end);
+                    self.block(&stmt.body, vec![stmt.var_name.clone()]);
+                }
+                Statement::Break | Statement::Continue => {}
+                Statement::Expr(e) => self.expr(e),
+            }
+        }
+        self.scopes.pop();
+        self.in_unsafe = in_unsafe;
+    }
+}
@@ -0,0 +1,32 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use serde::{Deserialize, Serialize};
+
+use super::{Attribute, Type};
+
+/// `enum Name { A, B(T, ...), ... }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<EnumVariant>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    /// Payload types; empty for a unit variant.
+    pub payload: Vec<Type>,
+}
+
+impl Enum {
+    pub fn variant(&self, name: &str) -> Option<&EnumVariant> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+}
@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::Block;
+use super::{Block, Closure, Type};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Literal {
@@ -48,10 +48,72 @@ pub enum Expression {
         expr: Box<Expression>,
     },
     Block(Block),
+    /// `expr as ty`, a lossless integer conversion.
+    Cast {
+        expr: Box<Expression>,
+        ty: Type,
+    },
+    /// `(a, b, ...)`, at least two elements.
+    Tuple(Vec<Expression>),
+    /// `expr.0`
+    Field {
+        expr: Box<Expression>,
+        index: usize,
+    },
+    /// `Enum::Variant` or `Enum::Variant(a, ...)`.
+    Variant {
+        enum_name: String,
+        variant: String,
+        args: Vec<Expression>,
+    },
+    /// `match value { Pattern => { ... } ... }`; its value is the value of
+    /// the arm that runs.
+    Match {
+        value: Box<Expression>,
+        arms: Vec<MatchArm>,
+    },
+    /// `|a, b: T| body`
+    Closure(Closure),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Pattern {
+    /// `_`
+    Wildcard,
+    /// `Enum::Variant` or `Enum::Variant(a, ...)`; a `_` binding ignores its
+    /// payload value.
+    Variant {
+        enum_name: String,
+        variant: String,
+        bindings: Vec<String>,
+    },
+}
+
+impl Pattern {
+    /// Names bound by the pattern, without `_`.
+    pub fn bindings(&self) -> impl Iterator<Item = &String> {
+        let bindings = match self {
+            Pattern::Wildcard => &[][..],
+            Pattern::Variant { bindings, .. } => bindings,
+        };
+        bindings.iter().filter(|name| *name != "_")
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CallExpr {
     pub func_name: String,
     pub args: Vec<Expression>,
+    /// Explicit type arguments, `f::<T>(...)`.
+    #[serde(default)]
+    pub type_args: Vec<Type>,
+    /// Source line of the call, 0 when unknown.
+    #[serde(default)]
+    pub line: usize,
 }
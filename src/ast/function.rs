@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{Block, Type};
+use super::{Attribute, Block, Type};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Function {
@@ -23,6 +23,15 @@ pub struct Function {
     pub args: Vec<Arg>,
     pub ret_type: Option<Type>,
     pub body: Block,
+    /// `test fn`: run by `safe test`, never called by the program.
+    #[serde(default)]
+    pub is_test: bool,
+    /// File the function was read from, relative to the project root. Set
+    /// by the CLI after parsing; used in assertion messages.
+    #[serde(default)]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
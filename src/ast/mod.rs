@@ -13,16 +13,24 @@
 // See: https://opensource.org/licenses/MIT
 
 mod alias;
+mod attribute;
 mod block;
+mod closure;
+mod enum_def;
 mod expr;
 mod function;
 mod source_file;
 mod struct_def;
 mod ty;
 
-pub use alias::Alias;
-pub use block::{Block, ConstStatement, ForStatement, IfStatement, LetStatement, Statement};
-pub use expr::{BinaryOp, CallExpr, Expression, Literal};
+pub use alias::{Alias, is_type_name};
+pub use attribute::Attribute;
+pub use block::{
+    Block, ConstStatement, ForStatement, IfStatement, LetStatement, LetTupleStatement, Statement,
+};
+pub use closure::{Capture, CaptureMode, Closure, ClosureParam};
+pub use enum_def::{Enum, EnumVariant};
+pub use expr::{BinaryOp, CallExpr, Expression, Literal, MatchArm, Pattern};
 pub use function::{Arg, Function, SafetyLevel};
 pub use source_file::{Item, SourceFile};
 pub use struct_def::{Struct, StructField};
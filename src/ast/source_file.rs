@@ -14,7 +14,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{Alias, Function, Struct};
+use super::{Alias, Enum, Function, Struct};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceFile {
@@ -26,4 +26,5 @@ pub enum Item {
     Function(Function),
     Alias(Alias),
     Struct(Struct),
+    Enum(Enum),
 }
@@ -14,12 +14,14 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::Type;
+use super::{Attribute, Type};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Struct {
     pub name: String,
     pub fields: Vec<StructField>,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
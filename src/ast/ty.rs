@@ -18,5 +18,22 @@ use serde::{Deserialize, Serialize};
 pub enum Type {
     Path(String),
     RawPtr(Box<Type>),
-    Ref { mutable: bool, inner: Box<Type> },
+    Ref {
+        mutable: bool,
+        inner: Box<Type>,
+    },
+    /// `(A, B, ...)`, at least two elements; `()` stays `Path("()")`.
+    Tuple(Vec<Type>),
+    /// `fn(A, B) -> R`, or `raw fn(...)` for a `raw fn`; without `-> R` the
+    /// return type is `()`.
+    Fn {
+        raw: bool,
+        /// A closure that captures bindings, or a parameter that accepts
+        /// one; rendered as `closure(A) -> R`. A plain function fits where a
+        /// closure is expected, not the reverse.
+        #[serde(default)]
+        closure: bool,
+        params: Vec<Type>,
+        ret: Box<Type>,
+    },
 }
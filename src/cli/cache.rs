@@ -0,0 +1,184 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! `.safe-cache`: on-disk cache of parsed files and checked programs.
+//!
+//! Entries are JSON-serialized ASTs named by the SHA-256 of their inputs and
+//! the compiler build, so an entry is never stale: changing a file, an alias
+//! file or a rule level simply produces a new key. All entries are per
+//! source file: `parsed/` holds its AST (imports stripped), `molded/` its
+//! molded AST and type signatures, and `typed/` records that its function
+//! bodies passed type checking against the signatures of the program.
+//!
+//! The cache is best-effort. Unreadable entries count as misses and failed
+//! writes are ignored.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::UNIX_EPOCH;
+
+use crate::ast::{Block, Item, SourceFile};
+
+pub const CACHE_DIR: &str = ".safe-cache";
+/// Bumped when the layout of an entry changes.
+const CACHE_SCHEMA: u32 = 2;
+
+/// Molding output of one file, with the warnings to replay on a hit.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MoldedFile {
+    pub items: Vec<Item>,
+    /// `items` without function bodies: what type checking the other files
+    /// depends on.
+    pub signatures: Vec<Item>,
+    pub warnings: Vec<String>,
+}
+
+impl MoldedFile {
+    pub fn new(items: Vec<Item>, warnings: Vec<String>) -> Self {
+        let signatures = items
+            .iter()
+            .cloned()
+            .map(|mut item| {
+                if let Item::Function(func) = &mut item {
+                    func.body = Block {
+                        statements: Vec::new(),
+                        unsafe_block: false,
+                    };
+                }
+                item
+            })
+            .collect();
+        Self {
+            items,
+            signatures,
+            warnings,
+        }
+    }
+}
+
+pub struct BuildCache {
+    dir: PathBuf,
+}
+
+impl BuildCache {
+    /// Cache stored in `<root>/.safe-cache`.
+    pub fn new(root: &Path) -> Self {
+        Self {
+            dir: root.join(CACHE_DIR),
+        }
+    }
+
+    /// Parsed AST of one file body, parsing it with `parse` on a miss.
+    pub fn parsed(
+        &self,
+        body: &str,
+        parse: impl FnOnce(&str) -> Result<SourceFile, String>,
+    ) -> Result<SourceFile, String> {
+        let path = self.entry("parsed", &key(&[body.as_bytes()]));
+        self.get_or_insert(&path, || parse(body))
+    }
+
+    /// Molded file for `inputs` (its body and what molding it depends on),
+    /// running `mold` on a miss. Failures are not cached.
+    pub fn molded(
+        &self,
+        inputs: &[&[u8]],
+        mold: impl FnOnce() -> Result<MoldedFile, String>,
+    ) -> Result<MoldedFile, String> {
+        let path = self.entry("molded", &key(inputs));
+        self.get_or_insert(&path, mold)
+    }
+
+    /// Runs `check` unless it already passed for `inputs` (a molded file and
+    /// the signatures of the program).
+    pub fn typed(
+        &self,
+        inputs: &[&[u8]],
+        check: impl FnOnce() -> Result<(), String>,
+    ) -> Result<(), String> {
+        let path = self.entry("typed", &key(inputs));
+        self.get_or_insert(&path, check)
+    }
+
+    fn entry(&self, kind: &str, key: &str) -> PathBuf {
+        self.dir.join(kind).join(format!("{key}.json"))
+    }
+
+    fn get_or_insert<T: Serialize + DeserializeOwned>(
+        &self,
+        path: &Path,
+        compute: impl FnOnce() -> Result<T, String>,
+    ) -> Result<T, String> {
+        if let Some(value) = fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+        {
+            return Ok(value);
+        }
+
+        let value = compute()?;
+        if let Ok(text) = serde_json::to_string(&value) {
+            Self::store(path, &text);
+        }
+        Ok(value)
+    }
+
+    /// Writes through a temporary file so a concurrent build never reads a
+    /// half-written entry.
+    fn store(path: &Path, text: &str) {
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        let temp = path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::create_dir_all(dir).is_ok() && fs::write(&temp, text).is_ok() {
+            let _ = fs::rename(&temp, path);
+        }
+        let _ = fs::remove_file(&temp);
+    }
+}
+
+/// Version, cache schema and build of the running compiler. The size and
+/// modification time of the executable stand in for a hash of it: a rebuild
+/// changes them, and reading them costs nothing.
+fn compiler_id() -> &'static str {
+    static ID: OnceLock<String> = OnceLock::new();
+    ID.get_or_init(|| {
+        let build = env::current_exe()
+            .and_then(fs::metadata)
+            .map(|meta| {
+                let modified = meta
+                    .modified()
+                    .ok()
+                    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                    .map_or(0, |elapsed| elapsed.as_nanos());
+                format!("{}-{modified}", meta.len())
+            })
+            .unwrap_or_default();
+        format!("{}+{CACHE_SCHEMA}+{build}", env!("CARGO_PKG_VERSION"))
+    })
+}
+
+/// Hex SHA-256 of the compiler id and each input, length-prefixed so that
+/// `["ab", "c"]` and `["a", "bc"]` differ.
+fn key(inputs: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(compiler_id().as_bytes());
+    for input in inputs {
+        hasher.update((input.len() as u64).to_le_bytes());
+        hasher.update(input);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -0,0 +1,114 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Cargo project generated by `safe run`.
+//!
+//! Each entry gets `<run-dir>/<package>/` with a `Cargo.toml` that depends on
+//! this crate by path. All packages share `<run-dir>/target`, so the runtime
+//! is compiled once per project.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Location of the `safe_lang` runtime crate the generated code links to.
+const RUNTIME_PATH: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Writes the cargo project for `generated`, builds it offline and runs it.
+pub fn build_and_run(run_dir: &Path, entry_file: &Path, generated: &str) -> Result<(), String> {
+    let package = package_name(entry_file);
+    let manifest_path = write_project(run_dir, &package, generated)?;
+    let target_dir = run_dir.join("target");
+
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .arg("build")
+        .arg("--offline")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(&manifest_path)
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .map_err(|e| format!("Failed to start cargo: {e}"))?;
+    if !status.success() {
+        return Err(format!(
+            "cargo build failed for '{}'",
+            manifest_path.display()
+        ));
+    }
+
+    let binary = target_dir
+        .join("debug")
+        .join(format!("{package}{}", env::consts::EXE_SUFFIX));
+    let status = Command::new(&binary)
+        .status()
+        .map_err(|e| format!("Failed to run '{}': {}", binary.display(), e))?;
+    if !status.success() {
+        return Err(format!("Program exited with {status}"));
+    }
+    Ok(())
+}
+
+/// Writes `Cargo.toml` and `src/main.rs`, returning the manifest path.
+/// Unchanged files are left alone so cargo can skip the rebuild.
+pub fn write_project(run_dir: &Path, package: &str, generated: &str) -> Result<PathBuf, String> {
+    let project_dir = run_dir.join(package);
+    let src_dir = project_dir.join("src");
+    fs::create_dir_all(&src_dir)
+        .map_err(|e| format!("Failed to create '{}': {}", src_dir.display(), e))?;
+
+    let manifest_path = project_dir.join("Cargo.toml");
+    write_if_changed(&manifest_path, &cargo_manifest(package))?;
+    write_if_changed(&src_dir.join("main.rs"), generated)?;
+    Ok(manifest_path)
+}
+
+fn cargo_manifest(package: &str) -> String {
+    let runtime = toml::Value::String(RUNTIME_PATH.to_string());
+    format!(
+        "[package]\nname = \"{package}\"\nversion = \"0.0.0\"\nedition = \"2024\"\npublish = false\n\n\
+         [dependencies]\nsafe-lang = {{ path = {runtime} }}\n\n\
+         # Generated code is not meant to be read; keep `safe run` output clean.\n\
+         [lints.rust]\nwarnings = \"allow\"\n\n\
+         # Keeps the project out of any enclosing cargo workspace.\n[workspace]\n"
+    )
+}
+
+/// Target names cargo forbids for a binary, plus `target`, the project
+/// directory all packages share.
+const RESERVED_NAMES: [&str; 5] = ["build", "deps", "examples", "incremental", "target"];
+
+/// Cargo package name derived from the entry file stem. Names that do not
+/// start with a letter, or that cargo reserves, get a `safe_` prefix.
+pub fn package_name(entry_file: &Path) -> String {
+    let stem = entry_file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() && !RESERVED_NAMES.contains(&name.as_str()) => name,
+        _ => format!("safe_{name}"),
+    }
+}
+
+fn write_if_changed(path: &Path, content: &str) -> Result<(), String> {
+    if fs::read_to_string(path).is_ok_and(|current| current == content) {
+        return Ok(());
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+}
@@ -0,0 +1,249 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! `Safe.toml` project manifest.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::Molder;
+use crate::ast::SourceFile;
+use crate::molding::{AliasFile, RuleConfig};
+
+pub const MANIFEST_FILE: &str = "Safe.toml";
+const DEFAULT_ENTRY: &str = "src/main.safe";
+const DEFAULT_SOURCE_DIR: &str = "src";
+const DEFAULT_RUN_DIR: &str = "target/safe";
+const DEFAULT_ALIAS_FILE: &str = "rules.safe";
+
+/// Paths are relative to the directory holding `Safe.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    /// Entry file used when a command gets no file. `src/main.safe` when unset.
+    pub entry: Option<PathBuf>,
+    /// Directories searched for imports. `["src"]` when unset.
+    pub sources: Option<Vec<PathBuf>>,
+    /// Alias files loaded instead of `rules.safe`.
+    pub aliases: Option<Vec<AliasEntry>>,
+    #[serde(default)]
+    pub build: BuildConfig,
+    #[serde(default)]
+    pub rules: RuleConfig,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
+}
+
+/// `[build]` table.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct BuildConfig {
+    /// Where `safe build` writes `<entry>.rs`. Next to the entry when unset.
+    pub out_dir: Option<PathBuf>,
+    /// Where `safe run` generates its cargo projects. `target/safe` when unset.
+    pub run_dir: Option<PathBuf>,
+}
+
+/// `"path.safe"` or `{ path = "path.safe", namespace = "mem" }`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AliasEntry {
+    Path(PathBuf),
+    Namespaced { path: PathBuf, namespace: String },
+}
+
+/// Another SAFE? project, imported as `import "<name>/file.safe"`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Dependency {
+    pub path: PathBuf,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| format!("Invalid {MANIFEST_FILE}: {}", e.message()))
+    }
+}
+
+/// A manifest together with the directory it was found in.
+#[derive(Debug)]
+pub struct Project {
+    pub root: PathBuf,
+    pub manifest: Manifest,
+}
+
+impl Project {
+    /// Walks up from the entry file to the nearest `Safe.toml`. Without one,
+    /// the entry's directory is the root and every setting has its default.
+    pub fn discover(entry_file: &Path) -> Result<Self, String> {
+        let entry_dir = entry_file
+            .parent()
+            .ok_or_else(|| format!("Invalid parent path: {}", entry_file.display()))?;
+
+        match Self::find(entry_dir) {
+            Some(root) => Self::load(&root),
+            None => Ok(Self {
+                root: entry_dir.to_path_buf(),
+                manifest: Manifest::default(),
+            }),
+        }
+    }
+
+    /// Project containing `dir`, for commands run without a file.
+    pub fn current(dir: &Path) -> Result<Self, String> {
+        let root = Self::find(dir).ok_or_else(|| {
+            format!(
+                "No {MANIFEST_FILE} found in '{}' or its parents",
+                dir.display()
+            )
+        })?;
+        Self::load(&root)
+    }
+
+    pub fn load(root: &Path) -> Result<Self, String> {
+        let path = root.join(MANIFEST_FILE);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        let manifest = Manifest::parse(&text).map_err(|e| format!("{} ({})", e, path.display()))?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            manifest,
+        })
+    }
+
+    fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .find(|dir| dir.join(MANIFEST_FILE).is_file())
+            .map(Path::to_path_buf)
+    }
+
+    pub fn entry(&self) -> PathBuf {
+        let entry = self.manifest.entry.as_deref();
+        self.root.join(entry.unwrap_or(Path::new(DEFAULT_ENTRY)))
+    }
+
+    pub fn source_dirs(&self) -> Vec<PathBuf> {
+        match &self.manifest.sources {
+            Some(dirs) => dirs.iter().map(|dir| self.root.join(dir)).collect(),
+            None => vec![self.root.join(DEFAULT_SOURCE_DIR)],
+        }
+    }
+
+    /// Output path of `safe build` for `entry_file`.
+    pub fn build_output(&self, entry_file: &Path) -> PathBuf {
+        match (&self.manifest.build.out_dir, entry_file.file_stem()) {
+            (Some(dir), Some(stem)) => self.root.join(dir).join(stem).with_extension("rs"),
+            _ => entry_file.with_extension("rs"),
+        }
+    }
+
+    pub fn run_dir(&self) -> PathBuf {
+        let dir = self.manifest.build.run_dir.as_deref();
+        self.root.join(dir.unwrap_or(Path::new(DEFAULT_RUN_DIR)))
+    }
+
+    /// Alias files of the project: the manifest's `aliases`, or
+    /// `rules.safe` in the project root when present.
+    pub fn alias_files(&self) -> Vec<AliasFile> {
+        match &self.manifest.aliases {
+            Some(entries) => entries
+                .iter()
+                .map(|entry| match entry {
+                    AliasEntry::Path(path) => AliasFile::new(self.root.join(path)),
+                    AliasEntry::Namespaced { path, namespace } => {
+                        AliasFile::namespaced(self.root.join(path), namespace)
+                    }
+                })
+                .collect(),
+            None => {
+                let default = self.root.join(DEFAULT_ALIAS_FILE);
+                if default.is_file() {
+                    vec![AliasFile::new(default)]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    /// Alias files to mold with: a non-empty `alias_override` (from
+    /// `--aliases`) replaces the project's own.
+    pub fn alias_files_with(&self, alias_override: &[AliasFile]) -> Vec<AliasFile> {
+        if alias_override.is_empty() {
+            self.alias_files()
+        } else {
+            alias_override.to_vec()
+        }
+    }
+
+    /// Molder configured with the project's rule levels and alias files.
+    pub fn molder(&self, source: SourceFile, alias_override: &[AliasFile]) -> Molder {
+        Molder::new(source)
+            .with_rules(self.manifest.rules.clone())
+            .with_alias_files(self.alias_files_with(alias_override))
+    }
+
+    /// Source directories of this project and of its path dependencies.
+    pub fn import_roots(&self) -> Result<ImportRoots, String> {
+        let mut dependencies = BTreeMap::new();
+        for (name, dependency) in &self.manifest.dependencies {
+            let dir = self.root.join(&dependency.path);
+            if !dir.join(MANIFEST_FILE).is_file() {
+                return Err(format!(
+                    "Dependency '{name}' has no {MANIFEST_FILE} in '{}'",
+                    dir.display()
+                ));
+            }
+            let project = Self::load(&dir)?;
+            dependencies.insert(name.clone(), project.source_dirs());
+        }
+        Ok(ImportRoots {
+            sources: self.source_dirs(),
+            dependencies,
+        })
+    }
+}
+
+/// Where `import "path.safe"` is looked up.
+#[derive(Debug, Default)]
+pub struct ImportRoots {
+    sources: Vec<PathBuf>,
+    dependencies: BTreeMap<String, Vec<PathBuf>>,
+}
+
+impl ImportRoots {
+    /// Resolves `import` from a file in `from_dir`: next to the importing
+    /// file first, then `<dependency>/...` in a dependency's source
+    /// directories, then the project's source directories.
+    pub fn resolve(&self, from_dir: &Path, import: &str) -> PathBuf {
+        let local = from_dir.join(import);
+        if local.exists() {
+            return local;
+        }
+
+        let import = Path::new(import);
+        let mut components = import.components();
+        if let Some(Component::Normal(first)) = components.next()
+            && let Some(dirs) = first.to_str().and_then(|name| self.dependencies.get(name))
+        {
+            let rest = components.as_path();
+            if let Some(found) = dirs.iter().map(|dir| dir.join(rest)).find(|p| p.exists()) {
+                return found;
+            }
+        }
+
+        self.sources
+            .iter()
+            .map(|dir| dir.join(import))
+            .find(|path| path.exists())
+            .unwrap_or(local)
+    }
+}
@@ -0,0 +1,1074 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+mod cache;
+mod cargo;
+mod manifest;
+
+pub use manifest::{
+    AliasEntry, BuildConfig, Dependency, ImportRoots, MANIFEST_FILE, Manifest, Project,
+};
+
+use cache::{BuildCache, MoldedFile};
+
+use crate::CodeGenerator;
+use crate::Molder;
+use crate::TypeChecker;
+use crate::ast::{Item, SafetyLevel, SourceFile};
+use crate::formatter::format_source;
+use crate::interpreter::{Interpreter, Repl};
+use crate::lexer;
+use crate::molding::AliasFile;
+use crate::parser;
+
+const INIT_MANIFEST: &str = "name = \"safe-project\"\nversion = \"1.0\"\n";
+const INIT_MAIN_SAFE: &str = "safe fn main() {\n    let high_size: usize = 4\n    let high_buf = allocate_buffer(high_size)\n    deallocate_buffer(high_buf)\n}\n";
+
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<(), String> {
+    let args: Vec<String> = args.into_iter().collect();
+    if args.is_empty() {
+        return Err(usage());
+    }
+
+    match args[0].as_str() {
+        "build" => {
+            let options = CompileOptions::parse(&args[1..], false)
+                .ok_or("Usage: safe build [--aliases [ns=]file] [file.safe]")?;
+            build_command(&options)
+        }
+        "check" => {
+            let options = CompileOptions::parse(&args[1..], false)
+                .ok_or("Usage: safe check [--aliases [ns=]file] [file.safe]")?;
+            check_command(&options)
+        }
+        "run" => {
+            let options = CompileOptions::parse(&args[1..], true)
+                .ok_or("Usage: safe run [--interpret] [--aliases [ns=]file] [file.safe]")?;
+            if options.interpret {
+                interpret_command(&options)
+            } else {
+                run_command(&options)
+            }
+        }
+        "test" => {
+            let options = CompileOptions::parse(&args[1..], false)
+                .ok_or("Usage: safe test [--aliases [ns=]file] [file.safe]")?;
+            test_command(&options)
+        }
+        "fmt" => fmt_command(&args[1..]),
+        "repl" => {
+            if args.len() != 1 {
+                return Err("Usage: safe repl".to_string());
+            }
+            let stdin = std::io::stdin();
+            Repl::new()
+                .run(stdin.lock(), std::io::stdout())
+                .map_err(|e| format!("REPL I/O error: {e}"))
+        }
+        "init" => {
+            if args.len() == 1 {
+                init_current_dir()
+            } else if args.len() == 2 {
+                init_new_project(&args[1])
+            } else {
+                Err("Usage: safe init [project-name]".to_string())
+            }
+        }
+        _ => Err(usage()),
+    }
+}
+
+fn usage() -> String {
+    "Usage:\n  safe build [--aliases [ns=]file] [file.safe]\n  safe check [--aliases [ns=]file] [file.safe]\n  safe run [--interpret] [--aliases [ns=]file] [file.safe]\n  safe test [--aliases [ns=]file] [file.safe]\n  safe fmt [--check] [path...]\n  safe repl\n  safe init\n  safe init <project-name>"
+        .to_string()
+}
+
+/// Arguments shared by `build`, `check` and `run`.
+#[derive(Debug, Default, PartialEq)]
+struct CompileOptions {
+    entry_file: Option<String>,
+    interpret: bool,
+    /// `--aliases` files, replacing the project's alias files when given.
+    aliases: Vec<AliasFile>,
+}
+
+impl CompileOptions {
+    fn parse(args: &[String], allow_interpret: bool) -> Option<Self> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--interpret" if allow_interpret => options.interpret = true,
+                "--aliases" => {
+                    let value = args.next()?;
+                    options.aliases.push(match value.split_once('=') {
+                        Some((namespace, path)) => AliasFile::namespaced(path, namespace),
+                        None => AliasFile::new(value),
+                    });
+                }
+                flag if flag.starts_with("--") => return None,
+                file if options.entry_file.is_none() => options.entry_file = Some(file.to_string()),
+                _ => return None,
+            }
+        }
+        Some(options)
+    }
+}
+
+fn build_command(options: &CompileOptions) -> Result<(), String> {
+    let (entry_abs, project) = resolve_entry(options.entry_file.as_deref())?;
+    let generated = compile_to_rust(&compile(&entry_abs, &project, &options.aliases)?)?;
+
+    let output_path = project.build_output(&entry_abs);
+    if let Some(dir) = output_path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create '{}': {}", dir.display(), e))?;
+    }
+    fs::write(&output_path, generated)
+        .map_err(|e| format!("Failed to write output '{}': {}", output_path.display(), e))?;
+
+    println!("Build successful: {}", output_path.display());
+    Ok(())
+}
+
+fn check_command(options: &CompileOptions) -> Result<(), String> {
+    let (entry_abs, project) = resolve_entry(options.entry_file.as_deref())?;
+    compile(&entry_abs, &project, &options.aliases)?;
+
+    println!("Check successful: {}", entry_abs.display());
+    Ok(())
+}
+
+fn run_command(options: &CompileOptions) -> Result<(), String> {
+    let (entry_abs, project) = resolve_entry(options.entry_file.as_deref())?;
+    let generated = compile_to_rust(&compile(&entry_abs, &project, &options.aliases)?)?;
+
+    cargo::build_and_run(&project.run_dir(), &entry_abs, &generated)
+}
+
+fn interpret_command(options: &CompileOptions) -> Result<(), String> {
+    let (entry_abs, project) = resolve_entry(options.entry_file.as_deref())?;
+    let source = compile(&entry_abs, &project, &options.aliases)?;
+
+    let mut interpreter = Interpreter::new();
+    interpreter.load(&source);
+    interpreter.run_main()?;
+    Ok(())
+}
+
+/// Runs every `test fn` of the entry file and its imports in the
+/// interpreter, in source order, and prints a summary.
+fn test_command(options: &CompileOptions) -> Result<(), String> {
+    let (entry_abs, project) = resolve_entry(options.entry_file.as_deref())?;
+    let source = compile(&entry_abs, &project, &options.aliases)?;
+
+    let (passed, failures) = run_tests(&source);
+    if failures.is_empty() {
+        println!("\ntest result: ok. {passed} passed; 0 failed");
+        return Ok(());
+    }
+
+    println!("\nfailures:");
+    for (name, message) in &failures {
+        println!("    {name}: {message}");
+    }
+    println!(
+        "\ntest result: FAILED. {passed} passed; {} failed",
+        failures.len()
+    );
+    Err(format!("{} test(s) failed", failures.len()))
+}
+
+/// Runs the tests of `source`, printing one line per test. Returns the
+/// number of passing tests and the name and error of each failing one.
+fn run_tests(source: &SourceFile) -> (usize, Vec<(String, String)>) {
+    let tests: Vec<&str> = source
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Function(func) if func.is_test => Some(func.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let mut interpreter = Interpreter::new();
+    interpreter.load(source);
+
+    println!("running {} test(s)", tests.len());
+    let mut passed = 0;
+    let mut failures = Vec::new();
+    for name in tests {
+        match interpreter.call(name, Vec::new()) {
+            Ok(_) => {
+                println!("test {name} ... ok");
+                passed += 1;
+            }
+            Err(message) => {
+                println!("test {name} ... FAILED");
+                failures.push((name.to_string(), message));
+            }
+        }
+    }
+    (passed, failures)
+}
+
+/// Formats the given files and directories, or every source directory of
+/// the current project. With `--check`, lists unformatted files instead and
+/// fails if there are any.
+fn fmt_command(args: &[String]) -> Result<(), String> {
+    let mut check = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--check" => check = true,
+            flag if flag.starts_with("--") => {
+                return Err("Usage: safe fmt [--check] [path...]".to_string());
+            }
+            path => paths.push(PathBuf::from(path)),
+        }
+    }
+    if paths.is_empty() {
+        let cwd = env::current_dir().map_err(|e| format!("Failed to get current dir: {e}"))?;
+        paths = Project::current(&cwd)?.source_dirs();
+    }
+
+    let mut files = Vec::new();
+    for path in &paths {
+        collect_safe_files(path, &mut files)?;
+    }
+    files.sort();
+
+    let mut unformatted = 0;
+    for file in &files {
+        let content = fs::read_to_string(file)
+            .map_err(|e| format!("Failed to read '{}': {}", file.display(), e))?;
+        let formatted = format_source(&content).map_err(|e| format!("{}: {e}", file.display()))?;
+        if formatted == content {
+            continue;
+        }
+        if check {
+            println!("Would reformat: {}", file.display());
+            unformatted += 1;
+        } else {
+            fs::write(file, formatted)
+                .map_err(|e| format!("Failed to write '{}': {}", file.display(), e))?;
+            println!("Formatted: {}", file.display());
+        }
+    }
+
+    if unformatted > 0 {
+        return Err(format!("{unformatted} file(s) need formatting"));
+    }
+    Ok(())
+}
+
+/// `path` itself if it is a file, or every `.safe` file below it.
+fn collect_safe_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        if !path.exists() {
+            return Err(format!("Path not found '{}'", path.display()));
+        }
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let entries =
+        fs::read_dir(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        let child = entry.path();
+        if child.is_dir() {
+            collect_safe_files(&child, files)?;
+        } else if child.extension().is_some_and(|ext| ext == "safe") {
+            files.push(child);
+        }
+    }
+    Ok(())
+}
+
+/// The given entry file and the project around it, or the manifest entry of
+/// the project containing the working directory.
+fn resolve_entry(entry_file: Option<&str>) -> Result<(PathBuf, Project), String> {
+    match entry_file {
+        Some(file) => {
+            let entry_abs = canonicalize_existing(Path::new(file))?;
+            let project = Project::discover(&entry_abs)?;
+            Ok((entry_abs, project))
+        }
+        None => {
+            let cwd = env::current_dir().map_err(|e| format!("Failed to get current dir: {e}"))?;
+            let project = Project::current(&cwd)?;
+            let entry_abs = canonicalize_existing(&project.entry())?;
+            Ok((entry_abs, project))
+        }
+    }
+}
+
+/// Expands imports, molds with the project's settings and type-checks.
+/// Each file is parsed, molded and checked on its own, and reused from
+/// `.safe-cache` when neither it nor what it depends on has changed.
+fn compile(
+    entry_abs: &Path,
+    project: &Project,
+    alias_override: &[AliasFile],
+) -> Result<SourceFile, String> {
+    let roots = project.import_roots()?;
+    let files = collect_files_with_imports(entry_abs, &roots)?;
+    let alias_files = project.alias_files_with(alias_override);
+    let cache = BuildCache::new(&project.root);
+
+    // Paths are part of the keys because functions record the file they
+    // come from.
+    let paths: Vec<String> = files
+        .iter()
+        .map(|(path, _)| relative_display(path, &project.root))
+        .collect();
+    let mut parsed = Vec::new();
+    for ((_, body), path) in files.iter().zip(&paths) {
+        let mut source = cache.parsed(body, parse_source)?;
+        for item in &mut source.items {
+            match item {
+                Item::Function(func) => func.file = Some(path.clone()),
+                Item::Alias(alias) => alias.file = Some(path.clone()),
+                _ => {}
+            }
+        }
+        parsed.push(source);
+    }
+
+    // Molding a file only looks at the rest of the program for its aliases
+    // and `raw fn`s, so those join the alias files and rule levels in the key.
+    let aliases: Vec<Vec<Item>> = parsed
+        .iter()
+        .map(|source| {
+            source
+                .items
+                .iter()
+                .filter(|item| matches!(item, Item::Alias(_)))
+                .cloned()
+                .collect()
+        })
+        .collect();
+    let raw_functions: Vec<String> = parsed
+        .iter()
+        .flat_map(|source| &source.items)
+        .filter_map(|item| match item {
+            Item::Function(func) if matches!(func.safety, SafetyLevel::Raw) => {
+                Some(func.name.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    let mut settings = vec![format!("{:?}", project.manifest.rules).into_bytes()];
+    for file in &alias_files {
+        settings.push(format!("{:?}", file).into_bytes());
+        settings.push(fs::read(&file.path).unwrap_or_default());
+    }
+    settings.push(serde_json::to_vec(&aliases).unwrap_or_default());
+    settings.push(raw_functions.join(",").into_bytes());
+
+    let mut molded = Vec::new();
+    for (index, ((mut source, (_, body)), path)) in
+        parsed.into_iter().zip(&files).zip(&paths).enumerate()
+    {
+        let inputs: Vec<&[u8]> = [path.as_bytes(), body.as_bytes()]
+            .into_iter()
+            .chain(settings.iter().map(Vec::as_slice))
+            .collect();
+        let file = cache.molded(&inputs, || {
+            let others = aliases.iter().enumerate().filter(|(i, _)| *i != index);
+            source
+                .items
+                .extend(others.flat_map(|(_, items)| items.iter().cloned()));
+            let mut molder = project
+                .molder(source, alias_override)
+                .with_raw_functions(raw_functions.iter().cloned());
+            molder.mold().map_err(|e| format!("Molding failed: {e}"))?;
+            Ok(MoldedFile::new(
+                molder.get_output().items.clone(),
+                molder.warnings(),
+            ))
+        })?;
+        molded.push((inputs.concat(), file));
+    }
+
+    // Bodies are checked against the signatures of the whole program, so a
+    // file is checked again only when it or some signature changed.
+    let signatures: Vec<&Vec<Item>> = molded.iter().map(|(_, file)| &file.signatures).collect();
+    let signatures = serde_json::to_vec(&signatures).unwrap_or_default();
+    let program = SourceFile {
+        items: molded
+            .iter()
+            .flat_map(|(_, file)| file.items.iter().cloned())
+            .collect(),
+    };
+    let mut checker = TypeChecker::new();
+    let type_error = |e| format!("Type checking failed: {e}");
+    checker.declare(&program).map_err(type_error)?;
+    for (inputs, file) in &molded {
+        cache
+            .typed(&[inputs, &signatures], || {
+                file.items.iter().try_for_each(|item| match item {
+                    Item::Function(func) => checker.check_function(func),
+                    _ => Ok(()),
+                })
+            })
+            .map_err(type_error)?;
+    }
+    for warning in molded.iter().flat_map(|(_, file)| &file.warnings) {
+        eprintln!("warning: {warning}");
+    }
+    Ok(program)
+}
+
+fn compile_to_rust(source: &SourceFile) -> Result<String, String> {
+    let mut generator = CodeGenerator::new();
+    generator
+        .generate(source)
+        .map_err(|e| format!("Code generation failed: {e}"))
+}
+
+pub(crate) fn parse_source(input: &str) -> Result<SourceFile, String> {
+    let tokens = lexer::tokenize(input).map_err(|e| format!("Lexing failed: {e}"))?;
+    let (rest, source) = parser::parse(&tokens).map_err(|e| format!("Parsing failed: {e:?}"))?;
+    if !rest.is_empty() {
+        return Err("Parsing failed: unconsumed tokens".to_string());
+    }
+    Ok(source)
+}
+
+/// Runs the molding phases and the type checker, returning the molded source.
+pub(crate) fn mold_and_check(source: SourceFile) -> Result<SourceFile, String> {
+    check_molded(Molder::new(source))
+}
+
+/// Like `mold_and_check` for an already configured molder. Rule violations
+/// configured as `warn` are printed to stderr.
+fn check_molded(molder: Molder) -> Result<SourceFile, String> {
+    let checked = check_program(molder)?;
+    for warning in &checked.warnings {
+        eprintln!("warning: {warning}");
+    }
+    Ok(checked.source)
+}
+
+/// Output of molding and type checking, with the warnings to print.
+struct CheckedProgram {
+    source: SourceFile,
+    warnings: Vec<String>,
+}
+
+fn check_program(mut molder: Molder) -> Result<CheckedProgram, String> {
+    molder.mold().map_err(|e| format!("Molding failed: {e}"))?;
+
+    let mut checker = TypeChecker::new();
+    checker
+        .check(molder.get_output())
+        .map_err(|e| format!("Type checking failed: {e}"))?;
+
+    Ok(CheckedProgram {
+        source: molder.get_output().clone(),
+        warnings: molder.warnings(),
+    })
+}
+
+/// `path` relative to the project root, for messages; the full path when it
+/// lies outside the root.
+fn relative_display(path: &Path, root: &Path) -> String {
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    path.strip_prefix(&root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Paths and bodies of the entry file and everything it imports,
+/// dependencies first. Import lines are blanked so line numbers still match
+/// the file.
+fn collect_files_with_imports(
+    entry_file: &Path,
+    roots: &ImportRoots,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    let mut visited = HashSet::new();
+    let mut visiting = HashSet::new();
+    let mut stack = Vec::new();
+    let mut cache = HashMap::new();
+    let mut output = Vec::new();
+
+    collect_recursive(
+        entry_file,
+        roots,
+        &mut visited,
+        &mut visiting,
+        &mut stack,
+        &mut cache,
+        &mut output,
+    )?;
+
+    Ok(output)
+}
+
+fn collect_recursive(
+    file: &Path,
+    roots: &ImportRoots,
+    visited: &mut HashSet<PathBuf>,
+    visiting: &mut HashSet<PathBuf>,
+    stack: &mut Vec<PathBuf>,
+    cache: &mut HashMap<PathBuf, String>,
+    output: &mut Vec<(PathBuf, String)>,
+) -> Result<(), String> {
+    let canonical = canonicalize_existing(file)?;
+
+    if visited.contains(&canonical) {
+        return Ok(());
+    }
+
+    if visiting.contains(&canonical) {
+        let mut chain = stack
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>();
+        chain.push(canonical.display().to_string());
+        return Err(format!("Import cycle detected: {}", chain.join(" -> ")));
+    }
+
+    visiting.insert(canonical.clone());
+    stack.push(canonical.clone());
+
+    let content = if let Some(cached) = cache.get(&canonical) {
+        cached.clone()
+    } else {
+        let loaded = fs::read_to_string(&canonical)
+            .map_err(|e| format!("Failed to read '{}': {}", canonical.display(), e))?;
+        cache.insert(canonical.clone(), loaded.clone());
+        loaded
+    };
+
+    let parent = canonical
+        .parent()
+        .ok_or_else(|| format!("Invalid parent path: {}", canonical.display()))?;
+    let mut body = String::new();
+
+    for line in content.lines() {
+        if let Some(import_path) = parse_import_line(line) {
+            let import_full = roots.resolve(parent, import_path);
+            collect_recursive(&import_full, roots, visited, visiting, stack, cache, output)?;
+        } else {
+            body.push_str(line);
+        }
+        body.push('\n');
+    }
+
+    output.push((canonical.clone(), body));
+
+    stack.pop();
+    visiting.remove(&canonical);
+    visited.insert(canonical);
+    Ok(())
+}
+
+pub(crate) fn parse_import_line(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with("import") {
+        return None;
+    }
+
+    let rest = trimmed.strip_prefix("import")?.trim_start();
+    if !(rest.starts_with('"') && rest.ends_with('"') && rest.len() >= 2) {
+        return None;
+    }
+    Some(&rest[1..rest.len() - 1])
+}
+
+fn init_current_dir() -> Result<(), String> {
+    let cwd = env::current_dir().map_err(|e| format!("Failed to get current dir: {e}"))?;
+    init_project_at(&cwd, false)
+}
+
+fn init_new_project(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Project name cannot be empty".to_string());
+    }
+    let cwd = env::current_dir().map_err(|e| format!("Failed to get current dir: {e}"))?;
+    let project_dir = cwd.join(name);
+    init_project_at(&project_dir, true)
+}
+
+fn init_project_at(path: &Path, create_root: bool) -> Result<(), String> {
+    if create_root {
+        if path.exists() {
+            return Err(format!("Directory already exists: {}", path.display()));
+        }
+        fs::create_dir_all(path)
+            .map_err(|e| format!("Failed to create directory '{}': {}", path.display(), e))?;
+    } else if !path.exists() {
+        return Err(format!("Directory does not exist: {}", path.display()));
+    }
+
+    let src_dir = path.join("src");
+    if !src_dir.exists() {
+        fs::create_dir_all(&src_dir)
+            .map_err(|e| format!("Failed to create '{}': {}", src_dir.display(), e))?;
+    }
+
+    let manifest_path = path.join("Safe.toml");
+    let main_safe_path = src_dir.join("main.safe");
+
+    write_if_missing(&manifest_path, INIT_MANIFEST)?;
+    write_if_missing(&main_safe_path, INIT_MAIN_SAFE)?;
+
+    println!("Initialized SAFE project at {}", path.display());
+    Ok(())
+}
+
+fn write_if_missing(path: &Path, content: &str) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+}
+
+fn canonicalize_existing(path: &Path) -> Result<PathBuf, String> {
+    fs::canonicalize(path).map_err(|e| format!("Path not found '{}': {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cargo::{package_name, write_project};
+    use super::{
+        AliasFile, CompileOptions, ImportRoots, Manifest, Project, collect_files_with_imports,
+        compile, parse_import_line, run_tests,
+    };
+    use crate::ast::{Expression, Item, Statement};
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(prefix: &str) -> PathBuf {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time went backwards")
+            .as_nanos();
+        let dir = env::temp_dir().join(format!("{}_{}_{}", prefix, std::process::id(), now));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        dir
+    }
+
+    #[test]
+    fn test_parse_import_line() {
+        assert_eq!(parse_import_line("import \"a.safe\""), Some("a.safe"));
+        assert_eq!(
+            parse_import_line("  import   \"dir/b.safe\"  "),
+            Some("dir/b.safe")
+        );
+        assert_eq!(parse_import_line("import a.safe"), None);
+        assert_eq!(parse_import_line("let high_x = 1"), None);
+    }
+
+    #[test]
+    fn test_collect_files_with_imports_orders_dependencies_first() {
+        let dir = temp_dir("safe_import_merge");
+        let entry = dir.join("file1.safe");
+        let dep = dir.join("file2.safe");
+
+        fs::write(
+            &dep,
+            "safe fn dep() {\n    let high_size: usize = 1\n    let high_buf = allocate_buffer(high_size)\n    deallocate_buffer(high_buf)\n}\n",
+        )
+        .expect("write dep");
+        fs::write(
+            &entry,
+            "import \"file2.safe\"\nsafe fn main() {\n    dep()\n}\n",
+        )
+        .expect("write entry");
+
+        let files = collect_files_with_imports(&entry, &ImportRoots::default()).expect("collect");
+        assert_eq!(files.len(), 2);
+        assert!(files[0].0.ends_with("file2.safe"));
+        assert!(files[0].1.starts_with("safe fn dep()"));
+        assert_eq!(files[1].1, "\nsafe fn main() {\n    dep()\n}\n");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_collect_files_with_imports_detects_cycle() {
+        let dir = temp_dir("safe_import_cycle");
+        let f1 = dir.join("file1.safe");
+        let f2 = dir.join("file2.safe");
+
+        fs::write(&f1, "import \"file2.safe\"\nsafe fn a() {}\n").expect("write f1");
+        fs::write(&f2, "import \"file1.safe\"\nsafe fn b() {}\n").expect("write f2");
+
+        let err = collect_files_with_imports(&f1, &ImportRoots::default())
+            .expect_err("cycle should fail");
+        assert!(err.contains("Import cycle detected"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_manifest_parses_build_dirs() {
+        let manifest = Manifest::parse(
+            "name = \"demo\"\nversion = \"1.0\"\n[build]\nout-dir = \"gen\"\nrun-dir = \"out/run\"\n",
+        )
+        .expect("parse manifest");
+        assert_eq!(manifest.name.as_deref(), Some("demo"));
+        assert_eq!(manifest.build.out_dir.as_deref(), Some(Path::new("gen")));
+        assert_eq!(
+            manifest.build.run_dir.as_deref(),
+            Some(Path::new("out/run"))
+        );
+
+        let err = Manifest::parse("[build]\nout_dir = \"gen\"\n").expect_err("unknown key");
+        assert!(err.contains("Invalid Safe.toml"));
+    }
+
+    #[test]
+    fn test_project_discover_walks_up_to_manifest() {
+        let dir = temp_dir("safe_project_discover");
+        let src = dir.join("src");
+        fs::create_dir_all(&src).expect("create src");
+        fs::write(dir.join("Safe.toml"), "[build]\nout-dir = \"gen\"\n").expect("write manifest");
+        let entry = src.join("main.safe");
+
+        let project = Project::discover(&entry).expect("discover");
+        assert_eq!(project.root, dir);
+        assert_eq!(
+            project.build_output(&entry),
+            dir.join("gen").join("main.rs")
+        );
+        assert_eq!(project.run_dir(), dir.join("target").join("safe"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_project_without_manifest_builds_next_to_entry() {
+        let dir = temp_dir("safe_project_default");
+        let entry = dir.join("demo.safe");
+
+        let project = Project::discover(&entry).expect("discover");
+        assert_eq!(project.build_output(&entry), dir.join("demo.rs"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_package_name_avoids_names_cargo_reserves() {
+        for stem in ["build", "deps", "examples", "incremental", "target"] {
+            let package = package_name(Path::new(&format!("src/{stem}.safe")));
+            assert_eq!(package, format!("safe_{stem}"));
+        }
+        assert_eq!(package_name(Path::new("Build.safe")), "safe_build");
+        assert_eq!(package_name(Path::new("builder.safe")), "builder");
+    }
+
+    #[test]
+    fn test_write_project_depends_on_runtime_by_path() {
+        let dir = temp_dir("safe_cargo_project");
+        let package = package_name(Path::new("1 packet.parse.safe"));
+        assert_eq!(package, "safe_1_packet_parse");
+
+        let manifest_path = write_project(&dir, &package, "fn main() {}\n").expect("write");
+        let manifest = fs::read_to_string(&manifest_path).expect("read manifest");
+        assert!(manifest.contains("name = \"safe_1_packet_parse\""));
+        assert!(manifest.contains(&format!("path = \"{}\"", env!("CARGO_MANIFEST_DIR"))));
+        assert!(manifest.contains("[workspace]"));
+        let main =
+            fs::read_to_string(dir.join(&package).join("src").join("main.rs")).expect("read main");
+        assert_eq!(main, "fn main() {}\n");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_manifest_rejects_unknown_rule() {
+        let err = Manifest::parse("[rules]\nrule9 = \"off\"\n").expect_err("unknown rule");
+        assert!(err.contains("rule9"));
+        let err = Manifest::parse("[rules]\nrule4 = \"loud\"\n").expect_err("unknown level");
+        assert!(err.contains("Invalid Safe.toml"));
+    }
+
+    #[test]
+    fn test_compile_project_uses_manifest_settings() {
+        let dir = temp_dir("safe_manifest_project");
+        let app = dir.join("app");
+        let util = dir.join("util");
+        fs::create_dir_all(app.join("lib")).expect("create app");
+        fs::create_dir_all(util.join("src")).expect("create util");
+
+        fs::write(
+            util.join("Safe.toml"),
+            "name = \"util\"\nversion = \"1.0\"\n",
+        )
+        .expect("write util manifest");
+        fs::write(
+            util.join("src").join("buffers.safe"),
+            "safe fn make(high_n: usize) -> HighPtr {\n    alloc(high_n)\n}\n",
+        )
+        .expect("write util source");
+        fs::write(
+            app.join("Safe.toml"),
+            "name = \"app\"\nentry = \"lib/start.safe\"\nsources = [\"lib\"]\naliases = [\"aliases.safe\"]\n\n[rules]\nrule5 = \"warn\"\n\n[dependencies]\nutil = { path = \"../util\" }\n",
+        )
+        .expect("write app manifest");
+        fs::write(app.join("aliases.safe"), "alias alloc = allocate_buffer\n")
+            .expect("write aliases");
+        fs::write(
+            app.join("lib").join("start.safe"),
+            "import \"util/buffers.safe\"\nsafe fn main() {\n    let n: usize = make_len()\n    let high_n: usize = make_len()\n    let high_buf = make(high_n)\n    deallocate_buffer(high_buf)\n}\nsafe fn make_len() -> usize {\n    string_len(&\"ab\")\n}\n",
+        )
+        .expect("write entry");
+
+        let project = Project::current(&app.join("lib")).expect("find project");
+        assert_eq!(project.entry(), app.join("lib").join("start.safe"));
+        let source = compile(&project.entry(), &project, &[]).expect("compile project");
+        let names = source
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                crate::ast::Item::Function(func) => Some(func.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["make", "main", "make_len"]);
+
+        fs::write(
+            app.join("Safe.toml"),
+            "[dependencies]\nutil = { path = \"../missing\" }\n",
+        )
+        .expect("rewrite manifest");
+        let err = Project::current(&app)
+            .and_then(|project| project.import_roots())
+            .expect_err("missing dependency");
+        assert!(err.contains("Dependency 'util' has no Safe.toml"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compile_options_parse_aliases_and_flags() {
+        let args = [
+            "--aliases",
+            "mem=lib/mem.safe",
+            "main.safe",
+            "--aliases",
+            "a.safe",
+        ]
+        .map(String::from);
+        let options = CompileOptions::parse(&args, false).expect("parse");
+        assert_eq!(options.entry_file.as_deref(), Some("main.safe"));
+        assert_eq!(
+            options.aliases,
+            [
+                AliasFile::namespaced("lib/mem.safe", "mem"),
+                AliasFile::new("a.safe")
+            ]
+        );
+
+        let run = ["--interpret".to_string()];
+        assert!(CompileOptions::parse(&run, true).expect("run").interpret);
+        assert!(CompileOptions::parse(&run, false).is_none());
+        assert!(CompileOptions::parse(&["--aliases".to_string()], false).is_none());
+        assert!(CompileOptions::parse(&["a".to_string(), "b".to_string()], false).is_none());
+    }
+
+    #[test]
+    fn test_project_alias_files_resolve_from_root() {
+        let dir = temp_dir("safe_project_aliases");
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        let entry = dir.join("src").join("main.safe");
+        fs::write(dir.join("Safe.toml"), "").expect("write manifest");
+
+        let project = Project::discover(&entry).expect("discover");
+        assert!(project.alias_files().is_empty());
+
+        fs::write(dir.join("rules.safe"), "alias alloc = allocate_buffer\n").expect("write");
+        let project = Project::discover(&entry).expect("discover");
+        assert_eq!(
+            project.alias_files(),
+            [AliasFile::new(dir.join("rules.safe"))]
+        );
+
+        fs::write(
+            dir.join("Safe.toml"),
+            "aliases = [\"a.safe\", { path = \"m.safe\", namespace = \"mem\" }]\n",
+        )
+        .expect("write manifest");
+        let project = Project::discover(&entry).expect("discover");
+        assert_eq!(
+            project.alias_files(),
+            [
+                AliasFile::new(dir.join("a.safe")),
+                AliasFile::namespaced(dir.join("m.safe"), "mem")
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compile_reuses_cache_until_inputs_change() {
+        let dir = temp_dir("safe_build_cache");
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        fs::write(dir.join("Safe.toml"), "name = \"cached\"\n").expect("write manifest");
+        fs::write(
+            dir.join("src").join("util.safe"),
+            "safe fn two() -> usize {\n    string_len(&\"ab\")\n}\n",
+        )
+        .expect("write util");
+        let entry = dir.join("src").join("main.safe");
+        fs::write(
+            &entry,
+            "import \"util.safe\"\nsafe fn main() {\n    let high_n: usize = two()\n}\n",
+        )
+        .expect("write entry");
+
+        let project = Project::load(&dir).expect("load");
+        let counts = || {
+            ["parsed", "molded", "typed"].map(|kind| {
+                fs::read_dir(dir.join(".safe-cache").join(kind))
+                    .map_or(0, |entries| entries.count())
+            })
+        };
+        compile(&entry, &project, &[]).expect("first compile");
+        assert_eq!(counts(), [2, 2, 2]);
+
+        // Editing a body redoes only that file; the other one's signatures
+        // are unchanged, so it is not checked again.
+        fs::write(
+            &entry,
+            "import \"util.safe\"\nsafe fn main() {\n    let high_m: usize = two()\n}\n",
+        )
+        .expect("rewrite entry");
+        let source = compile(&entry, &project, &[]).expect("recompile");
+        assert_eq!(source.items.len(), 2);
+        assert_eq!(counts(), [3, 3, 3]);
+
+        // A new signature in one file checks every file again, but molds
+        // only that one.
+        fs::write(
+            dir.join("src").join("util.safe"),
+            "safe fn two() -> usize {\n    string_len(&\"ab\")\n}\n\nsafe fn three() -> usize {\n    string_len(&\"abc\")\n}\n",
+        )
+        .expect("rewrite util");
+        compile(&entry, &project, &[]).expect("compile with new signature");
+        assert_eq!(counts(), [4, 4, 5]);
+
+        // Alias files take part in the key as well.
+        let aliases = dir.join("rules.safe");
+        fs::write(&aliases, "alias len = string_len\n").expect("write aliases");
+        let project = Project::load(&dir).expect("reload");
+        compile(&entry, &project, &[]).expect("compile with aliases");
+        assert_eq!(counts(), [4, 6, 7]);
+
+        // A hit returns the stored files without molding again.
+        for molded in fs::read_dir(dir.join(".safe-cache").join("molded")).expect("molded dir") {
+            let path = molded.expect("entry").path();
+            fs::write(&path, r#"{"items":[],"signatures":[],"warnings":[]}"#).expect("overwrite");
+        }
+        let source = compile(&entry, &project, &[]).expect("cached compile");
+        assert!(source.items.is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compile_molds_each_file_with_the_aliases_and_raw_fns_of_the_others() {
+        let dir = temp_dir("safe_build_per_file");
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        fs::write(dir.join("Safe.toml"), "name = \"per_file\"\n").expect("write manifest");
+        fs::write(
+            dir.join("src").join("util.safe"),
+            "alias seven = fresh\n\nraw fn fresh() -> u8 {\n    7\n}\n",
+        )
+        .expect("write util");
+        let entry = dir.join("src").join("main.safe");
+        fs::write(
+            &entry,
+            "import \"util.safe\"\n\nsafe fn main() {\n    printl(seven())\n}\n",
+        )
+        .expect("write entry");
+
+        let project = Project::load(&dir).expect("load");
+        let source = compile(&entry, &project, &[]).expect("compile");
+        let main = source
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Function(func) if func.name == "main" => Some(func),
+                _ => None,
+            })
+            .expect("main");
+        // The call went through the alias to a `raw fn` of util.safe, so it
+        // was wrapped in `unsafe`.
+        assert!(matches!(
+            &main.body.statements[..],
+            [Statement::Expr(Expression::Block(block))] if block.unsafe_block
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compile_reports_duplicate_aliases_by_file_and_line() {
+        let dir = temp_dir("safe_build_duplicate_alias");
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        fs::write(dir.join("Safe.toml"), "name = \"duplicate_alias\"\n").expect("write manifest");
+        fs::write(
+            dir.join("src").join("util.safe"),
+            "alias mk = allocate_buffer\n",
+        )
+        .expect("write util");
+        let entry = dir.join("src").join("main.safe");
+        fs::write(
+            &entry,
+            "import \"util.safe\"\n\nalias mk = allocate_buffer\n\nsafe fn main() {}\n",
+        )
+        .expect("write entry");
+
+        let project = Project::load(&dir).expect("load");
+        let err = compile(&entry, &project, &[]).expect_err("duplicate alias");
+        assert!(err.contains("Duplicate alias 'mk' at src/"), "{err}");
+        assert!(err.contains("src/main.safe:3"), "{err}");
+        assert!(err.contains("src/util.safe:1"), "{err}");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_run_tests_reports_imported_file_locations() {
+        let dir = temp_dir("safe_test_command");
+        fs::create_dir_all(dir.join("src")).expect("create src");
+        fs::write(dir.join("Safe.toml"), "").expect("write manifest");
+        fs::write(
+            dir.join("src").join("checks.safe"),
+            "test fn passes() {\n    assert(true)\n}\n\ntest fn fails() {\n    assert_eq(string_len(&\"ab\"), 3)\n}\n",
+        )
+        .expect("write checks");
+        fs::write(
+            dir.join("src").join("main.safe"),
+            "import \"checks.safe\"\n\nsafe fn main() {}\n",
+        )
+        .expect("write entry");
+
+        let project = Project::current(&dir).expect("find project");
+        let source = compile(&project.entry(), &project, &[]).expect("compile");
+        let (passed, failures) = run_tests(&source);
+        assert_eq!(passed, 1);
+        assert_eq!(
+            failures,
+            [(
+                "fails".to_string(),
+                "assertion failed at src/checks.safe:6: left 2 != right 3".to_string()
+            )]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
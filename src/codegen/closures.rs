@@ -0,0 +1,61 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Closures are emitted as `move` closures, so a binding the type checker
+//! found to be moved (always a `Copy` value) is copied in. Bindings captured
+//! by reference are rebound to a reference first:
+//! `{ let high_xs = &high_xs; move |high_x: u8| ... }`.
+
+use super::CodeGenerator;
+use crate::ast::*;
+
+impl CodeGenerator {
+    pub(super) fn generate_closure(&mut self, closure: &Closure) -> Result<(), String> {
+        let by_ref = closure
+            .captures()
+            .into_iter()
+            .filter(|capture| {
+                capture.mode == CaptureMode::Ref && self.bound_names.contains(&capture.name)
+            })
+            .map(|capture| capture.name)
+            .collect::<Vec<_>>();
+        if !by_ref.is_empty() {
+            self.emit("{ ");
+            for name in &by_ref {
+                self.emit(&format!("let {name} = &{name}; "));
+            }
+        }
+
+        let outer_levels = self.levels.clone();
+        let outer_refs = self.ref_captures.clone();
+        let outer_names = self.bound_names.clone();
+        self.ref_captures.extend(by_ref.iter().cloned());
+        self.emit("move |");
+        for (i, param) in closure.params.iter().enumerate() {
+            if i > 0 {
+                self.emit(", ");
+            }
+            self.emit(&param.name);
+            if let Some(ty) = &param.ty {
+                self.emit(&format!(": {}", Self::type_to_rust(ty)));
+            }
+            self.levels.remove(&param.name);
+            self.bind_name(&param.name);
+        }
+        self.emit("| ");
+        let result = self.generate_expression(&closure.body);
+        self.levels = outer_levels;
+        self.ref_captures = outer_refs;
+        self.bound_names = outer_names;
+        result?;
+
+        if !by_ref.is_empty() {
+            self.emit(" }");
+        }
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+use super::levels::ValueLevel;
 use crate::ast::*;
 use crate::std_api;
 use std::collections::{HashMap, HashSet};
@@ -14,6 +15,23 @@ pub struct CodeGenerator {
     pub(super) output: String,
     pub(super) aliases: HashMap<String, String>,
     pub(super) known_functions: HashSet<String>,
+    pub(super) raw_functions: HashSet<String>,
+    pub(super) function_returns: HashMap<String, Option<Type>>,
+    pub(super) levels: HashMap<String, ValueLevel>,
+    /// Whether the function being generated is a `raw fn`, where a `Raw`
+    /// binding is unwrapped wherever it is read.
+    pub(super) raw_body: bool,
+    /// Whether a `Raw` binding read now is unwrapped: in a `raw fn`, or in
+    /// the arguments of a call that consumes raw values.
+    pub(super) raw_operands: bool,
+    /// Names bound anywhere in the function being generated; a call through
+    /// one of them is an indirect call, which the type checker resolved.
+    pub(super) bound_names: HashSet<String>,
+    /// Names a closure being generated holds by reference; `&name` inside
+    /// its body is the name itself.
+    pub(super) ref_captures: HashSet<String>,
+    /// File of the function being generated, for assertion locations.
+    pub(super) current_file: Option<String>,
 }
 
 impl Default for CodeGenerator {
@@ -29,6 +47,14 @@ impl CodeGenerator {
             output: String::new(),
             aliases: HashMap::new(),
             known_functions: HashSet::new(),
+            raw_functions: HashSet::new(),
+            function_returns: HashMap::new(),
+            levels: HashMap::new(),
+            raw_body: false,
+            raw_operands: false,
+            bound_names: HashSet::new(),
+            ref_captures: HashSet::new(),
+            current_file: None,
         }
     }
 
@@ -37,6 +63,8 @@ impl CodeGenerator {
         self.indent_level = 0;
         self.aliases.clear();
         self.known_functions.clear();
+        self.raw_functions.clear();
+        self.function_returns.clear();
 
         for func in std_api::api_functions() {
             self.known_functions.insert(func.name.to_string());
@@ -55,6 +83,11 @@ impl CodeGenerator {
             }
             if let Item::Function(func) = item {
                 self.known_functions.insert(func.name.clone());
+                self.function_returns
+                    .insert(func.name.clone(), func.ret_type.clone());
+                if matches!(func.safety, SafetyLevel::Raw) {
+                    self.raw_functions.insert(func.name.clone());
+                }
             }
         }
 
@@ -73,6 +106,10 @@ impl CodeGenerator {
                     self.generate_struct(s);
                     self.emit("\n");
                 }
+                Item::Enum(def) => {
+                    self.generate_enum(def);
+                    self.emit("\n");
+                }
             }
         }
 
@@ -85,7 +122,13 @@ impl CodeGenerator {
             SafetyLevel::Raw => "pub unsafe fn",
         };
 
+        self.generate_attributes(&func.attributes);
+        if func.is_test {
+            self.emit("#[test]\n");
+        }
+        self.current_file = func.file.clone();
         self.emit(&format!("{} {}(", safety, func.name));
+        self.bound_names = func.args.iter().map(|arg| arg.name.clone()).collect();
         for (i, arg) in func.args.iter().enumerate() {
             if i > 0 {
                 self.emit(", ");
@@ -100,6 +143,9 @@ impl CodeGenerator {
 
         self.emit(" {\n");
         self.indent_level += 1;
+        self.levels.clear();
+        self.raw_body = matches!(func.safety, SafetyLevel::Raw);
+        self.raw_operands = self.raw_body;
 
         for (idx, stmt) in func.body.statements.iter().enumerate() {
             let is_last = idx + 1 == func.body.statements.len();
@@ -116,48 +162,44 @@ impl CodeGenerator {
     fn generate_statement(&mut self, stmt: &Statement, semicolon: bool) -> Result<(), String> {
         self.emit_indent();
         match stmt {
-            Statement::Let(l) => {
+            Statement::Let(l) => self.generate_binding(&l.name, &l.ty, &l.value)?,
+            Statement::LetTuple(l) => {
+                self.emit(&format!("let ({})", l.names.join(", ")));
                 if let Some(ty) = &l.ty {
-                    self.emit(&format!("let {}: {} = ", l.name, Self::type_to_rust(ty)));
-                } else {
-                    self.emit(&format!("let {} = ", l.name));
+                    self.emit(&format!(": {}", Self::type_to_rust(ty)));
                 }
+                self.emit(" = ");
                 self.generate_expression(&l.value)?;
                 self.emit(";\n");
-            }
-            Statement::Const(c) => {
-                if let Some(ty) = &c.ty {
-                    self.emit(&format!("let {}: {} = ", c.name, Self::type_to_rust(ty)));
-                } else {
-                    self.emit(&format!("let {} = ", c.name));
+                for name in &l.names {
+                    self.levels.remove(name);
+                    self.bind_name(name);
                 }
-                self.generate_expression(&c.value)?;
-                self.emit(";\n");
             }
+            Statement::Const(c) => self.generate_binding(&c.name, &c.ty, &c.value)?,
             Statement::If(stmt) => {
                 self.emit("if ");
+                let outer = self.levels.clone();
+                if let Some(pattern) = &stmt.pattern {
+                    self.emit(&format!("let {} = ", Self::render_pattern(pattern)));
+                    self.unbind_pattern(pattern);
+                }
                 self.generate_expression(&stmt.condition)?;
                 self.emit(" {\n");
-                self.indent_level += 1;
-                for inner in &stmt.then_block.statements {
-                    self.generate_statement(inner, true)?;
-                }
-                self.indent_level -= 1;
+                self.generate_scoped_statements(&stmt.then_block.statements, None)?;
+                self.levels = outer;
                 self.emit_indent();
                 self.emit("}");
                 if let Some(else_block) = &stmt.else_block {
                     self.emit(" else {\n");
-                    self.indent_level += 1;
-                    for inner in &else_block.statements {
-                        self.generate_statement(inner, true)?;
-                    }
-                    self.indent_level -= 1;
+                    self.generate_scoped_statements(&else_block.statements, None)?;
                     self.emit_indent();
                     self.emit("}");
                 }
                 self.emit("\n");
             }
             Statement::For(stmt) => {
+                self.bind_name(&stmt.var_name);
                 self.emit(&format!("for {} in ", stmt.var_name));
                 self.generate_expression(&stmt.start)?;
                 if stmt.inclusive {
@@ -167,11 +209,7 @@ impl CodeGenerator {
                 }
                 self.generate_expression(&stmt.end)?;
                 self.emit(" {\n");
-                self.indent_level += 1;
-                for inner in &stmt.body.statements {
-                    self.generate_statement(inner, true)?;
-                }
-                self.indent_level -= 1;
+                self.generate_scoped_statements(&stmt.body.statements, Some(&stmt.var_name))?;
                 self.emit_indent();
                 self.emit("}\n");
             }
@@ -189,7 +227,7 @@ impl CodeGenerator {
         Ok(())
     }
 
-    fn generate_expression(&mut self, expr: &Expression) -> Result<(), String> {
+    pub(super) fn generate_expression(&mut self, expr: &Expression) -> Result<(), String> {
         match expr {
             Expression::Literal(lit) => match lit {
                 Literal::Integer(i) => self.emit(&i.to_string()),
@@ -199,7 +237,12 @@ impl CodeGenerator {
                 )),
                 Literal::Bool(value) => self.emit(if *value { "true" } else { "false" }),
             },
-            Expression::Variable(name) => self.emit(name),
+            Expression::Variable(name) => {
+                self.emit(name);
+                if self.unwraps(name) {
+                    self.emit(".unwrap()");
+                }
+            }
             Expression::Binary { op, left, right } => {
                 self.generate_expression(left)?;
                 let op_str = match op {
@@ -214,6 +257,20 @@ impl CodeGenerator {
                 self.generate_expression(right)?;
             }
             Expression::Ref { mutable, expr } => {
+                if let Expression::Variable(name) = expr.as_ref()
+                    && self.unwraps(name)
+                {
+                    let getter = if *mutable { "get_mut" } else { "get" };
+                    self.emit(&format!("{name}.{getter}()"));
+                    return Ok(());
+                }
+                if let Expression::Variable(name) = expr.as_ref()
+                    && !mutable
+                    && self.ref_captures.contains(name)
+                {
+                    self.emit(name);
+                    return Ok(());
+                }
                 if *mutable {
                     self.emit("&mut ");
                 } else {
@@ -221,45 +278,188 @@ impl CodeGenerator {
                 }
                 self.generate_expression(expr)?;
             }
+            Expression::Call(call) if self.bound_names.contains(&call.func_name) => {
+                self.emit(&format!("{}(", call.func_name));
+                let consumes = self.raw_body || self.consumes_raw(&call.func_name);
+                let outer = std::mem::replace(&mut self.raw_operands, consumes);
+                for (i, arg) in call.args.iter().enumerate() {
+                    if i > 0 {
+                        self.emit(", ");
+                    }
+                    self.generate_expression(arg)?;
+                }
+                self.raw_operands = outer;
+                self.emit(")");
+            }
             Expression::Call(call) => {
                 let func_name = self.resolve_alias_chain(&call.func_name)?;
                 self.ensure_known_function(&func_name)?;
-                if std_api::is_print_function(&func_name) || std_api::is_printl_function(&func_name)
-                {
-                    self.generate_print_call(&func_name, &call.args)?;
-                    return Ok(());
-                }
-                let rendered = Self::render_function_name(&func_name);
-                self.emit(&format!("{rendered}("));
-                for (i, arg) in call.args.iter().enumerate() {
+                let consumes = self.raw_body || self.consumes_raw(&func_name);
+                let outer = std::mem::replace(&mut self.raw_operands, consumes);
+                let result = self.generate_call(&func_name, call);
+                self.raw_operands = outer;
+                result?;
+            }
+            Expression::Block(block) => self.generate_block(block, None)?,
+            Expression::Tuple(elements) => {
+                self.emit("(");
+                for (i, element) in elements.iter().enumerate() {
                     if i > 0 {
                         self.emit(", ");
                     }
-                    self.generate_expression(arg)?;
+                    self.generate_expression(element)?;
                 }
                 self.emit(")");
             }
-            Expression::Block(block) => {
-                if block.unsafe_block {
-                    self.emit("unsafe ");
+            Expression::Field { expr, index } => {
+                self.generate_expression(expr)?;
+                self.emit(&format!(".{index}"));
+            }
+            Expression::Variant {
+                enum_name,
+                variant,
+                args,
+            } => {
+                self.emit(&format!("{enum_name}::{variant}"));
+                if !args.is_empty() {
+                    self.emit("(");
+                    for (i, arg) in args.iter().enumerate() {
+                        if i > 0 {
+                            self.emit(", ");
+                        }
+                        self.generate_expression(arg)?;
+                    }
+                    self.emit(")");
                 }
-                self.emit("{\n");
+            }
+            Expression::Match { value, arms } => {
+                self.emit("match ");
+                self.generate_expression(value)?;
+                self.emit(" {\n");
                 self.indent_level += 1;
-
-                for (idx, stmt) in block.statements.iter().enumerate() {
-                    let is_last = idx + 1 == block.statements.len();
-                    let trailing_semicolon = !is_last;
-                    self.generate_statement(stmt, trailing_semicolon)?;
+                for arm in arms {
+                    self.emit_indent();
+                    self.emit(&format!("{} => ", Self::render_pattern(&arm.pattern)));
+                    let outer = self.levels.clone();
+                    self.unbind_pattern(&arm.pattern);
+                    self.generate_block(&arm.body, None)?;
+                    self.levels = outer;
+                    self.emit("\n");
                 }
-
                 self.indent_level -= 1;
                 self.emit_indent();
                 self.emit("}");
             }
+            Expression::Closure(closure) => self.generate_closure(closure)?,
+            Expression::Cast { expr, ty } => {
+                // The type checker only accepts lossless casts.
+                self.emit(&format!(
+                    "<{} as std::convert::TryFrom<_>>::try_from(",
+                    Self::type_to_rust(ty)
+                ));
+                self.generate_expression(expr)?;
+                self.emit(").expect(\"lossless integer cast\")");
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_call(&mut self, func_name: &str, call: &CallExpr) -> Result<(), String> {
+        if std_api::is_print_function(func_name) || std_api::is_printl_function(func_name) {
+            return self.generate_print_call(func_name, &call.args);
+        }
+        if Self::is_promotion(func_name) {
+            self.generate_promotion(func_name, &call.args)?;
+            self.emit(".unwrap()");
+            return Ok(());
+        }
+        let rendered = Self::render_function_name(func_name);
+        let canonical = std_api::canonical_name(func_name).unwrap_or(func_name);
+        if std_api::is_testing_function(canonical) {
+            return self.generate_testing_call(&rendered, canonical, call);
+        }
+        self.emit(&rendered);
+        if !call.type_args.is_empty() {
+            // The cast builtins also take the source type, which
+            // Rust infers.
+            let type_args: Vec<String> = call.type_args.iter().map(Self::type_to_rust).collect();
+            self.emit(&format!("::<{}, _>", type_args.join(", ")));
+        }
+        self.emit("(");
+        for (i, arg) in call.args.iter().enumerate() {
+            if i > 0 {
+                self.emit(", ");
+            }
+            self.generate_expression(arg)?;
+        }
+        self.emit(")");
+        Ok(())
+    }
+
+    /// Whether a read of `name` unwraps its `Typed` wrapper. A `Raw` binding
+    /// stays wrapped unless it is read where raw values are consumed.
+    fn unwraps(&self, name: &str) -> bool {
+        match self.levels.get(name) {
+            Some(ValueLevel::Raw) => self.raw_operands,
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Emits a block expression. With `tail_level`, the tail expression is
+    /// emitted as a `Typed` value at that level.
+    pub(super) fn generate_block(
+        &mut self,
+        block: &Block,
+        tail_level: Option<ValueLevel>,
+    ) -> Result<(), String> {
+        if block.unsafe_block {
+            self.emit("unsafe ");
+        }
+        self.emit("{\n");
+        self.indent_level += 1;
+        let outer = self.levels.clone();
+
+        for (idx, stmt) in block.statements.iter().enumerate() {
+            let is_last = idx + 1 == block.statements.len();
+            match stmt {
+                Statement::Expr(tail) if is_last && tail_level.is_some() => {
+                    self.emit_indent();
+                    self.generate_typed_expression(tail, tail_level)?;
+                    self.emit("\n");
+                }
+                _ => self.generate_statement(stmt, !is_last)?,
+            }
         }
+
+        self.levels = outer;
+        self.indent_level -= 1;
+        self.emit_indent();
+        self.emit("}");
         Ok(())
     }
 
+    /// Emits the statements of an `if`/`for` body one level deeper. Bindings
+    /// made inside, and `binder` (a loop variable, which is never `Typed`),
+    /// hide outer levels only until the block ends.
+    fn generate_scoped_statements(
+        &mut self,
+        statements: &[Statement],
+        binder: Option<&str>,
+    ) -> Result<(), String> {
+        let outer = self.levels.clone();
+        if let Some(name) = binder {
+            self.levels.remove(name);
+        }
+        self.indent_level += 1;
+        let result = statements
+            .iter()
+            .try_for_each(|inner| self.generate_statement(inner, true));
+        self.indent_level -= 1;
+        self.levels = outer;
+        result
+    }
+
     fn generate_print_call(&mut self, func_name: &str, args: &[Expression]) -> Result<(), String> {
         self.emit("{ ");
         for arg in args {
@@ -274,7 +474,79 @@ impl CodeGenerator {
         Ok(())
     }
 
+    /// Testing builtins take the SAFE? source location as an extra argument;
+    /// `expect_panic` gets its argument as a closure.
+    fn generate_testing_call(
+        &mut self,
+        rendered: &str,
+        canonical: &str,
+        call: &CallExpr,
+    ) -> Result<(), String> {
+        let lazy = canonical == "core::testing::expect_panic";
+        self.emit(&format!("{rendered}("));
+        for arg in &call.args {
+            if lazy {
+                self.emit("|| ");
+            }
+            self.generate_expression(arg)?;
+            self.emit(", ");
+        }
+        let location = std_api::source_location(self.current_file.as_deref(), call.line);
+        self.emit(&format!("\"{}\")", Self::escape_string(&location)));
+        Ok(())
+    }
+
+    /// `#[doc(...)]` becomes `///` lines and `#[inline]` is kept; the other
+    /// attributes only matter to the compiler.
+    fn generate_attributes(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            match (attribute.name.as_str(), attribute.args.as_slice()) {
+                ("doc", [text]) => {
+                    for line in text.lines() {
+                        self.emit(&format!("/// {line}\n"));
+                    }
+                }
+                ("inline", _) => self.emit("#[inline]\n"),
+                _ => {}
+            }
+        }
+    }
+
+    fn generate_enum(&mut self, def: &Enum) {
+        self.generate_attributes(&def.attributes);
+        self.emit(&format!("pub enum {} {{\n", def.name));
+        self.indent_level += 1;
+        for variant in &def.variants {
+            self.emit_indent();
+            self.emit(&variant.name);
+            if !variant.payload.is_empty() {
+                let payload: Vec<String> = variant.payload.iter().map(Self::type_to_rust).collect();
+                self.emit(&format!("({})", payload.join(", ")));
+            }
+            self.emit(",\n");
+        }
+        self.indent_level -= 1;
+        self.emit("}\n");
+    }
+
+    /// Pattern bindings are plain values, hiding any `Typed` binding of the
+    /// same name.
+    fn unbind_pattern(&mut self, pattern: &Pattern) {
+        for name in pattern.bindings() {
+            self.levels.remove(name);
+            self.bind_name(name);
+        }
+    }
+
+    /// Records a binding of the function being generated; it hides a
+    /// by-reference capture of the same name.
+    pub(super) fn bind_name(&mut self, name: &str) {
+        self.ref_captures.remove(name);
+        self.bound_names.insert(name.to_string());
+    }
+
     fn generate_struct(&mut self, s: &Struct) {
+        self.generate_attributes(&s.attributes);
         self.emit(&format!("pub struct {} {{\n", s.name));
         self.indent_level += 1;
         for f in &s.fields {
@@ -0,0 +1,231 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Safety levels of generated values.
+//!
+//! Bindings produced by raw operations, `validate_raw` and `into_high` are
+//! emitted as `safe_lang::type_system::{Raw, Validated, High}<T>`. A `Raw<T>`
+//! binding keeps its wrapper when it is a block's value or bound again, and
+//! in a safe function it is only unwrapped as an argument of a raw operation
+//! or of the memory runtime. Handing it to anything else is a type error in
+//! the emitted Rust, as is reading it outside `unsafe`.
+
+use super::CodeGenerator;
+use crate::ast::*;
+use crate::std_api;
+use std::collections::HashMap;
+
+const VALIDATE_RAW: &str = "core::memory::safe::validate_raw";
+const INTO_HIGH: &str = "core::memory::safe::into_high";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ValueLevel {
+    Raw,
+    Validated,
+    High,
+}
+
+impl ValueLevel {
+    fn wrapper(self) -> &'static str {
+        match self {
+            Self::Raw => "safe_lang::type_system::Raw",
+            Self::Validated => "safe_lang::type_system::Validated",
+            Self::High => "safe_lang::type_system::High",
+        }
+    }
+}
+
+impl CodeGenerator {
+    fn is_raw_operation(&self, name: &str) -> bool {
+        name.starts_with("raw_") || name.contains("::raw::") || self.raw_functions.contains(name)
+    }
+
+    fn return_type_of(&self, name: &str) -> Option<String> {
+        if let Some(ret) = self.function_returns.get(name) {
+            return ret.as_ref().map(Self::type_name);
+        }
+        std_api::api_functions()
+            .iter()
+            .find(|f| f.name == name || f.canonical == name)
+            .and_then(|f| f.ret)
+            .map(str::to_string)
+    }
+
+    fn type_name(ty: &Type) -> String {
+        match ty {
+            Type::Path(name) => std_api::normalize_type_name(name),
+            _ => String::new(),
+        }
+    }
+
+    fn call_level(&self, name: &str) -> Option<ValueLevel> {
+        match name {
+            VALIDATE_RAW => return Some(ValueLevel::Validated),
+            INTO_HIGH => return Some(ValueLevel::High),
+            _ => {}
+        }
+        let ret = self.return_type_of(name)?;
+        if ret == "()" {
+            return None;
+        }
+        if self.is_raw_operation(name) {
+            return Some(ValueLevel::Raw);
+        }
+        match ret.as_str() {
+            "core::memory::safe::ValidatedPtr" => Some(ValueLevel::Validated),
+            "core::memory::safe::HighPtr" => Some(ValueLevel::High),
+            _ => None,
+        }
+    }
+
+    /// Whether a call may read the value of a `Raw` argument: a raw
+    /// operation, the memory runtime, which checks what it is given, or a
+    /// print or assertion, which only looks at it. Reading it is still
+    /// `unsafe`, so outside an `unsafe` block rustc rejects the call.
+    pub(super) fn consumes_raw(&self, name: &str) -> bool {
+        let canonical = std_api::canonical_name(name).unwrap_or(name);
+        self.is_raw_operation(name)
+            || canonical.starts_with("core::memory::")
+            || std_api::is_print_function(name)
+            || std_api::is_printl_function(name)
+            || std_api::is_testing_function(canonical)
+    }
+
+    pub(super) fn expr_level(&self, expr: &Expression) -> Option<ValueLevel> {
+        self.level_in(expr, &self.levels)
+    }
+
+    /// Level of `expr` with `levels` for the bindings in scope, so a block's
+    /// tail sees the bindings made earlier in the block.
+    fn level_in(
+        &self,
+        expr: &Expression,
+        levels: &HashMap<String, ValueLevel>,
+    ) -> Option<ValueLevel> {
+        match expr {
+            Expression::Variable(name) => levels.get(name).copied(),
+            Expression::Call(call) => {
+                let name = self.resolve_alias_chain(&call.func_name).ok()?;
+                self.call_level(&name)
+            }
+            Expression::Block(block) => {
+                let Some((Statement::Expr(tail), body)) = block.statements.split_last() else {
+                    return None;
+                };
+                let mut scoped = levels.clone();
+                for stmt in body {
+                    match stmt {
+                        Statement::Let(LetStatement { name, value, .. })
+                        | Statement::Const(ConstStatement { name, value, .. }) => {
+                            match self.level_in(value, &scoped) {
+                                Some(level) => scoped.insert(name.clone(), level),
+                                None => scoped.remove(name),
+                            };
+                        }
+                        Statement::LetTuple(l) => l.names.iter().for_each(|name| {
+                            scoped.remove(name);
+                        }),
+                        _ => {}
+                    }
+                }
+                self.level_in(tail, &scoped)
+            }
+            _ => None,
+        }
+    }
+
+    /// Emits `let name[: ty] = value;`, keeping the value's safety level.
+    pub(super) fn generate_binding(
+        &mut self,
+        name: &str,
+        ty: &Option<Type>,
+        value: &Expression,
+    ) -> Result<(), String> {
+        self.bind_name(name);
+        let level = self.expr_level(value);
+        match (ty, level) {
+            (Some(ty), Some(level)) => self.emit(&format!(
+                "let {name}: {}<{}> = ",
+                level.wrapper(),
+                Self::type_to_rust(ty)
+            )),
+            (Some(ty), None) => self.emit(&format!("let {name}: {} = ", Self::type_to_rust(ty))),
+            (None, _) => self.emit(&format!("let {name} = ")),
+        }
+        self.generate_typed_expression(value, level)?;
+        self.emit(";\n");
+        match level {
+            Some(level) => self.levels.insert(name.to_string(), level),
+            None => self.levels.remove(name),
+        };
+        Ok(())
+    }
+
+    /// Emits `expr` as a `Typed` value at `level`, wrapping plain values.
+    pub(super) fn generate_typed_expression(
+        &mut self,
+        expr: &Expression,
+        level: Option<ValueLevel>,
+    ) -> Result<(), String> {
+        let Some(level) = level else {
+            return self.generate_expression(expr);
+        };
+        let own = self.expr_level(expr);
+        match expr {
+            Expression::Variable(name) if own.is_some() => {
+                self.emit(name);
+                Ok(())
+            }
+            Expression::Block(block) if own.is_some() => self.generate_block(block, Some(level)),
+            Expression::Call(call) if own.is_some() => {
+                let name = self.resolve_alias_chain(&call.func_name)?;
+                if Self::is_promotion(&name) {
+                    return self.generate_promotion(&name, &call.args);
+                }
+                self.emit(&format!("{}::new(", level.wrapper()));
+                self.generate_expression(expr)?;
+                self.emit(")");
+                Ok(())
+            }
+            // Plain values (function arguments, untagged calls) enter the
+            // chain at the requested level.
+            _ => {
+                self.emit(&format!("{}::new(", level.wrapper()));
+                self.generate_expression(expr)?;
+                self.emit(")");
+                Ok(())
+            }
+        }
+    }
+
+    /// Emits `validate_raw`/`into_high` through their typed forms, which only
+    /// accept `Raw<RawPtr>` and `Validated<ValidatedPtr>` respectively.
+    pub(super) fn generate_promotion(
+        &mut self,
+        name: &str,
+        args: &[Expression],
+    ) -> Result<(), String> {
+        let (func, input) = if name == VALIDATE_RAW {
+            ("validate_raw_ptr", ValueLevel::Raw)
+        } else {
+            ("into_high_ptr", ValueLevel::Validated)
+        };
+        self.emit(&format!("safe_lang::type_system::{func}("));
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                self.emit(", ");
+            }
+            self.generate_typed_expression(arg, Some(input))?;
+        }
+        self.emit(")");
+        Ok(())
+    }
+
+    pub(super) fn is_promotion(name: &str) -> bool {
+        name == VALIDATE_RAW || name == INTO_HIGH
+    }
+}
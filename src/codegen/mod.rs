@@ -5,8 +5,10 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+mod closures;
 mod emit;
 mod generator;
+mod levels;
 mod render;
 mod resolve;
 
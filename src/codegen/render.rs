@@ -6,7 +6,7 @@
 // See: https://opensource.org/licenses/MIT
 
 use super::CodeGenerator;
-use crate::ast::Type;
+use crate::ast::{Pattern, Type};
 
 impl CodeGenerator {
     pub(super) fn type_to_rust(ty: &Type) -> String {
@@ -20,6 +20,58 @@ impl CodeGenerator {
                     format!("&{}", Self::type_to_rust(inner))
                 }
             }
+            Type::Tuple(elements) => format!(
+                "({})",
+                elements
+                    .iter()
+                    .map(Self::type_to_rust)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            // Closure types have no name in Rust; they only appear as
+            // parameters of runtime functions.
+            Type::Fn {
+                closure: true,
+                params,
+                ret,
+                ..
+            } => format!(
+                "impl FnMut({}) -> {}",
+                params
+                    .iter()
+                    .map(Self::type_to_rust)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::type_to_rust(ret)
+            ),
+            Type::Fn {
+                raw, params, ret, ..
+            } => format!(
+                "{}fn({}) -> {}",
+                if *raw { "unsafe " } else { "" },
+                params
+                    .iter()
+                    .map(Self::type_to_rust)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::type_to_rust(ret)
+            ),
+        }
+    }
+
+    pub(super) fn render_pattern(pattern: &Pattern) -> String {
+        match pattern {
+            Pattern::Wildcard => "_".to_string(),
+            Pattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+            } if bindings.is_empty() => format!("{enum_name}::{variant}"),
+            Pattern::Variant {
+                enum_name,
+                variant,
+                bindings,
+            } => format!("{enum_name}::{variant}({})", bindings.join(", ")),
         }
     }
 
@@ -43,6 +95,16 @@ impl CodeGenerator {
             return format!("[{}]", Self::type_path_to_rust(inner));
         }
 
+        if let Some(inner) = name.strip_prefix('(').and_then(|n| n.strip_suffix(')'))
+            && !inner.is_empty()
+        {
+            let elements = Self::split_type_args(inner)
+                .into_iter()
+                .map(Self::type_path_to_rust)
+                .collect::<Vec<_>>();
+            return format!("({})", elements.join(", "));
+        }
+
         match name {
             "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "usize" | "isize"
             | "bool" | "char" | "()" => name.to_string(),
@@ -56,9 +118,12 @@ impl CodeGenerator {
             }
             "core::types::List" => "safe_lang::core::types::List".to_string(),
             "HighPtr" => "safe_lang::core::memory::safe::HighPtr".to_string(),
+            "HighView" => "safe_lang::core::memory::safe::HighView".to_string(),
             "ValidatedPtr" => "safe_lang::core::memory::safe::ValidatedPtr".to_string(),
             "RawPtr" => "safe_lang::core::memory::raw::RawPtr".to_string(),
+            "MemoryError" => "safe_lang::core::memory::MemoryError".to_string(),
             "core::memory::safe::HighPtr"
+            | "core::memory::safe::HighView"
             | "core::memory::safe::ValidatedPtr"
             | "core::memory::raw::RawPtr" => Self::render_type_path(name),
             other if other.starts_with("Option<") => {
@@ -70,7 +135,7 @@ impl CodeGenerator {
             }
             other if other.starts_with("Result<") => {
                 let inner = other.trim_start_matches("Result<").trim_end_matches('>');
-                let mut parts = inner.split(',').map(|p| p.trim());
+                let mut parts = Self::split_type_args(inner).into_iter();
                 let ok_ty = parts.next().unwrap_or("()");
                 let err_ty = parts.next().unwrap_or("()");
                 format!(
@@ -92,7 +157,7 @@ impl CodeGenerator {
                 let inner = other
                     .trim_start_matches("core::types::Result<")
                     .trim_end_matches('>');
-                let mut parts = inner.split(',').map(|p| p.trim());
+                let mut parts = Self::split_type_args(inner).into_iter();
                 let ok_ty = parts.next().unwrap_or("()");
                 let err_ty = parts.next().unwrap_or("()");
                 format!(
@@ -105,6 +170,26 @@ impl CodeGenerator {
         }
     }
 
+    /// Splits generic or tuple arguments at the commas outside brackets.
+    fn split_type_args(text: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (idx, ch) in text.char_indices() {
+            match ch {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    parts.push(text[start..idx].trim());
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(text[start..].trim());
+        parts
+    }
+
     pub(super) fn escape_string(input: &str) -> String {
         let mut out = String::with_capacity(input.len());
         for ch in input.chars() {
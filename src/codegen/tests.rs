@@ -192,7 +192,7 @@ safe fn process() {
 
     assert!(rust_code.contains("core::memory::safe::allocate_buffer(1)"));
     assert!(rust_code.contains("core::memory::raw::alloc(1)"));
-    assert!(rust_code.contains("core::memory::raw::write(raw_ptr, 0, 1)"));
+    assert!(rust_code.contains("core::memory::raw::write(raw_ptr.unwrap(), 0, 1)"));
 }
 
 #[test]
@@ -300,3 +300,393 @@ safe fn test() {
     assert!(rust_code.contains("safe_lang::core::types::print_any(&("));
     assert!(rust_code.contains("std::println!();"));
 }
+
+#[test]
+fn test_codegen_passes_predicate_to_validate_raw_with() {
+    let code = r#"
+raw fn is_packet(raw_pkt: RawPtr, raw_len: usize) -> bool {
+    raw_read(raw_pkt, 0) == 1
+}
+
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_res = validate_raw_with(raw_x, is_packet)
+        printl(validation_is_ok(&validated_res))
+    }
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(
+        rust_code
+            .contains("safe_lang::core::memory::raw::validate_with(raw_x.unwrap(), is_packet)")
+    );
+}
+
+#[test]
+fn test_codegen_try_apis_and_memory_error_type() {
+    let code = r#"
+safe fn test() {
+    let high_res: Result<HighPtr, MemoryError> = try_allocate_buffer(4)
+    if result_is_ok(high_res) {
+        let high_buf = result_unwrap(high_res)
+        let high_freed = try_deallocate_buffer(high_buf)
+        if result_is_err(high_freed) {
+            let high_code = memory_error_code(result_unwrap_err(high_freed))
+        }
+    }
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(rust_code.contains(
+        "safe_lang::core::types::Result<safe_lang::core::memory::safe::HighPtr, safe_lang::core::memory::MemoryError>"
+    ));
+    assert!(rust_code.contains("safe_lang::core::memory::safe::try_allocate_buffer(4)"));
+    assert!(rust_code.contains("safe_lang::core::types::result_unwrap_err(high_freed)"));
+}
+
+#[test]
+fn test_codegen_wraps_promotion_chain_in_typed_levels() {
+    let code = r#"
+safe fn promote() -> HighPtr {
+    unsafe {
+        let raw_p = raw_alloc(4)
+        raw_write(raw_p, 0, 7)
+        let validated_p = validate_raw(raw_p)
+        into_high(validated_p)
+    }
+}
+
+safe fn main() {
+    let high_p = promote()
+    deallocate_buffer(high_p)
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(rust_code.contains(
+        "let raw_p = safe_lang::type_system::Raw::new(safe_lang::core::memory::raw::alloc(4));"
+    ));
+    assert!(rust_code.contains("safe_lang::core::memory::raw::write(raw_p.unwrap(), 0, 7)"));
+    assert!(
+        rust_code.contains("let validated_p = safe_lang::type_system::validate_raw_ptr(raw_p);")
+    );
+    assert!(rust_code.contains("safe_lang::type_system::into_high_ptr(validated_p).unwrap()"));
+    assert!(rust_code.contains("let high_p = safe_lang::type_system::High::new(promote());"));
+    assert!(rust_code.contains("deallocate_buffer(high_p.unwrap())"));
+}
+
+#[test]
+fn test_codegen_restores_levels_after_shadowing_block() {
+    let code = r#"
+safe fn promote() -> HighPtr {
+    unsafe {
+        let raw_p = raw_alloc(4)
+        let validated_p = validate_raw(raw_p)
+        into_high(validated_p)
+    }
+}
+
+safe fn main() {
+    let high_p = promote()
+    if true {
+        let high_p = 3
+        let high_q = high_p
+    }
+    deallocate_buffer(high_p)
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(rust_code.contains("let high_q = high_p;"));
+    assert!(rust_code.contains("deallocate_buffer(high_p.unwrap())"));
+}
+
+#[test]
+fn test_codegen_keeps_raw_values_wrapped_outside_raw_operations() {
+    let code = r#"
+safe fn double(high_x: u8) -> u8 {
+    high_x
+}
+
+safe fn main() {
+    let high_v = unsafe {
+        let raw_p = raw_alloc(1)
+        raw_write(raw_p, 0, 21)
+        let raw_v = raw_read(raw_p, 0)
+        raw_v
+    }
+    printl(double(high_v))
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(rust_code.contains("safe_lang::core::memory::raw::read(raw_p.unwrap(), 0)"));
+    assert!(!rust_code.contains("raw_v.unwrap()"));
+    assert!(rust_code.contains("double(high_v)"));
+}
+
+#[test]
+fn test_codegen_typed_annotation_and_plain_arguments() {
+    let code = r#"
+raw fn promote_arg(raw_arg: RawPtr) -> HighPtr {
+    let validated_arg: ValidatedPtr = validate_raw(raw_arg)
+    into_high(validated_arg)
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("tokenize");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty());
+
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+
+    let mut checker = TypeChecker::new();
+    checker.check(molder.get_output()).expect("type check");
+
+    let mut generator = CodeGenerator::new();
+    let rust_code = generator.generate(molder.get_output()).expect("codegen");
+
+    assert!(rust_code.contains(
+        "let validated_arg: safe_lang::type_system::Validated<safe_lang::core::memory::safe::ValidatedPtr> = safe_lang::type_system::validate_raw_ptr(safe_lang::type_system::Raw::new(raw_arg));"
+    ));
+}
+
+#[test]
+fn test_codegen_emits_test_functions_with_locations() {
+    let code = r#"
+test fn lengths() {
+    assert_eq(string_len(&"ab"), 2)
+    expect_panic(allocate_buffer(0))
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("#[test]\npub fn lengths()"));
+    assert!(rust_code.contains(
+        "safe_lang::core::testing::assert_eq(safe_lang::core::types::string_len(&safe_lang::core::types::String::from(\"ab\")), 2, \"line 3\")"
+    ));
+    assert!(rust_code.contains(
+        "safe_lang::core::testing::expect_panic(|| safe_lang::core::memory::safe::allocate_buffer(0), \"line 4\")"
+    ));
+}
+
+#[test]
+fn test_codegen_casts() {
+    let code = r#"
+safe fn widen(high_byte: u8) -> i64 {
+    let high_checked = checked_cast::<i8>(high_byte)
+    high_byte as i64
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("safe_lang::core::types::checked_cast::<i8, _>(high_byte)"));
+    assert!(rust_code.contains(
+        "<i64 as std::convert::TryFrom<_>>::try_from(high_byte).expect(\"lossless integer cast\")"
+    ));
+}
+
+#[test]
+fn test_codegen_tuples() {
+    let code = r#"
+safe fn split(high_len: usize) -> (u8, usize) {
+    let (high_tag, high_rest): (u8, usize) = (7, high_len)
+    (high_tag, high_rest)
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("pub fn split(high_len: usize) -> (u8, usize) {"));
+    assert!(rust_code.contains("let (high_tag, high_rest): (u8, usize) = (7, high_len);"));
+    assert!(rust_code.contains("    (high_tag, high_rest)\n"));
+}
+
+#[test]
+fn test_codegen_enums() {
+    let code = r#"
+enum Packet {
+    Ping,
+    Data(u16),
+}
+
+safe fn size(high_p: Packet) -> u16 {
+    if let Packet::Ping = high_p {
+        let high_ping = true
+    }
+    match high_p {
+        Packet::Ping => { 0 }
+        Packet::Data(high_len) => { high_len }
+    }
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("pub enum Packet {\n    Ping,\n    Data(u16),\n}"));
+    assert!(rust_code.contains("if let Packet::Ping = high_p {"));
+    assert!(rust_code.contains("match high_p {"));
+    assert!(rust_code.contains("Packet::Data(high_len) => "));
+}
+
+#[test]
+fn test_codegen_function_values() {
+    let code = r#"
+raw fn peek(raw_x: u8) -> u8 {
+    raw_x
+}
+
+safe fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+safe fn test() {
+    let high_v = apply(identity, 1)
+    unsafe {
+        let raw_f: raw fn(u8) -> u8 = peek
+        let raw_v = raw_f(2)
+    }
+}
+
+safe fn identity(high_x: u8) -> u8 {
+    high_x
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("pub fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {"));
+    assert!(rust_code.contains("    high_f(high_x)\n"));
+    assert!(rust_code.contains("apply(identity, 1)"));
+    assert!(rust_code.contains("let raw_f: unsafe fn(u8) -> u8 = peek;"));
+    assert!(rust_code.contains("let raw_v = raw_f(2);"));
+}
+
+#[test]
+fn test_codegen_closures() {
+    let code = r#"
+safe fn test() {
+    let high_table = list_new()
+    let high_limit: u8 = 3
+    let high_small = list_filter_u8(&high_table, |high_x| high_x < high_limit)
+    let high_lookup = |high_i: usize| list_get_u8(&high_table, high_i)
+    let high_first = high_lookup(0)
+}
+"#;
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (_, source) = parser::parse(&tokens).expect("parse");
+    let mut molder = Molder::new(source);
+    molder.mold().expect("mold");
+    TypeChecker::new()
+        .check(molder.get_output())
+        .expect("type check");
+
+    let rust_code = CodeGenerator::new()
+        .generate(molder.get_output())
+        .expect("codegen");
+    assert!(rust_code.contains("list_filter_u8(&high_table, move |high_x| high_x < high_limit)"));
+    assert!(rust_code.contains(
+        "let high_lookup = { let high_table = &high_table; move |high_i: usize| safe_lang::core::types::list_get_u8(high_table, high_i) };"
+    ));
+    assert!(rust_code.contains("let high_first = high_lookup(0);"));
+}
@@ -0,0 +1,263 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::alloc::Layout;
+use std::sync::{Mutex, OnceLock};
+
+/// Backend that provides the bytes behind every `HighPtr` and `RawPtr`.
+///
+/// Implementations hand out zeroed blocks and get them back with the same
+/// size they were allocated with. Size tracking and bounds checks stay in
+/// `core::memory::safe` / `core::memory::raw`; the backend only owns bytes.
+pub trait Allocator: Send + Sync {
+    /// Returns a zeroed block of `size` bytes, or null when exhausted.
+    /// `size` is always greater than zero.
+    fn allocate(&self, size: usize) -> *mut u8;
+
+    /// Releases a block previously returned by [`Allocator::allocate`].
+    ///
+    /// # Safety
+    /// `ptr` must come from `allocate` on this allocator with the same
+    /// `size`, and must not be released twice.
+    unsafe fn deallocate(&self, ptr: *mut u8, size: usize);
+
+    fn name(&self) -> &'static str;
+}
+
+/// Global-heap backend. Used when no other allocator is installed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemAllocator;
+
+impl Allocator for SystemAllocator {
+    fn allocate(&self, size: usize) -> *mut u8 {
+        let Ok(layout) = Layout::array::<u8>(size) else {
+            return std::ptr::null_mut();
+        };
+        unsafe { std::alloc::alloc_zeroed(layout) }
+    }
+
+    unsafe fn deallocate(&self, ptr: *mut u8, size: usize) {
+        let layout = Layout::array::<u8>(size).expect("system allocator layout");
+        unsafe { std::alloc::dealloc(ptr, layout) };
+    }
+
+    fn name(&self) -> &'static str {
+        "system"
+    }
+}
+
+/// Fixed-capacity region owned by an arena or pool backend.
+#[derive(Debug)]
+struct Region {
+    base: usize,
+    layout: Layout,
+}
+
+impl Region {
+    fn new(capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("allocator capacity must be > 0");
+        }
+        let layout = Layout::array::<u8>(capacity).expect("allocator capacity too large");
+        let base = unsafe { std::alloc::alloc_zeroed(layout) };
+        if base.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        Self {
+            base: base as usize,
+            layout,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.layout.size()
+    }
+
+    fn contains(&self, ptr: *mut u8, size: usize) -> bool {
+        let addr = ptr as usize;
+        addr >= self.base && addr + size <= self.base + self.capacity()
+    }
+
+    fn block(&self, offset: usize, size: usize) -> *mut u8 {
+        let ptr = (self.base + offset) as *mut u8;
+        unsafe { std::ptr::write_bytes(ptr, 0, size) };
+        ptr
+    }
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe { std::alloc::dealloc(self.base as *mut u8, self.layout) };
+    }
+}
+
+#[derive(Debug, Default)]
+struct ArenaState {
+    top: usize,
+    live: usize,
+}
+
+/// Bump allocator over a fixed region.
+///
+/// Releasing the most recent block rolls the bump pointer back, and the
+/// whole arena is reused once every block has been released. Otherwise
+/// freed space is not reclaimed, so total use is bounded by `capacity`.
+#[derive(Debug)]
+pub struct ArenaAllocator {
+    region: Region,
+    state: Mutex<ArenaState>,
+}
+
+impl ArenaAllocator {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            region: Region::new(capacity),
+            state: Mutex::new(ArenaState::default()),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.region.capacity()
+    }
+
+    pub fn used(&self) -> usize {
+        self.state.lock().expect("arena lock poisoned").top
+    }
+}
+
+impl Allocator for ArenaAllocator {
+    fn allocate(&self, size: usize) -> *mut u8 {
+        let mut state = self.state.lock().expect("arena lock poisoned");
+        let Some(end) = state.top.checked_add(size) else {
+            return std::ptr::null_mut();
+        };
+        if end > self.capacity() {
+            return std::ptr::null_mut();
+        }
+        let ptr = self.region.block(state.top, size);
+        state.top = end;
+        state.live += 1;
+        ptr
+    }
+
+    unsafe fn deallocate(&self, ptr: *mut u8, size: usize) {
+        if !self.region.contains(ptr, size) {
+            panic!("arena allocator does not own this block");
+        }
+        let mut state = self.state.lock().expect("arena lock poisoned");
+        state.live = state
+            .live
+            .checked_sub(1)
+            .unwrap_or_else(|| panic!("arena allocator released more blocks than allocated"));
+        let offset = ptr as usize - self.region.base;
+        if state.live == 0 {
+            state.top = 0;
+        } else if offset + size == state.top {
+            state.top = offset;
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "arena"
+    }
+}
+
+/// Fixed-size block allocator.
+///
+/// Requests larger than `block_size` fail, and at most `block_count`
+/// blocks can be live at once.
+#[derive(Debug)]
+pub struct PoolAllocator {
+    region: Region,
+    block_size: usize,
+    free: Mutex<Vec<usize>>,
+}
+
+impl PoolAllocator {
+    pub fn new(block_size: usize, block_count: usize) -> Self {
+        if block_size == 0 || block_count == 0 {
+            panic!("pool allocator block size and count must be > 0");
+        }
+        let capacity = block_size
+            .checked_mul(block_count)
+            .unwrap_or_else(|| panic!("pool allocator capacity overflow"));
+        Self {
+            region: Region::new(capacity),
+            block_size,
+            free: Mutex::new((0..block_count).rev().collect()),
+        }
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    pub fn available_blocks(&self) -> usize {
+        self.free.lock().expect("pool lock poisoned").len()
+    }
+}
+
+impl Allocator for PoolAllocator {
+    fn allocate(&self, size: usize) -> *mut u8 {
+        if size > self.block_size {
+            return std::ptr::null_mut();
+        }
+        let Some(index) = self.free.lock().expect("pool lock poisoned").pop() else {
+            return std::ptr::null_mut();
+        };
+        self.region.block(index * self.block_size, self.block_size)
+    }
+
+    unsafe fn deallocate(&self, ptr: *mut u8, size: usize) {
+        let offset = (ptr as usize).wrapping_sub(self.region.base);
+        if size > self.block_size
+            || !self.region.contains(ptr, size)
+            || !offset.is_multiple_of(self.block_size)
+        {
+            panic!("pool allocator does not own this block");
+        }
+        self.free
+            .lock()
+            .expect("pool lock poisoned")
+            .push(offset / self.block_size);
+    }
+
+    fn name(&self) -> &'static str {
+        "pool"
+    }
+}
+
+fn installed() -> &'static OnceLock<Box<dyn Allocator>> {
+    static ALLOCATOR: OnceLock<Box<dyn Allocator>> = OnceLock::new();
+    &ALLOCATOR
+}
+
+/// Selects the allocator for the rest of the program.
+///
+/// Must run before the first allocation; once any buffer has been
+/// allocated the backend is fixed and this returns an error.
+pub fn install(allocator: Box<dyn Allocator>) -> Result<(), String> {
+    installed()
+        .set(allocator)
+        .map_err(|_| format!("allocator already selected: {}", current().name()))
+}
+
+/// Returns the active allocator, selecting [`SystemAllocator`] on first use
+/// if nothing was installed.
+pub fn current() -> &'static dyn Allocator {
+    installed()
+        .get_or_init(|| Box::new(SystemAllocator))
+        .as_ref()
+}
+
+pub fn use_arena_allocator(capacity: usize) -> bool {
+    install(Box::new(ArenaAllocator::new(capacity))).is_ok()
+}
+
+pub fn use_pool_allocator(block_size: usize, block_count: usize) -> bool {
+    install(Box::new(PoolAllocator::new(block_size, block_count))).is_ok()
+}
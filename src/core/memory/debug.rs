@@ -0,0 +1,217 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Debug memory mode, enabled with the `debug-memory` cargo feature.
+//!
+//! Every block gets canary bytes on both sides, freed blocks are poisoned
+//! and held in a quarantine instead of going back to the allocator, and
+//! corruption reports name the site that allocated the block. Without the
+//! feature, [`allocate`] and [`deallocate`] forward straight to the
+//! installed allocator.
+
+use std::collections::{HashMap, VecDeque};
+use std::panic::Location;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use super::allocator;
+
+pub const CANARY_LEN: usize = 16;
+pub const CANARY_BYTE: u8 = 0xCA;
+pub const POISON_BYTE: u8 = 0xDD;
+
+/// Freed bytes kept in quarantine before blocks are handed back.
+pub const QUARANTINE_BYTES: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    size: usize,
+    site: &'static Location<'static>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Quarantined {
+    addr: usize,
+    block: Block,
+    freed_at: &'static Location<'static>,
+}
+
+#[derive(Debug, Default)]
+struct DebugState {
+    live: HashMap<usize, Block>,
+    quarantine: VecDeque<Quarantined>,
+    quarantined_bytes: usize,
+}
+
+fn state() -> MutexGuard<'static, DebugState> {
+    static STATE: OnceLock<Mutex<DebugState>> = OnceLock::new();
+    STATE
+        .get_or_init(|| Mutex::new(DebugState::default()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub const fn enabled() -> bool {
+    cfg!(feature = "debug-memory")
+}
+
+fn base_of(addr: usize) -> *mut u8 {
+    (addr - CANARY_LEN) as *mut u8
+}
+
+fn guarded_len(size: usize) -> usize {
+    size + 2 * CANARY_LEN
+}
+
+fn bytes(addr: usize, len: usize) -> &'static [u8] {
+    unsafe { std::slice::from_raw_parts(addr as *const u8, len) }
+}
+
+fn check_canaries(addr: usize, block: Block) -> Result<(), String> {
+    let before = bytes(addr - CANARY_LEN, CANARY_LEN);
+    let after = bytes(addr + block.size, CANARY_LEN);
+    let side = if before.iter().any(|b| *b != CANARY_BYTE) {
+        "before"
+    } else if after.iter().any(|b| *b != CANARY_BYTE) {
+        "after"
+    } else {
+        return Ok(());
+    };
+    Err(format!(
+        "debug memory: canary {side} block {addr:#x} ({} bytes, allocated at {}) was overwritten",
+        block.size, block.site
+    ))
+}
+
+fn check_poison(entry: &Quarantined) -> Result<(), String> {
+    let guarded = bytes(entry.addr - CANARY_LEN, guarded_len(entry.block.size));
+    if guarded.iter().all(|b| *b == POISON_BYTE) {
+        return Ok(());
+    }
+    Err(format!(
+        "debug memory: freed block {:#x} ({} bytes, allocated at {}, freed at {}) was written after free",
+        entry.addr, entry.block.size, entry.block.site, entry.freed_at
+    ))
+}
+
+/// Returns a zeroed block of `size` bytes from the installed allocator,
+/// or null when exhausted. In debug mode the block is surrounded by canaries
+/// and `site` is remembered for reports.
+pub(crate) fn allocate(size: usize, site: &'static Location<'static>) -> *mut u8 {
+    if !enabled() {
+        return allocator::current().allocate(size);
+    }
+
+    let Some(total) = size.checked_add(2 * CANARY_LEN) else {
+        return std::ptr::null_mut();
+    };
+    let base = allocator::current().allocate(total);
+    if base.is_null() {
+        return base;
+    }
+    unsafe {
+        std::ptr::write_bytes(base, CANARY_BYTE, CANARY_LEN);
+        std::ptr::write_bytes(base.add(CANARY_LEN + size), CANARY_BYTE, CANARY_LEN);
+    }
+    let ptr = unsafe { base.add(CANARY_LEN) };
+    state().live.insert(ptr as usize, Block { size, site });
+    ptr
+}
+
+/// Releases a block returned by [`allocate`].
+///
+/// In debug mode the canaries are verified, the block is poisoned and put
+/// in quarantine, and the oldest quarantined blocks are checked for writes
+/// after free before they go back to the allocator.
+///
+/// # Safety
+/// `ptr` must come from [`allocate`] with the same `size` and must not have
+/// been released before.
+pub(crate) unsafe fn deallocate(ptr: *mut u8, size: usize, freed_at: &'static Location<'static>) {
+    if !enabled() {
+        unsafe { allocator::current().deallocate(ptr, size) };
+        return;
+    }
+
+    let addr = ptr as usize;
+    let mut state = state();
+    let block = state.live.remove(&addr).unwrap_or(Block {
+        size,
+        site: freed_at,
+    });
+    if let Err(report) = check_canaries(addr, block) {
+        drop(state);
+        panic!("{report}; detected when freed at {freed_at}");
+    }
+
+    unsafe { std::ptr::write_bytes(base_of(addr), POISON_BYTE, guarded_len(size)) };
+    state.quarantine.push_back(Quarantined {
+        addr,
+        block,
+        freed_at,
+    });
+    state.quarantined_bytes += guarded_len(size);
+
+    let mut released = Vec::new();
+    while state.quarantined_bytes > QUARANTINE_BYTES {
+        let Some(oldest) = state.quarantine.pop_front() else {
+            break;
+        };
+        state.quarantined_bytes -= guarded_len(oldest.block.size);
+        if let Err(report) = check_poison(&oldest) {
+            drop(state);
+            panic!("{report}");
+        }
+        released.push(oldest);
+    }
+    drop(state);
+
+    for entry in released {
+        unsafe {
+            allocator::current().deallocate(base_of(entry.addr), guarded_len(entry.block.size))
+        };
+    }
+}
+
+/// Describes what debug mode knows about `addr`, for "ptr is invalid"
+/// reports. Empty when the mode is off or the address is unknown.
+pub(crate) fn describe(addr: usize) -> String {
+    if !enabled() {
+        return String::new();
+    }
+    let state = state();
+    if let Some(entry) = state.quarantine.iter().rev().find(|q| q.addr == addr) {
+        return format!(
+            " (freed block of {} bytes, allocated at {}, freed at {})",
+            entry.block.size, entry.block.site, entry.freed_at
+        );
+    }
+    String::new()
+}
+
+/// Checks the canaries of every live block and the poison of every
+/// quarantined block. Always `Ok` when the mode is off.
+pub fn verify_heap() -> Result<(), String> {
+    if !enabled() {
+        return Ok(());
+    }
+    let state = state();
+    for (addr, block) in &state.live {
+        check_canaries(*addr, *block)?;
+    }
+    for entry in &state.quarantine {
+        check_poison(entry)?;
+    }
+    Ok(())
+}
+
+/// Number of freed blocks currently held in quarantine.
+pub fn quarantined_blocks() -> usize {
+    if !enabled() {
+        return 0;
+    }
+    state().quarantine.len()
+}
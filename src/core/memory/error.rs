@@ -0,0 +1,61 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::fmt;
+
+/// Failure reported by the `try_` variants of the memory runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryError {
+    NullPointer,
+    Untracked,
+    OutOfBounds,
+    Overflow,
+    DoubleFree,
+    ZeroSize,
+    /// The installed allocator could not provide the block.
+    OutOfMemory,
+    /// The allocation failed the check of a validating call.
+    Rejected,
+}
+
+impl MemoryError {
+    /// Stable numeric code, usable from SAFE? via `memory_error_code`.
+    pub fn code(self) -> i32 {
+        match self {
+            Self::NullPointer => 1,
+            Self::Untracked => 2,
+            Self::OutOfBounds => 3,
+            Self::Overflow => 4,
+            Self::DoubleFree => 5,
+            Self::ZeroSize => 6,
+            Self::OutOfMemory => 7,
+            Self::Rejected => 8,
+        }
+    }
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::NullPointer => "pointer must be non-null",
+            Self::Untracked => "pointer is not a live tracked allocation",
+            Self::OutOfBounds => "range out of bounds",
+            Self::Overflow => "range overflow",
+            Self::DoubleFree => "allocation already deallocated",
+            Self::ZeroSize => "size must be > 0",
+            Self::OutOfMemory => "out of memory",
+            Self::Rejected => "allocation failed the validation check",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+pub fn memory_error_code(error: MemoryError) -> i32 {
+    error.code()
+}
@@ -5,5 +5,11 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+pub mod allocator;
+pub mod debug;
+mod error;
 pub mod raw;
 pub mod safe;
+pub mod tracking;
+
+pub use error::{MemoryError, memory_error_code};
@@ -5,8 +5,14 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::panic::Location;
+use std::sync::OnceLock;
+
+use super::MemoryError;
+use super::debug;
+use super::safe::{self, ValidatedPtr};
+use super::tracking::AllocationTable;
+use crate::core::types;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RawPtr(*mut u8);
@@ -29,27 +35,22 @@ impl RawPtr {
     }
 }
 
-fn allocations() -> &'static Mutex<HashMap<usize, usize>> {
-    static ALLOCS: OnceLock<Mutex<HashMap<usize, usize>>> = OnceLock::new();
-    ALLOCS.get_or_init(|| Mutex::new(HashMap::new()))
+fn allocations() -> &'static AllocationTable {
+    static ALLOCS: OnceLock<AllocationTable> = OnceLock::new();
+    ALLOCS.get_or_init(AllocationTable::new)
 }
 
 fn track_allocation(ptr: RawPtr, size: usize) {
-    let key = ptr.addr();
-    let mut map = allocations().lock().expect("raw allocations lock poisoned");
-    map.insert(key, size);
+    safe::forget_freed(ptr.addr());
+    allocations().insert(ptr.addr(), size);
 }
 
 pub fn allocation_size(ptr: RawPtr) -> Option<usize> {
-    let key = ptr.addr();
-    let map = allocations().lock().expect("raw allocations lock poisoned");
-    map.get(&key).copied()
+    allocations().get(ptr.addr())
 }
 
 fn take_allocation(ptr: RawPtr) -> Option<usize> {
-    let key = ptr.addr();
-    let mut map = allocations().lock().expect("raw allocations lock poisoned");
-    map.remove(&key)
+    allocations().remove(ptr.addr())
 }
 
 /// Allocates a raw byte buffer and returns an unmanaged raw pointer handle.
@@ -57,14 +58,16 @@ fn take_allocation(ptr: RawPtr) -> Option<usize> {
 /// # Safety
 /// The returned pointer must be passed to [`deallocate`] exactly once.
 /// Callers must ensure reads and writes stay within the allocated size.
+#[track_caller]
 pub unsafe fn alloc(size: usize) -> RawPtr {
     if size == 0 {
         panic!("raw::alloc size must be > 0");
     }
 
-    let mut buf = vec![0u8; size].into_boxed_slice();
-    let ptr = buf.as_mut_ptr();
-    std::mem::forget(buf);
+    let ptr = debug::allocate(size, Location::caller());
+    if ptr.is_null() {
+        panic!("raw::alloc out of memory");
+    }
     let raw_ptr = RawPtr::from_ptr(ptr);
     track_allocation(raw_ptr, size);
     raw_ptr
@@ -75,19 +78,20 @@ pub unsafe fn alloc(size: usize) -> RawPtr {
 /// # Safety
 /// `ptr` must be non-null, currently allocated by [`alloc`], and must not
 /// have been deallocated before. Using `ptr` after this call is invalid.
+#[track_caller]
 pub unsafe fn deallocate(ptr: RawPtr) {
     if ptr.is_null() {
         panic!("raw::deallocate ptr must be non-null");
     }
 
     let Some(len) = take_allocation(ptr) else {
-        panic!("raw::deallocate ptr is invalid or already deallocated");
+        panic!(
+            "raw::deallocate ptr is invalid or already deallocated{}",
+            debug::describe(ptr.addr())
+        );
     };
 
-    unsafe {
-        let slice = std::slice::from_raw_parts_mut(ptr.as_ptr(), len);
-        let _ = Box::from_raw(slice);
-    }
+    unsafe { debug::deallocate(ptr.as_ptr(), len, Location::caller()) };
 }
 
 /// Writes one byte at `offset` within an allocated raw buffer.
@@ -99,7 +103,8 @@ pub unsafe fn write(ptr: RawPtr, offset: usize, value: u8) {
     if ptr.is_null() {
         panic!("raw::write ptr must be non-null");
     }
-    let size = allocation_size(ptr).unwrap_or_else(|| panic!("raw::write ptr is invalid"));
+    let size = allocation_size(ptr)
+        .unwrap_or_else(|| panic!("raw::write ptr is invalid{}", debug::describe(ptr.addr())));
     if offset >= size {
         panic!("raw::write offset out of bounds");
     }
@@ -115,13 +120,208 @@ pub unsafe fn read(ptr: RawPtr, offset: usize) -> u8 {
     if ptr.is_null() {
         panic!("raw::read ptr must be non-null");
     }
-    let size = allocation_size(ptr).unwrap_or_else(|| panic!("raw::read ptr is invalid"));
+    let size = allocation_size(ptr)
+        .unwrap_or_else(|| panic!("raw::read ptr is invalid{}", debug::describe(ptr.addr())));
     if offset >= size {
         panic!("raw::read offset out of bounds");
     }
     unsafe { *ptr.as_ptr().add(offset) }
 }
 
+fn require_valid_range(ptr: RawPtr, offset: usize, len: usize, op: &str) {
+    if ptr.is_null() {
+        panic!("raw::{op} ptr must be non-null");
+    }
+    let size = allocation_size(ptr)
+        .unwrap_or_else(|| panic!("raw::{op} ptr is invalid{}", debug::describe(ptr.addr())));
+    let end = offset
+        .checked_add(len)
+        .unwrap_or_else(|| panic!("raw::{op} range overflow"));
+    if end > size {
+        panic!("raw::{op} offset out of bounds");
+    }
+}
+
+unsafe fn read_array<const N: usize>(ptr: RawPtr, offset: usize, op: &str) -> [u8; N] {
+    require_valid_range(ptr, offset, N, op);
+    let mut out = [0u8; N];
+    unsafe {
+        std::ptr::copy_nonoverlapping(ptr.as_ptr().add(offset), out.as_mut_ptr(), N);
+    }
+    out
+}
+
+unsafe fn write_array<const N: usize>(ptr: RawPtr, offset: usize, bytes: [u8; N], op: &str) {
+    require_valid_range(ptr, offset, N, op);
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.as_ptr().add(offset), N);
+    }
+}
+
+/// Reads a little-endian `u16` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 2` must not exceed the allocation size.
+pub unsafe fn read_u16_le(ptr: RawPtr, offset: usize) -> u16 {
+    u16::from_le_bytes(unsafe { read_array(ptr, offset, "read_u16_le") })
+}
+
+/// Reads a big-endian `u16` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 2` must not exceed the allocation size.
+pub unsafe fn read_u16_be(ptr: RawPtr, offset: usize) -> u16 {
+    u16::from_be_bytes(unsafe { read_array(ptr, offset, "read_u16_be") })
+}
+
+/// Reads a little-endian `u32` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 4` must not exceed the allocation size.
+pub unsafe fn read_u32_le(ptr: RawPtr, offset: usize) -> u32 {
+    u32::from_le_bytes(unsafe { read_array(ptr, offset, "read_u32_le") })
+}
+
+/// Reads a big-endian `u32` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 4` must not exceed the allocation size.
+pub unsafe fn read_u32_be(ptr: RawPtr, offset: usize) -> u32 {
+    u32::from_be_bytes(unsafe { read_array(ptr, offset, "read_u32_be") })
+}
+
+/// Reads a little-endian `u64` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 8` must not exceed the allocation size.
+pub unsafe fn read_u64_le(ptr: RawPtr, offset: usize) -> u64 {
+    u64::from_le_bytes(unsafe { read_array(ptr, offset, "read_u64_le") })
+}
+
+/// Reads a big-endian `u64` at `offset` within an allocated raw buffer.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 8` must not exceed the allocation size.
+pub unsafe fn read_u64_be(ptr: RawPtr, offset: usize) -> u64 {
+    u64::from_be_bytes(unsafe { read_array(ptr, offset, "read_u64_be") })
+}
+
+/// Writes `value` as little-endian `u16` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 2` must not exceed the allocation size.
+pub unsafe fn write_u16_le(ptr: RawPtr, offset: usize, value: u16) {
+    unsafe { write_array(ptr, offset, value.to_le_bytes(), "write_u16_le") }
+}
+
+/// Writes `value` as big-endian `u16` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 2` must not exceed the allocation size.
+pub unsafe fn write_u16_be(ptr: RawPtr, offset: usize, value: u16) {
+    unsafe { write_array(ptr, offset, value.to_be_bytes(), "write_u16_be") }
+}
+
+/// Writes `value` as little-endian `u32` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 4` must not exceed the allocation size.
+pub unsafe fn write_u32_le(ptr: RawPtr, offset: usize, value: u32) {
+    unsafe { write_array(ptr, offset, value.to_le_bytes(), "write_u32_le") }
+}
+
+/// Writes `value` as big-endian `u32` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 4` must not exceed the allocation size.
+pub unsafe fn write_u32_be(ptr: RawPtr, offset: usize, value: u32) {
+    unsafe { write_array(ptr, offset, value.to_be_bytes(), "write_u32_be") }
+}
+
+/// Writes `value` as little-endian `u64` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 8` must not exceed the allocation size.
+pub unsafe fn write_u64_le(ptr: RawPtr, offset: usize, value: u64) {
+    unsafe { write_array(ptr, offset, value.to_le_bytes(), "write_u64_le") }
+}
+
+/// Writes `value` as big-endian `u64` at `offset`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + 8` must not exceed the allocation size.
+pub unsafe fn write_u64_be(ptr: RawPtr, offset: usize, value: u64) {
+    unsafe { write_array(ptr, offset, value.to_be_bytes(), "write_u64_be") }
+}
+
+/// Copies `len` bytes from `src[src_offset..]` into `dst[dst_offset..]`.
+///
+/// # Safety
+/// `dst` and `src` must be valid, live allocations from [`alloc`] and both
+/// ranges must lie within their allocation sizes. Ranges may overlap.
+pub unsafe fn copy_from(
+    dst: RawPtr,
+    dst_offset: usize,
+    src: RawPtr,
+    src_offset: usize,
+    len: usize,
+) {
+    require_valid_range(dst, dst_offset, len, "copy_from");
+    require_valid_range(src, src_offset, len, "copy_from");
+    unsafe {
+        std::ptr::copy(
+            src.as_ptr().add(src_offset),
+            dst.as_ptr().add(dst_offset),
+            len,
+        );
+    }
+}
+
+/// Copies `len` bytes from `src[src_offset..]` into `dst[dst_offset..]`.
+///
+/// # Safety
+/// Same requirements as [`copy_from`].
+pub unsafe fn copy_to(src: RawPtr, src_offset: usize, dst: RawPtr, dst_offset: usize, len: usize) {
+    unsafe { copy_from(dst, dst_offset, src, src_offset, len) }
+}
+
+/// Sets `len` bytes starting at `offset` to `value`.
+///
+/// # Safety
+/// `ptr` must be a valid, live allocation from [`alloc`] and
+/// `offset + len` must not exceed the allocation size.
+pub unsafe fn fill(ptr: RawPtr, offset: usize, len: usize, value: u8) {
+    require_valid_range(ptr, offset, len, "fill");
+    unsafe {
+        std::ptr::write_bytes(ptr.as_ptr().add(offset), value, len);
+    }
+}
+
+/// Validates `ptr` with a user predicate that receives the pointer and its
+/// tracked size. See [`super::safe::validate_raw_checked`] for the errors.
+///
+/// # Safety
+/// `check` is called with `ptr`; it must uphold the same requirements as
+/// any other raw operation on a live allocation.
+pub unsafe fn validate_with(
+    ptr: RawPtr,
+    check: unsafe fn(RawPtr, usize) -> bool,
+) -> types::Result<ValidatedPtr, MemoryError> {
+    safe::validate_raw_checked(ptr, |bytes| unsafe { check(ptr, bytes.len()) })
+}
+
 pub(crate) fn claim_allocation(ptr: RawPtr) -> Option<usize> {
     take_allocation(ptr)
 }
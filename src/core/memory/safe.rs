@@ -5,8 +5,14 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::collections::VecDeque;
+use std::panic::Location;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use super::MemoryError;
+use super::debug;
+use super::tracking::AllocationTable;
+use crate::core::types;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct HighPtr(*mut u8);
@@ -32,6 +38,18 @@ impl HighPtr {
     }
 }
 
+/// Bounded window `[offset, offset + len)` into a [`HighPtr`] allocation.
+///
+/// Accessors take offsets relative to the view and reject anything past
+/// `len`, so a view can be handed to code that must not see the rest of
+/// the buffer. The underlying allocation is still checked on every access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HighView {
+    ptr: HighPtr,
+    offset: usize,
+    len: usize,
+}
+
 impl ValidatedPtr {
     pub(crate) fn from_ptr(ptr: *mut u8) -> Self {
         Self(ptr)
@@ -50,34 +68,166 @@ impl ValidatedPtr {
     }
 }
 
-fn allocations() -> &'static Mutex<HashMap<usize, usize>> {
-    static ALLOCS: OnceLock<Mutex<HashMap<usize, usize>>> = OnceLock::new();
-    ALLOCS.get_or_init(|| Mutex::new(HashMap::new()))
+impl HighView {
+    /// Creates a view covering `[offset, offset + len)` of `ptr`.
+    pub fn new(ptr: HighPtr, offset: usize, len: usize) -> Self {
+        require_valid_range(ptr, offset, len);
+        Self { ptr, offset, len }
+    }
+
+    /// Creates a view covering the whole allocation behind `ptr`.
+    pub fn whole(ptr: HighPtr) -> Self {
+        if ptr.is_null() {
+            panic!("high ptr must be non-null");
+        }
+        let len = allocation_size(ptr)
+            .unwrap_or_else(|| panic!("high ptr is invalid{}", debug::describe(ptr.addr())));
+        Self {
+            ptr,
+            offset: 0,
+            len,
+        }
+    }
+
+    pub fn ptr(self) -> HighPtr {
+        self.ptr
+    }
+
+    pub fn offset(self) -> usize {
+        self.offset
+    }
+
+    pub fn len(self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// Narrows the view to `[offset, offset + len)` relative to this view.
+    pub fn sub(self, offset: usize, len: usize) -> Self {
+        let start = self.absolute(offset, len);
+        Self::new(self.ptr, start, len)
+    }
+
+    /// Splits the view into `[0, mid)` and `[mid, len)`.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        (self.sub(0, mid), self.sub(mid, self.len - mid))
+    }
+
+    pub fn read_bytes(self, offset: usize, len: usize) -> Vec<u8> {
+        read_bytes(self.ptr, self.absolute(offset, len), len)
+    }
+
+    pub fn write_bytes(self, offset: usize, src: &[u8]) {
+        write_bytes(self.ptr, self.absolute(offset, src.len()), src);
+    }
+
+    fn absolute(self, offset: usize, len: usize) -> usize {
+        let end = offset
+            .checked_add(len)
+            .unwrap_or_else(|| panic!("high view range overflow"));
+        if end > self.len {
+            panic!("high view range out of bounds");
+        }
+        self.offset + offset
+    }
+}
+
+fn allocations() -> &'static AllocationTable {
+    static ALLOCS: OnceLock<AllocationTable> = OnceLock::new();
+    ALLOCS.get_or_init(AllocationTable::new)
+}
+
+/// Releases remembered for double-free reports. Older ones are forgotten,
+/// and releasing such an address again reports `Untracked`.
+pub const FREED_HISTORY: usize = 4096;
+
+/// Addresses released by `deallocate_buffer` and not handed out again,
+/// so a second release can be reported as a double free.
+fn freed() -> &'static AllocationTable {
+    static FREED: OnceLock<AllocationTable> = OnceLock::new();
+    FREED.get_or_init(AllocationTable::new)
+}
+
+/// Release order of the entries in [`freed`], oldest first. Only touched
+/// on release, so allocation stays on the sharded tables.
+fn freed_order() -> MutexGuard<'static, VecDeque<usize>> {
+    static ORDER: OnceLock<Mutex<VecDeque<usize>>> = OnceLock::new();
+    ORDER
+        .get_or_init(|| Mutex::new(VecDeque::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Every entry of [`freed`] has exactly one entry in [`freed_order`]; both
+/// change under the order lock.
+fn remember_freed(addr: usize, size: usize) {
+    let mut order = freed_order();
+    if freed().insert(addr, size).is_some() {
+        order.retain(|&remembered| remembered != addr);
+    }
+    order.push_back(addr);
+    while order.len() > FREED_HISTORY {
+        if let Some(oldest) = order.pop_front() {
+            freed().remove(oldest);
+        }
+    }
+}
+
+/// Drops a remembered release once `addr` is handed out again, by either
+/// the high or the raw allocation path. Its place in the release order goes
+/// too, so a later release of the same address is not evicted early.
+pub(super) fn forget_freed(addr: usize) {
+    // Most allocations reuse no remembered address and skip the lock.
+    if freed().get(addr).is_none() {
+        return;
+    }
+    let mut order = freed_order();
+    if freed().remove(addr).is_some()
+        && let Some(index) = order.iter().position(|&remembered| remembered == addr)
+    {
+        order.remove(index);
+    }
+}
+
+/// Number of released addresses currently remembered for double-free
+/// reports; never more than [`FREED_HISTORY`].
+pub fn remembered_frees() -> usize {
+    freed().len()
 }
 
 fn track_allocation(ptr: HighPtr, size: usize) {
-    let key = ptr.addr();
-    let mut map = allocations().lock().expect("allocations lock poisoned");
-    map.insert(key, size);
+    forget_freed(ptr.addr());
+    allocations().insert(ptr.addr(), size);
 }
 
 pub fn allocation_size(ptr: HighPtr) -> Option<usize> {
-    let key = ptr.addr();
-    let map = allocations().lock().expect("allocations lock poisoned");
-    map.get(&key).copied()
+    allocations().get(ptr.addr())
 }
 
-fn require_valid_range(ptr: HighPtr, offset: usize, len: usize) {
+fn check_range(ptr: HighPtr, offset: usize, len: usize) -> Result<(), MemoryError> {
     if ptr.is_null() {
-        panic!("high ptr must be non-null");
+        return Err(MemoryError::NullPointer);
     }
-
-    let size = allocation_size(ptr).unwrap_or_else(|| panic!("high ptr is invalid"));
-    let end = offset
-        .checked_add(len)
-        .unwrap_or_else(|| panic!("high ptr range overflow"));
+    let size = allocation_size(ptr).ok_or(MemoryError::Untracked)?;
+    let end = offset.checked_add(len).ok_or(MemoryError::Overflow)?;
     if end > size {
-        panic!("high ptr range out of bounds");
+        return Err(MemoryError::OutOfBounds);
+    }
+    Ok(())
+}
+
+fn require_valid_range(ptr: HighPtr, offset: usize, len: usize) {
+    match check_range(ptr, offset, len) {
+        Ok(()) => {}
+        Err(MemoryError::NullPointer) => panic!("high ptr must be non-null"),
+        Err(MemoryError::Untracked) => {
+            panic!("high ptr is invalid{}", debug::describe(ptr.addr()))
+        }
+        Err(MemoryError::Overflow) => panic!("high ptr range overflow"),
+        Err(_) => panic!("high ptr range out of bounds"),
     }
 }
 
@@ -111,38 +261,181 @@ pub(crate) fn read_byte(ptr: HighPtr, offset: usize) -> u8 {
     unsafe { *ptr.as_ptr().add(offset) }
 }
 
+fn read_array<const N: usize>(ptr: HighPtr, offset: usize) -> [u8; N] {
+    require_valid_range(ptr, offset, N);
+    let mut out = [0u8; N];
+    unsafe {
+        std::ptr::copy_nonoverlapping(ptr.as_ptr().add(offset), out.as_mut_ptr(), N);
+    }
+    out
+}
+
+fn write_array<const N: usize>(ptr: HighPtr, offset: usize, bytes: [u8; N]) {
+    require_valid_range(ptr, offset, N);
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.as_ptr().add(offset), N);
+    }
+}
+
+/// Reads a `u8` at `offset` within a high buffer.
+///
+/// Panics if `offset` is past the tracked allocation size.
+pub fn read_u8(ptr: HighPtr, offset: usize) -> u8 {
+    read_byte(ptr, offset)
+}
+
+/// Writes the byte `value` at `offset` within a high buffer.
+///
+/// Panics if `offset` is past the tracked allocation size.
+pub fn write_u8(ptr: HighPtr, offset: usize, value: u8) {
+    write_byte(ptr, offset, value);
+}
+
+/// Reads a little-endian `u16` at `offset` within a high buffer.
+///
+/// Panics if `offset + 2` exceeds the tracked allocation size.
+pub fn read_u16_le(ptr: HighPtr, offset: usize) -> u16 {
+    u16::from_le_bytes(read_array(ptr, offset))
+}
+
+/// Reads a big-endian `u16` at `offset` within a high buffer.
+///
+/// Panics if `offset + 2` exceeds the tracked allocation size.
+pub fn read_u16_be(ptr: HighPtr, offset: usize) -> u16 {
+    u16::from_be_bytes(read_array(ptr, offset))
+}
+
+/// Reads a little-endian `u32` at `offset` within a high buffer.
+///
+/// Panics if `offset + 4` exceeds the tracked allocation size.
+pub fn read_u32_le(ptr: HighPtr, offset: usize) -> u32 {
+    u32::from_le_bytes(read_array(ptr, offset))
+}
+
+/// Reads a big-endian `u32` at `offset` within a high buffer.
+///
+/// Panics if `offset + 4` exceeds the tracked allocation size.
+pub fn read_u32_be(ptr: HighPtr, offset: usize) -> u32 {
+    u32::from_be_bytes(read_array(ptr, offset))
+}
+
+/// Reads a little-endian `u64` at `offset` within a high buffer.
+///
+/// Panics if `offset + 8` exceeds the tracked allocation size.
+pub fn read_u64_le(ptr: HighPtr, offset: usize) -> u64 {
+    u64::from_le_bytes(read_array(ptr, offset))
+}
+
+/// Reads a big-endian `u64` at `offset` within a high buffer.
+///
+/// Panics if `offset + 8` exceeds the tracked allocation size.
+pub fn read_u64_be(ptr: HighPtr, offset: usize) -> u64 {
+    u64::from_be_bytes(read_array(ptr, offset))
+}
+
+/// Writes `value` as little-endian `u16` at `offset`.
+///
+/// Panics if `offset + 2` exceeds the tracked allocation size.
+pub fn write_u16_le(ptr: HighPtr, offset: usize, value: u16) {
+    write_array(ptr, offset, value.to_le_bytes());
+}
+
+/// Writes `value` as big-endian `u16` at `offset`.
+///
+/// Panics if `offset + 2` exceeds the tracked allocation size.
+pub fn write_u16_be(ptr: HighPtr, offset: usize, value: u16) {
+    write_array(ptr, offset, value.to_be_bytes());
+}
+
+/// Writes `value` as little-endian `u32` at `offset`.
+///
+/// Panics if `offset + 4` exceeds the tracked allocation size.
+pub fn write_u32_le(ptr: HighPtr, offset: usize, value: u32) {
+    write_array(ptr, offset, value.to_le_bytes());
+}
+
+/// Writes `value` as big-endian `u32` at `offset`.
+///
+/// Panics if `offset + 4` exceeds the tracked allocation size.
+pub fn write_u32_be(ptr: HighPtr, offset: usize, value: u32) {
+    write_array(ptr, offset, value.to_be_bytes());
+}
+
+/// Writes `value` as little-endian `u64` at `offset`.
+///
+/// Panics if `offset + 8` exceeds the tracked allocation size.
+pub fn write_u64_le(ptr: HighPtr, offset: usize, value: u64) {
+    write_array(ptr, offset, value.to_le_bytes());
+}
+
+/// Writes `value` as big-endian `u64` at `offset`.
+///
+/// Panics if `offset + 8` exceeds the tracked allocation size.
+pub fn write_u64_be(ptr: HighPtr, offset: usize, value: u64) {
+    write_array(ptr, offset, value.to_be_bytes());
+}
+
+/// Copies `len` bytes from `src[src_offset..]` into `dst[dst_offset..]`.
+///
+/// Both ranges are checked against their tracked allocation sizes. The
+/// ranges may overlap when `dst` and `src` are the same buffer.
+pub fn copy_from(dst: HighPtr, dst_offset: usize, src: HighPtr, src_offset: usize, len: usize) {
+    require_valid_range(dst, dst_offset, len);
+    require_valid_range(src, src_offset, len);
+    unsafe {
+        std::ptr::copy(
+            src.as_ptr().add(src_offset),
+            dst.as_ptr().add(dst_offset),
+            len,
+        );
+    }
+}
+
+/// Copies `len` bytes from `src[src_offset..]` into `dst[dst_offset..]`.
+///
+/// Same operation as [`copy_from`] with the source buffer first.
+pub fn copy_to(src: HighPtr, src_offset: usize, dst: HighPtr, dst_offset: usize, len: usize) {
+    copy_from(dst, dst_offset, src, src_offset, len);
+}
+
+/// Sets `len` bytes starting at `offset` to `value`.
+pub fn fill(ptr: HighPtr, offset: usize, len: usize, value: u8) {
+    require_valid_range(ptr, offset, len);
+    unsafe {
+        std::ptr::write_bytes(ptr.as_ptr().add(offset), value, len);
+    }
+}
+
+#[track_caller]
 pub fn allocate_buffer(size: usize) -> HighPtr {
     if size == 0 {
         panic!("allocate_buffer size must be > 0");
     }
 
-    let mut buf = vec![0u8; size].into_boxed_slice();
-    let ptr = buf.as_mut_ptr();
-    std::mem::forget(buf);
+    let ptr = debug::allocate(size, Location::caller());
+    if ptr.is_null() {
+        panic!("allocate_buffer out of memory");
+    }
     let high_ptr = HighPtr::from_ptr(ptr);
     track_allocation(high_ptr, size);
     high_ptr
 }
 
+#[track_caller]
 pub fn deallocate_buffer(ptr: HighPtr) {
     if ptr.is_null() {
         panic!("deallocate_buffer ptr must be non-null");
     }
 
-    let key = ptr.addr();
-    let size = {
-        let mut map = allocations().lock().expect("allocations lock poisoned");
-        map.remove(&key)
-    };
-
-    let Some(len) = size else {
-        panic!("deallocate_buffer ptr is invalid or already deallocated");
+    let Some(len) = allocations().remove(ptr.addr()) else {
+        panic!(
+            "deallocate_buffer ptr is invalid or already deallocated{}",
+            debug::describe(ptr.addr())
+        );
     };
+    remember_freed(ptr.addr(), len);
 
-    unsafe {
-        let slice = std::slice::from_raw_parts_mut(ptr.as_ptr(), len);
-        let _ = Box::from_raw(slice);
-    }
+    unsafe { debug::deallocate(ptr.as_ptr(), len, Location::caller()) };
 }
 
 pub fn validate_raw(raw_ptr: super::raw::RawPtr) -> ValidatedPtr {
@@ -155,6 +448,67 @@ pub fn validate_raw(raw_ptr: super::raw::RawPtr) -> ValidatedPtr {
     ValidatedPtr::from_ptr(raw_ptr.as_ptr())
 }
 
+/// Validates `raw_ptr` and runs `check` over its tracked bytes before a
+/// [`ValidatedPtr`] is produced.
+///
+/// Unlike [`validate_raw`] this never panics: a null pointer is
+/// [`MemoryError::NullPointer`], an untracked one [`MemoryError::Untracked`]
+/// and a failed check [`MemoryError::Rejected`].
+pub fn validate_raw_checked<F>(
+    raw_ptr: super::raw::RawPtr,
+    check: F,
+) -> types::Result<ValidatedPtr, MemoryError>
+where
+    F: FnOnce(&[u8]) -> bool,
+{
+    if raw_ptr.is_null() {
+        return types::Result::Err(MemoryError::NullPointer);
+    }
+    let Some(size) = super::raw::allocation_size(raw_ptr) else {
+        return types::Result::Err(MemoryError::Untracked);
+    };
+    let bytes = unsafe { std::slice::from_raw_parts(raw_ptr.as_ptr(), size) };
+    if !check(bytes) {
+        return types::Result::Err(MemoryError::Rejected);
+    }
+    types::Result::Ok(ValidatedPtr::from_ptr(raw_ptr.as_ptr()))
+}
+
+/// Validates `raw_ptr` and requires at least `min_len` tracked bytes.
+pub fn validate_raw_min_len(
+    raw_ptr: super::raw::RawPtr,
+    min_len: usize,
+) -> types::Result<ValidatedPtr, MemoryError> {
+    validate_raw_checked(raw_ptr, |bytes| bytes.len() >= min_len)
+}
+
+/// Whether a validating call succeeded.
+pub fn validation_is_ok(result: &types::Result<ValidatedPtr, MemoryError>) -> bool {
+    matches!(result, types::Result::Ok(_))
+}
+
+/// The validated pointer of a successful validating call.
+///
+/// Panics if the validation failed; check [`validation_is_ok`] first.
+#[track_caller]
+pub fn validation_unwrap(result: types::Result<ValidatedPtr, MemoryError>) -> ValidatedPtr {
+    match result {
+        types::Result::Ok(ptr) => ptr,
+        types::Result::Err(err) => panic!("validation_unwrap on a failed validation: {err}"),
+    }
+}
+
+/// Why a validating call failed.
+///
+/// Panics if the validation succeeded.
+#[track_caller]
+pub fn validation_error(result: &types::Result<ValidatedPtr, MemoryError>) -> MemoryError {
+    match result {
+        types::Result::Ok(_) => panic!("validation_error on a successful validation"),
+        types::Result::Err(err) => *err,
+    }
+}
+
 pub fn into_high(validated_ptr: ValidatedPtr) -> HighPtr {
     if validated_ptr.is_null() {
         panic!("into_high ptr must be non-null");
@@ -166,3 +520,180 @@ pub fn into_high(validated_ptr: ValidatedPtr) -> HighPtr {
     track_allocation(high_ptr, size);
     high_ptr
 }
+
+/// Non-panicking [`allocate_buffer`].
+#[track_caller]
+#[must_use]
+pub fn try_allocate_buffer(size: usize) -> types::Result<HighPtr, MemoryError> {
+    if size == 0 {
+        return types::Result::Err(MemoryError::ZeroSize);
+    }
+    let ptr = debug::allocate(size, Location::caller());
+    if ptr.is_null() {
+        return types::Result::Err(MemoryError::OutOfMemory);
+    }
+    let high_ptr = HighPtr::from_ptr(ptr);
+    track_allocation(high_ptr, size);
+    types::Result::Ok(high_ptr)
+}
+
+/// Non-panicking [`deallocate_buffer`]. Releasing the same buffer twice
+/// reports [`MemoryError::DoubleFree`].
+#[track_caller]
+#[must_use]
+pub fn try_deallocate_buffer(ptr: HighPtr) -> types::Result<(), MemoryError> {
+    if ptr.is_null() {
+        return types::Result::Err(MemoryError::NullPointer);
+    }
+    let Some(len) = allocations().remove(ptr.addr()) else {
+        if freed().get(ptr.addr()).is_some() {
+            return types::Result::Err(MemoryError::DoubleFree);
+        }
+        return types::Result::Err(MemoryError::Untracked);
+    };
+    remember_freed(ptr.addr(), len);
+    unsafe { debug::deallocate(ptr.as_ptr(), len, Location::caller()) };
+    types::Result::Ok(())
+}
+
+/// Non-panicking [`validate_raw`].
+#[must_use]
+pub fn try_validate_raw(raw_ptr: super::raw::RawPtr) -> types::Result<ValidatedPtr, MemoryError> {
+    if raw_ptr.is_null() {
+        return types::Result::Err(MemoryError::NullPointer);
+    }
+    if super::raw::allocation_size(raw_ptr).is_none() {
+        return types::Result::Err(MemoryError::Untracked);
+    }
+    types::Result::Ok(ValidatedPtr::from_ptr(raw_ptr.as_ptr()))
+}
+
+/// Non-panicking [`into_high`].
+#[must_use]
+pub fn try_into_high(validated_ptr: ValidatedPtr) -> types::Result<HighPtr, MemoryError> {
+    if validated_ptr.is_null() {
+        return types::Result::Err(MemoryError::NullPointer);
+    }
+    let Some(size) =
+        super::raw::claim_allocation(super::raw::RawPtr::from_ptr(validated_ptr.as_ptr()))
+    else {
+        return types::Result::Err(MemoryError::Untracked);
+    };
+    let high_ptr = HighPtr::from_ptr(validated_ptr.as_ptr());
+    track_allocation(high_ptr, size);
+    types::Result::Ok(high_ptr)
+}
+
+/// Non-panicking [`read_u8`].
+#[must_use]
+pub fn try_read_u8(ptr: HighPtr, offset: usize) -> types::Result<u8, MemoryError> {
+    match check_range(ptr, offset, 1) {
+        Ok(()) => types::Result::Ok(unsafe { *ptr.as_ptr().add(offset) }),
+        Err(error) => types::Result::Err(error),
+    }
+}
+
+/// Non-panicking [`write_u8`].
+#[must_use]
+pub fn try_write_u8(ptr: HighPtr, offset: usize, value: u8) -> types::Result<(), MemoryError> {
+    match check_range(ptr, offset, 1) {
+        Ok(()) => {
+            unsafe { *ptr.as_ptr().add(offset) = value };
+            types::Result::Ok(())
+        }
+        Err(error) => types::Result::Err(error),
+    }
+}
+
+pub fn view_of(ptr: HighPtr) -> HighView {
+    HighView::whole(ptr)
+}
+
+pub fn view_range(ptr: HighPtr, offset: usize, len: usize) -> HighView {
+    HighView::new(ptr, offset, len)
+}
+
+pub fn view_len(view: HighView) -> usize {
+    view.len()
+}
+
+pub fn view_sub(view: HighView, offset: usize, len: usize) -> HighView {
+    view.sub(offset, len)
+}
+
+/// Returns the first `mid` bytes of `view`.
+pub fn view_head(view: HighView, mid: usize) -> HighView {
+    view.split_at(mid).0
+}
+
+/// Returns everything after the first `mid` bytes of `view`.
+pub fn view_tail(view: HighView, mid: usize) -> HighView {
+    view.split_at(mid).1
+}
+
+pub fn view_read_u8(view: HighView, offset: usize) -> u8 {
+    read_u8(view.ptr, view.absolute(offset, 1))
+}
+
+pub fn view_write_u8(view: HighView, offset: usize, value: u8) {
+    write_u8(view.ptr, view.absolute(offset, 1), value);
+}
+
+pub fn view_read_u16_le(view: HighView, offset: usize) -> u16 {
+    read_u16_le(view.ptr, view.absolute(offset, 2))
+}
+
+pub fn view_read_u16_be(view: HighView, offset: usize) -> u16 {
+    read_u16_be(view.ptr, view.absolute(offset, 2))
+}
+
+pub fn view_read_u32_le(view: HighView, offset: usize) -> u32 {
+    read_u32_le(view.ptr, view.absolute(offset, 4))
+}
+
+pub fn view_read_u32_be(view: HighView, offset: usize) -> u32 {
+    read_u32_be(view.ptr, view.absolute(offset, 4))
+}
+
+pub fn view_read_u64_le(view: HighView, offset: usize) -> u64 {
+    read_u64_le(view.ptr, view.absolute(offset, 8))
+}
+
+pub fn view_read_u64_be(view: HighView, offset: usize) -> u64 {
+    read_u64_be(view.ptr, view.absolute(offset, 8))
+}
+
+pub fn view_write_u16_le(view: HighView, offset: usize, value: u16) {
+    write_u16_le(view.ptr, view.absolute(offset, 2), value);
+}
+
+pub fn view_write_u16_be(view: HighView, offset: usize, value: u16) {
+    write_u16_be(view.ptr, view.absolute(offset, 2), value);
+}
+
+pub fn view_write_u32_le(view: HighView, offset: usize, value: u32) {
+    write_u32_le(view.ptr, view.absolute(offset, 4), value);
+}
+
+pub fn view_write_u32_be(view: HighView, offset: usize, value: u32) {
+    write_u32_be(view.ptr, view.absolute(offset, 4), value);
+}
+
+pub fn view_write_u64_le(view: HighView, offset: usize, value: u64) {
+    write_u64_le(view.ptr, view.absolute(offset, 8), value);
+}
+
+pub fn view_write_u64_be(view: HighView, offset: usize, value: u64) {
+    write_u64_be(view.ptr, view.absolute(offset, 8), value);
+}
+
+/// Copies the whole of `src` into `dst` starting at `dst_offset`.
+pub fn view_copy_from(dst: HighView, dst_offset: usize, src: HighView) {
+    let start = dst.absolute(dst_offset, src.len);
+    copy_from(dst.ptr, start, src.ptr, src.offset, src.len);
+}
+
+/// Sets every byte of `view` to `value`.
+pub fn view_fill(view: HighView, value: u8) {
+    fill(view.ptr, view.offset, view.len, value);
+}
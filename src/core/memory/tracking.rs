@@ -0,0 +1,156 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+const SHARD_COUNT: usize = 64;
+const CACHE_SLOTS: usize = 16;
+
+#[derive(Debug, Default)]
+#[repr(align(64))]
+struct Shard(RwLock<HashMap<usize, usize>>);
+
+#[derive(Debug, Clone, Copy)]
+struct CacheEntry {
+    table: usize,
+    addr: usize,
+    size: usize,
+    generation: u64,
+}
+
+impl CacheEntry {
+    const EMPTY: Self = Self {
+        table: 0,
+        addr: 0,
+        size: 0,
+        generation: 0,
+    };
+}
+
+thread_local! {
+    static LOOKUP_CACHE: [Cell<CacheEntry>; CACHE_SLOTS] =
+        const { [const { Cell::new(CacheEntry::EMPTY) }; CACHE_SLOTS] };
+}
+
+/// Address -> size map used for allocation tracking and bounds checks.
+///
+/// Entries live in independently locked shards selected by address. Lookups
+/// first consult a small per-thread cache that is invalidated through a
+/// per-table generation counter bumped on every removal, so repeated bounds
+/// checks on a live buffer take no lock at all.
+#[derive(Debug)]
+pub struct AllocationTable {
+    id: usize,
+    generation: AtomicU64,
+    shards: [Shard; SHARD_COUNT],
+}
+
+impl Default for AllocationTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AllocationTable {
+    pub fn new() -> Self {
+        // Id 0 marks empty cache slots.
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            generation: AtomicU64::new(0),
+            shards: std::array::from_fn(|_| Shard::default()),
+        }
+    }
+
+    fn mix(addr: usize) -> usize {
+        // Low bits are mostly alignment; fold higher bits in so that
+        // neighbouring allocations land on different shards and slots.
+        (addr >> 4) ^ (addr >> 10) ^ (addr >> 16)
+    }
+
+    fn shard(&self, addr: usize) -> &RwLock<HashMap<usize, usize>> {
+        &self.shards[Self::mix(addr) % SHARD_COUNT].0
+    }
+
+    fn invalidate_caches(&self) {
+        self.generation.fetch_add(1, Ordering::Release);
+    }
+
+    pub fn insert(&self, addr: usize, size: usize) -> Option<usize> {
+        let previous = self
+            .shard(addr)
+            .write()
+            .expect("allocation shard lock poisoned")
+            .insert(addr, size);
+        if previous.is_some() {
+            self.invalidate_caches();
+        }
+        previous
+    }
+
+    pub fn get(&self, addr: usize) -> Option<usize> {
+        let generation = self.generation.load(Ordering::Acquire);
+        let slot = Self::mix(addr) % CACHE_SLOTS;
+
+        let cached = LOOKUP_CACHE.with(|cache| {
+            let entry = cache[slot].get();
+            (entry.table == self.id && entry.addr == addr && entry.generation == generation)
+                .then_some(entry.size)
+        });
+        if cached.is_some() {
+            return cached;
+        }
+
+        let size = self
+            .shard(addr)
+            .read()
+            .expect("allocation shard lock poisoned")
+            .get(&addr)
+            .copied()?;
+        LOOKUP_CACHE.with(|cache| {
+            cache[slot].set(CacheEntry {
+                table: self.id,
+                addr,
+                size,
+                generation,
+            });
+        });
+        Some(size)
+    }
+
+    pub fn remove(&self, addr: usize) -> Option<usize> {
+        let removed = self
+            .shard(addr)
+            .write()
+            .expect("allocation shard lock poisoned")
+            .remove(&addr);
+        if removed.is_some() {
+            self.invalidate_caches();
+        }
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .0
+                    .read()
+                    .expect("allocation shard lock poisoned")
+                    .len()
+            })
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
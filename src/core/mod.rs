@@ -6,4 +6,5 @@
 // See: https://opensource.org/licenses/MIT
 
 pub mod memory;
+pub mod testing;
 pub mod types;
@@ -0,0 +1,62 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Assertions behind SAFE?'s `test fn`.
+//!
+//! Generated code passes the SAFE? source location as the last argument so
+//! failures point at the `.safe` file rather than the generated Rust.
+
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+thread_local! {
+    /// How many `catch_silently` calls the current thread is inside.
+    static SILENCED: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn assert(condition: bool, location: &str) {
+    if !condition {
+        panic!("assertion failed at {location}");
+    }
+}
+
+pub fn assert_eq<T: PartialEq + Debug>(left: T, right: T, location: &str) {
+    if left != right {
+        panic!("assertion failed at {location}: left {left:?} != right {right:?}");
+    }
+}
+
+/// Runs `body` and panics if it did *not* panic.
+pub fn expect_panic<T>(body: impl FnOnce() -> T, location: &str) {
+    if catch_silently(body).is_ok() {
+        panic!("expected a panic at {location}");
+    }
+}
+
+/// Runs `body`, catching a panic without printing it.
+///
+/// The panic hook is process-wide, so it is never swapped per call: the
+/// first call installs a hook that stays quiet on threads inside this
+/// function and defers to the previous hook everywhere else.
+pub(crate) fn catch_silently<T>(body: impl FnOnce() -> T) -> thread::Result<T> {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if SILENCED.with(Cell::get) == 0 {
+                previous(info);
+            }
+        }));
+    });
+    SILENCED.with(|depth| depth.set(depth.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(body));
+    SILENCED.with(|depth| depth.set(depth.get() - 1));
+    result
+}
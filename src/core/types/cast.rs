@@ -0,0 +1,60 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use super::Option;
+
+/// Primitive integer types, widened to `i128` for conversions.
+pub trait Integer: Copy + TryFrom<i128> {
+    const MIN: i128;
+    const MAX: i128;
+
+    fn to_i128(self) -> i128;
+
+    /// `value`, which the caller has brought into range.
+    fn from_in_range(value: i128) -> Self {
+        match Self::try_from(value) {
+            Ok(v) => v,
+            Err(_) => unreachable!("{value} is in range"),
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Integer for $ty {
+                const MIN: i128 = <$ty>::MIN as i128;
+                const MAX: i128 = <$ty>::MAX as i128;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// `value` as `T`, or `None` when it is out of range.
+pub fn checked_cast<T: Integer, S: Integer>(value: S) -> Option<T> {
+    match T::try_from(value.to_i128()) {
+        Ok(v) => Option::Some(v),
+        Err(_) => Option::None,
+    }
+}
+
+/// `value` as `T`, wrapping around modulo the size of `T`.
+pub fn wrapping_cast<T: Integer, S: Integer>(value: S) -> T {
+    let span = T::MAX - T::MIN + 1;
+    T::from_in_range((value.to_i128() - T::MIN).rem_euclid(span) + T::MIN)
+}
+
+/// `value` as `T`, clamped to the range of `T`.
+pub fn saturating_cast<T: Integer, S: Integer>(value: S) -> T {
+    T::from_in_range(value.to_i128().clamp(T::MIN, T::MAX))
+}
@@ -119,6 +119,26 @@ pub fn list_push_bytes(list: &mut List, bytes: &List) {
     }
 }
 
+/// A new list holding `f` applied to each element.
+pub fn list_map_u8(list: &List, mut f: impl FnMut(u8) -> u8) -> List {
+    let mut mapped = List::new();
+    for value in list.to_vec() {
+        mapped.push(f(value));
+    }
+    mapped
+}
+
+/// A new list holding the elements for which `keep` returns `true`.
+pub fn list_filter_u8(list: &List, mut keep: impl FnMut(u8) -> bool) -> List {
+    let mut kept = List::new();
+    for value in list.to_vec() {
+        if keep(value) {
+            kept.push(value);
+        }
+    }
+    kept
+}
+
 pub(super) fn list_from_bytes(bytes: &[u8]) -> List {
     let mut list = List::new();
     for value in bytes {
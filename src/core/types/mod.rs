@@ -5,20 +5,26 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+mod cast;
 mod list;
 mod option;
 mod print;
 mod result;
 mod string;
 
+pub use cast::{Integer, checked_cast, saturating_cast, wrapping_cast};
 pub use list::{
-    List, list_get_u8, list_is_empty, list_len, list_new, list_push_bytes, list_push_u8,
+    List, list_filter_u8, list_get_u8, list_is_empty, list_len, list_map_u8, list_new,
+    list_push_bytes, list_push_u8,
+};
+pub use option::{
+    Option, option_is_some, option_is_some_u8, option_none_u8, option_some_u8, option_unwrap,
+    option_unwrap_u8,
 };
-pub use option::{Option, option_is_some_u8, option_none_u8, option_some_u8, option_unwrap_u8};
 pub use print::{Printable, format_printable, print, print_any, printl, printl_any};
 pub use result::{
-    Result, result_err_u8_i32, result_is_ok_u8_i32, result_ok_u8_i32, result_unwrap_err_u8_i32,
-    result_unwrap_u8_i32,
+    Result, result_err_u8_i32, result_is_err, result_is_ok, result_is_ok_u8_i32, result_ok_u8_i32,
+    result_unwrap, result_unwrap_err, result_unwrap_err_u8_i32, result_unwrap_u8_i32,
 };
 pub use string::{
     String, StringList, StringSplit, string_append_bytes, string_clear, string_clear_with_capacity,
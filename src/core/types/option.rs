@@ -5,7 +5,7 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Option<T> {
     Some(T),
     None,
@@ -28,6 +28,14 @@ impl<T> Option<T> {
     }
 }
 
+pub fn option_is_some<T>(value: Option<T>) -> bool {
+    value.is_some()
+}
+
+pub fn option_unwrap<T>(value: Option<T>) -> T {
+    value.unwrap()
+}
+
 pub fn option_some_u8(value: u8) -> Option<u8> {
     Option::Some(value)
 }
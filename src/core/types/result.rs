@@ -5,7 +5,7 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Result<T, E> {
     Ok(T),
     Err(E),
@@ -54,3 +54,19 @@ pub fn result_unwrap_u8_i32(value: Result<u8, i32>) -> u8 {
 pub fn result_unwrap_err_u8_i32(value: Result<u8, i32>) -> i32 {
     value.unwrap_err()
 }
+
+pub fn result_is_ok<T, E>(value: Result<T, E>) -> bool {
+    value.is_ok()
+}
+
+pub fn result_is_err<T, E>(value: Result<T, E>) -> bool {
+    value.is_err()
+}
+
+pub fn result_unwrap<T, E>(value: Result<T, E>) -> T {
+    value.unwrap()
+}
+
+pub fn result_unwrap_err<T, E>(value: Result<T, E>) -> E {
+    value.unwrap_err()
+}
@@ -0,0 +1,104 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Canonical formatter behind `safe fmt`.
+//!
+//! The source is parsed to the AST, which then drives a printer over the
+//! original tokens: the AST decides the layout, the tokens supply literal
+//! text and positions, and comments are placed by position: between
+//! statements on lines of their own, and inside a statement next to the
+//! tokens around them. Line-based `import "x.safe"` lines are kept where
+//! they are.
+
+mod printer;
+
+use crate::cli::parse_import_line;
+use crate::lexer::{self, Comment, Span, Token};
+use crate::parser;
+
+use printer::Printer;
+
+/// Lines longer than this get their call arguments or parameters wrapped,
+/// one per line.
+pub const MAX_WIDTH: usize = 100;
+
+/// Formats a whole `.safe` file. Fails when the file does not parse.
+pub fn format_source(input: &str) -> Result<String, String> {
+    let (body, imports) = split_imports(input);
+    let (tokens, mut comments) =
+        lexer::tokenize_with_comments(&body).map_err(|e| format!("Lexing failed: {e}"))?;
+    let (rest, source) = parser::parse(&tokens).map_err(|e| format!("Parsing failed: {e:?}"))?;
+    if let Some(token) = rest.first() {
+        return Err(format!(
+            "Parsing failed: unexpected token at line {}, column {}",
+            token.span.line, token.span.column
+        ));
+    }
+
+    // Imports are placed like own-line comments.
+    comments.extend(imports);
+    comments.sort_by_key(|comment| comment.span.start);
+
+    let mut printer = Printer::new(&tokens, &comments, &body);
+    printer.source_file(&source);
+    let formatted = printer.finish();
+
+    let (new_tokens, new_comments) = reread(&formatted)?;
+    let kinds = |tokens: &[Token]| tokens.iter().map(|t| t.kind.clone()).collect::<Vec<_>>();
+    if kinds(&new_tokens) != kinds(&tokens) {
+        return Err("Formatting changed the meaning of the file; please report this".to_string());
+    }
+    if interleave(&new_tokens, &new_comments) != interleave(&tokens, &comments) {
+        return Err("Formatting moved a comment; please report this".to_string());
+    }
+    Ok(formatted)
+}
+
+/// Blanks out import lines so the rest lexes, returning them as comments
+/// positioned at their line.
+fn split_imports(input: &str) -> (String, Vec<Comment>) {
+    let mut body = String::with_capacity(input.len());
+    let mut imports = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if let Some(path) = parse_import_line(line) {
+            imports.push(Comment {
+                text: format!("import \"{path}\""),
+                span: Span {
+                    start: body.len(),
+                    end: body.len(),
+                    line: index + 1,
+                    column: 1,
+                },
+            });
+        } else {
+            body.push_str(line);
+        }
+        body.push('\n');
+    }
+    (body, imports)
+}
+
+/// Tokens and comments (imports included) of formatted output.
+fn reread(formatted: &str) -> Result<(Vec<Token>, Vec<Comment>), String> {
+    let (body, imports) = split_imports(formatted);
+    let (tokens, mut comments) =
+        lexer::tokenize_with_comments(&body).map_err(|e| format!("Formatter produced {e}"))?;
+    comments.extend(imports);
+    comments.sort_by_key(|comment| comment.span.start);
+    Ok((tokens, comments))
+}
+
+/// For each comment, its text and how many tokens come before it.
+fn interleave<'a>(tokens: &[Token], comments: &'a [Comment]) -> Vec<(usize, &'a str)> {
+    comments
+        .iter()
+        .map(|comment| {
+            let before = tokens.partition_point(|token| token.span.start < comment.span.start);
+            (before, comment.text.as_str())
+        })
+        .collect()
+}
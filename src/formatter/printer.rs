@@ -0,0 +1,694 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use crate::ast::*;
+use crate::lexer::{Comment, Token, TokenKind};
+
+use super::MAX_WIDTH;
+
+const INDENT: &str = "    ";
+
+/// Walks the AST while consuming the matching tokens in order.
+pub(super) struct Printer<'a> {
+    tokens: &'a [Token],
+    comments: &'a [Comment],
+    source: &'a str,
+    /// Next token to print.
+    pos: usize,
+    /// Next comment to place.
+    comment: usize,
+    out: String,
+    indent: usize,
+    /// Source line where the last printed token or comment ends.
+    last_line: usize,
+    /// Set after `{` and at the start of the file: no blank line before the
+    /// first line.
+    at_block_start: bool,
+    /// Set between items that always get a blank line.
+    force_blank: bool,
+}
+
+/// Where a wrapped layout restarts from.
+struct Checkpoint {
+    pos: usize,
+    comment: usize,
+    out_len: usize,
+    last_line: usize,
+}
+
+impl<'a> Printer<'a> {
+    pub(super) fn new(tokens: &'a [Token], comments: &'a [Comment], source: &'a str) -> Self {
+        Self {
+            tokens,
+            comments,
+            source,
+            pos: 0,
+            comment: 0,
+            out: String::new(),
+            indent: 0,
+            last_line: 0,
+            at_block_start: true,
+            force_blank: false,
+        }
+    }
+
+    pub(super) fn finish(mut self) -> String {
+        let len = self.out.trim_end().len();
+        self.out.truncate(len);
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    pub(super) fn source_file(&mut self, source: &SourceFile) {
+        let mut previous: Option<&Item> = None;
+        for item in &source.items {
+            // Consecutive aliases may stay together; anything else is
+            // separated by one blank line.
+            self.force_blank = previous
+                .is_some_and(|prev| !matches!((prev, item), (Item::Alias(_), Item::Alias(_))));
+            self.leading_comments();
+            self.begin_line();
+            match item {
+                Item::Function(func) => self.function(func),
+                Item::Alias(alias) => self.alias(alias),
+                Item::Enum(def) => self.enum_def(def),
+                Item::Struct(_) => {}
+            }
+            self.end_line();
+            previous = Some(item);
+        }
+        self.leading_comments();
+    }
+
+    // ----- items -----
+
+    /// Prints each attribute on a line of its own.
+    fn attributes(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            self.token(); // #
+            self.token(); // [
+            self.token(); // name
+            if !attribute.args.is_empty() {
+                self.token(); // (
+                self.list(attribute.args.len(), false, |printer, _| printer.token());
+                self.token(); // )
+            }
+            self.token(); // ]
+            self.out.push('\n');
+            self.push_indent();
+        }
+    }
+
+    fn function(&mut self, func: &Function) {
+        self.attributes(&func.attributes);
+        if func.is_test {
+            self.token(); // test
+            self.out.push(' ');
+        }
+        if matches!(self.peek(), Some(TokenKind::Safe | TokenKind::Raw)) {
+            self.token();
+            self.out.push(' ');
+        }
+        self.token(); // fn
+        self.out.push(' ');
+        self.token(); // name
+
+        let start = self.checkpoint();
+        self.function_signature(func, false);
+        // The line goes on with ` {`.
+        self.out.push_str(" {");
+        let too_long = self.too_long(&start);
+        self.out.truncate(self.out.len() - 2);
+        if too_long {
+            self.restore(start);
+            self.function_signature(func, true);
+        }
+        self.out.push(' ');
+        self.block(&func.body.statements);
+    }
+
+    fn function_signature(&mut self, func: &Function, wrap: bool) {
+        self.token(); // (
+        self.list(func.args.len(), wrap, |printer, _| {
+            printer.token(); // name
+            printer.token(); // :
+            printer.out.push(' ');
+            printer.ty();
+        });
+        self.token(); // )
+        if func.ret_type.is_some() {
+            self.out.push(' ');
+            self.token(); // ->
+            self.out.push(' ');
+            self.ty();
+        }
+    }
+
+    fn alias(&mut self, alias: &Alias) {
+        self.attributes(&alias.attributes);
+        self.token(); // alias
+        self.out.push(' ');
+        self.token(); // name
+        if !alias.params.is_empty() {
+            self.token(); // <
+            self.list(alias.params.len(), false, |printer, _| {
+                printer.token();
+            });
+            self.token(); // >
+        }
+        self.out.push(' ');
+        self.token(); // =
+        self.out.push(' ');
+        if alias.is_type() {
+            self.ty();
+        } else {
+            self.token();
+        }
+    }
+
+    /// Prints each variant on a line of its own, keeping the commas as
+    /// written.
+    fn enum_def(&mut self, def: &Enum) {
+        self.attributes(&def.attributes);
+        self.token(); // enum
+        self.out.push(' ');
+        self.token(); // name
+        self.out.push(' ');
+        self.lines_in_braces(def.variants.len(), |printer, index| {
+            printer.token(); // name
+            let payload = &def.variants[index].payload;
+            if !payload.is_empty() {
+                printer.token(); // (
+                printer.list(payload.len(), false, |printer, _| printer.ty());
+                printer.token(); // )
+            }
+            if matches!(printer.peek(), Some(TokenKind::Comma)) {
+                printer.token();
+            }
+        });
+    }
+
+    // ----- statements -----
+
+    /// Prints `{`, the statements one per line, and `}`.
+    fn block(&mut self, statements: &[Statement]) {
+        self.lines_in_braces(statements.len(), |printer, index| {
+            printer.statement(&statements[index]);
+        });
+    }
+
+    /// Prints `{`, `count` lines drawn by `line`, and `}`. Comments between
+    /// lines stay on lines of their own.
+    fn lines_in_braces(&mut self, count: usize, mut line: impl FnMut(&mut Self, usize)) {
+        self.token(); // {
+        let close = self.next_start();
+        let has_comments = self
+            .comments
+            .get(self.comment)
+            .is_some_and(|comment| comment.span.start < close);
+        if count == 0 && !has_comments {
+            self.token(); // }
+            return;
+        }
+
+        self.out.push('\n');
+        self.indent += 1;
+        self.at_block_start = true;
+        for index in 0..count {
+            self.leading_comments();
+            self.begin_line();
+            line(self, index);
+            self.end_line();
+        }
+        self.leading_comments();
+        self.indent -= 1;
+        self.push_indent();
+        self.token(); // }
+    }
+
+    fn statement(&mut self, stmt: &Statement) {
+        self.attributes(stmt.attributes());
+        match stmt {
+            Statement::Let(LetStatement { ty, value, .. })
+            | Statement::LetTuple(LetTupleStatement { ty, value, .. })
+            | Statement::Const(ConstStatement { ty, value, .. }) => {
+                self.token(); // let / const
+                self.out.push(' ');
+                if let Statement::LetTuple(stmt) = stmt {
+                    self.token(); // (
+                    self.list(stmt.names.len(), false, |printer, _| printer.token());
+                    self.token(); // )
+                } else {
+                    self.token(); // name
+                }
+                if ty.is_some() {
+                    self.token(); // :
+                    self.out.push(' ');
+                    self.ty();
+                }
+                self.out.push(' ');
+                self.token(); // =
+                self.out.push(' ');
+                self.expr(value);
+            }
+            Statement::If(stmt) => self.if_statement(stmt),
+            Statement::For(stmt) => {
+                self.token(); // for
+                self.out.push(' ');
+                self.token(); // name
+                self.out.push(' ');
+                self.token(); // in
+                self.out.push(' ');
+                self.expr(&stmt.start);
+                self.token(); // .. / ..=
+                self.expr(&stmt.end);
+                self.out.push(' ');
+                self.block(&stmt.body.statements);
+            }
+            Statement::Break | Statement::Continue => self.token(),
+            Statement::Expr(expr) => self.expr(expr),
+        }
+    }
+
+    fn if_statement(&mut self, stmt: &IfStatement) {
+        self.token(); // if
+        self.out.push(' ');
+        if let Some(pattern) = &stmt.pattern {
+            self.token(); // let
+            self.out.push(' ');
+            self.pattern(pattern);
+            self.out.push(' ');
+            self.token(); // =
+            self.out.push(' ');
+        }
+        self.expr(&stmt.condition);
+        self.out.push(' ');
+        self.block(&stmt.then_block.statements);
+
+        let Some(else_block) = &stmt.else_block else {
+            return;
+        };
+        self.out.push(' ');
+        self.token(); // else
+        self.out.push(' ');
+        // `else if` is parsed into a block holding only the nested `if`.
+        match (self.peek(), else_block.statements.as_slice()) {
+            (Some(TokenKind::If), [Statement::If(nested)]) => self.if_statement(nested),
+            _ => self.block(&else_block.statements),
+        }
+    }
+
+    // ----- expressions -----
+
+    fn expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Call(call) => {
+                let start = self.checkpoint();
+                self.call(call, false);
+                if self.too_long(&start)
+                    && !call.args.is_empty()
+                    && !call
+                        .args
+                        .iter()
+                        .any(|arg| matches!(arg, Expression::Block(_) | Expression::Match { .. }))
+                {
+                    self.restore(start);
+                    self.call(call, true);
+                }
+            }
+            Expression::Variable(_) | Expression::Literal(_) => self.token(),
+            Expression::Binary { left, right, .. } => {
+                self.expr(left);
+                self.out.push(' ');
+                self.token(); // operator
+                self.out.push(' ');
+                self.expr(right);
+            }
+            Expression::Ref { mutable, expr } => {
+                self.token(); // &
+                if *mutable {
+                    self.token(); // mut
+                    self.out.push(' ');
+                }
+                self.expr(expr);
+            }
+            Expression::Block(block) => {
+                self.token(); // unsafe
+                self.out.push(' ');
+                self.block(&block.statements);
+            }
+            Expression::Tuple(elements) => {
+                self.token(); // (
+                self.list(elements.len(), false, |printer, index| {
+                    printer.expr(&elements[index]);
+                });
+                self.token(); // )
+            }
+            Expression::Field { expr, .. } => {
+                self.expr(expr);
+                self.token(); // .
+                self.token(); // index
+            }
+            Expression::Cast { expr, .. } => {
+                self.expr(expr);
+                self.out.push(' ');
+                self.token(); // as
+                self.out.push(' ');
+                self.ty();
+            }
+            Expression::Variant { args, .. } => {
+                self.token(); // Enum::Variant
+                if !args.is_empty() {
+                    self.token(); // (
+                    self.list(args.len(), false, |printer, index| {
+                        printer.expr(&args[index]);
+                    });
+                    self.token(); // )
+                }
+            }
+            Expression::Match { value, arms } => {
+                self.token(); // match
+                self.out.push(' ');
+                self.expr(value);
+                self.out.push(' ');
+                self.lines_in_braces(arms.len(), |printer, index| {
+                    printer.pattern(&arms[index].pattern);
+                    printer.out.push(' ');
+                    printer.token(); // =>
+                    printer.out.push(' ');
+                    printer.block(&arms[index].body.statements);
+                });
+            }
+            Expression::Closure(closure) => {
+                self.token(); // |
+                self.list(closure.params.len(), false, |printer, index| {
+                    printer.token(); // name
+                    if closure.params[index].ty.is_some() {
+                        printer.token(); // :
+                        printer.out.push(' ');
+                        printer.ty();
+                    }
+                });
+                self.token(); // |
+                self.out.push(' ');
+                self.expr(&closure.body);
+            }
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        self.token(); // _ / Enum::Variant
+        if let Pattern::Variant { bindings, .. } = pattern
+            && !bindings.is_empty()
+        {
+            self.token(); // (
+            self.list(bindings.len(), false, |printer, _| printer.token());
+            self.token(); // )
+        }
+    }
+
+    fn call(&mut self, call: &CallExpr, wrap: bool) {
+        self.token(); // name
+        if !call.type_args.is_empty() {
+            self.token(); // ::
+            self.type_args();
+        }
+        self.token(); // (
+        self.list(call.args.len(), wrap, |printer, index| {
+            printer.expr(&call.args[index]);
+        });
+        self.token(); // )
+    }
+
+    /// Prints `count` comma-separated elements, either on the current line
+    /// or one per line at one more level of indentation. Wrapped, a comment
+    /// after an element's comma stays on its line.
+    fn list(&mut self, count: usize, wrap: bool, mut element: impl FnMut(&mut Self, usize)) {
+        if wrap && count > 0 {
+            self.indent += 1;
+            if self
+                .comments
+                .get(self.comment)
+                .is_some_and(|comment| comment.text.starts_with("//"))
+            {
+                self.trailing_comment();
+            }
+            self.out.push('\n');
+        }
+        for index in 0..count {
+            if wrap {
+                self.push_indent();
+            }
+            element(self, index);
+            if index + 1 < count {
+                self.token(); // ,
+                if !wrap {
+                    self.out.push(' ');
+                }
+            }
+            if wrap {
+                self.trailing_comment();
+                self.out.push('\n');
+            }
+        }
+        if wrap && count > 0 {
+            self.indent -= 1;
+            self.push_indent();
+        }
+    }
+
+    /// Prints a type from its tokens: `*T`, `&T`, `&mut T`, `[T]`, `(A, B)`,
+    /// `[raw] fn(A) -> R`, `Name` or `Name<T, ...>`.
+    fn ty(&mut self) {
+        match self.peek() {
+            Some(TokenKind::Raw) => {
+                self.token();
+                self.out.push(' ');
+                self.ty();
+            }
+            Some(TokenKind::Fn) => {
+                self.token();
+                self.ty(); // (A, ...)
+                if matches!(self.peek(), Some(TokenKind::Arrow)) {
+                    self.out.push(' ');
+                    self.token();
+                    self.out.push(' ');
+                    self.ty();
+                }
+            }
+            Some(TokenKind::Star) => {
+                self.token();
+                self.ty();
+            }
+            Some(TokenKind::Ampersand) => {
+                self.token();
+                if matches!(self.peek(), Some(TokenKind::Identifier(name)) if name == "mut") {
+                    self.token();
+                    self.out.push(' ');
+                }
+                self.ty();
+            }
+            Some(TokenKind::OpenBracket) => {
+                self.token();
+                self.ty();
+                self.token(); // ]
+            }
+            Some(TokenKind::OpenParen) => {
+                self.token(); // (
+                if !matches!(self.peek(), Some(TokenKind::CloseParen)) {
+                    loop {
+                        self.ty();
+                        if !matches!(self.peek(), Some(TokenKind::Comma)) {
+                            break;
+                        }
+                        self.token();
+                        self.out.push(' ');
+                    }
+                }
+                self.token(); // )
+            }
+            _ => {
+                self.token();
+                if matches!(self.peek(), Some(TokenKind::LessThan)) {
+                    self.type_args();
+                }
+            }
+        }
+    }
+
+    /// Prints `<T, ...>`.
+    fn type_args(&mut self) {
+        self.token(); // <
+        loop {
+            self.ty();
+            if !matches!(self.peek(), Some(TokenKind::Comma)) {
+                break;
+            }
+            self.token();
+            self.out.push(' ');
+        }
+        self.token(); // >
+    }
+
+    // ----- tokens, comments and lines -----
+
+    fn peek(&self) -> Option<&'a TokenKind> {
+        self.tokens.get(self.pos).map(|token| &token.kind)
+    }
+
+    /// Prints the next token as written in the source.
+    fn token(&mut self) {
+        let Some(token) = self.tokens.get(self.pos) else {
+            return;
+        };
+        self.inline_comments(token);
+        let text = &self.source[token.span.start..token.span.end];
+        self.out.push_str(text);
+        self.last_line = token.span.line + text.matches('\n').count();
+        self.pos += 1;
+    }
+
+    fn next_start(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map_or(usize::MAX, |token| token.span.start)
+    }
+
+    fn next_line(&self) -> usize {
+        let token_line = self.tokens.get(self.pos).map(|token| token.span.line);
+        token_line.unwrap_or(self.last_line)
+    }
+
+    /// Places every comment before the next token on a line of its own.
+    fn leading_comments(&mut self) {
+        let next = self.next_start();
+        while let Some(comment) = self.comments.get(self.comment) {
+            if comment.span.start >= next {
+                break;
+            }
+            self.comment += 1;
+            self.begin_line_at(comment.span.line);
+            self.out.push_str(&comment.text);
+            self.out.push('\n');
+            self.last_line = self.last_line.max(comment_end_line(comment));
+        }
+    }
+
+    /// Places the comments left before `next`, which sit between two tokens
+    /// of one statement. A block comment stays in the line; a line comment
+    /// ends it, and the statement goes on one level deeper.
+    fn inline_comments(&mut self, next: &Token) {
+        while let Some(comment) = self.comments.get(self.comment)
+            && comment.span.start < next.span.start
+        {
+            self.comment += 1;
+            let line_start = self.out.rfind('\n').map_or(0, |idx| idx + 1);
+            let at_line_start = self.out[line_start..].trim().is_empty();
+            let ends_line = !comment.text.starts_with("/*") || comment.text.contains('\n');
+            if comment.text.starts_with("import ") && !at_line_start {
+                self.out.push('\n');
+                self.push_indent();
+            } else if !at_line_start
+                && !self.out.ends_with(' ')
+                && (ends_line || !self.out.ends_with(['(', '[']))
+            {
+                self.out.push(' ');
+            }
+            self.out.push_str(&comment.text);
+            self.last_line = comment_end_line(comment);
+            if ends_line {
+                self.out.push('\n');
+                let continued = usize::from(!at_line_start);
+                self.indent += continued;
+                self.push_indent();
+                self.indent -= continued;
+            } else if !matches!(
+                next.kind,
+                TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::Comma
+            ) {
+                self.out.push(' ');
+            }
+        }
+    }
+
+    /// Appends a comment that starts on the line the statement ended on.
+    fn trailing_comment(&mut self) {
+        if let Some(comment) = self.comments.get(self.comment)
+            && comment.span.line == self.last_line
+            && comment.span.start < self.next_start()
+            && comment.span.start >= self.last_token_end()
+            && !comment.text.starts_with("import ")
+        {
+            self.comment += 1;
+            self.out.push(' ');
+            self.out.push_str(&comment.text);
+            self.last_line = comment_end_line(comment);
+        }
+    }
+
+    fn last_token_end(&self) -> usize {
+        self.pos
+            .checked_sub(1)
+            .map_or(0, |index| self.tokens[index].span.end)
+    }
+
+    fn begin_line(&mut self) {
+        self.begin_line_at(self.next_line());
+    }
+
+    /// Starts an indented line for something on source line `line`, keeping
+    /// one blank line if the source had any before it.
+    fn begin_line_at(&mut self, line: usize) {
+        let blank = self.force_blank || (!self.at_block_start && line > self.last_line + 1);
+        if blank {
+            self.out.push('\n');
+        }
+        self.force_blank = false;
+        self.at_block_start = false;
+        self.push_indent();
+    }
+
+    fn end_line(&mut self) {
+        self.trailing_comment();
+        self.out.push('\n');
+    }
+
+    fn push_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            pos: self.pos,
+            comment: self.comment,
+            out_len: self.out.len(),
+            last_line: self.last_line,
+        }
+    }
+
+    fn restore(&mut self, checkpoint: Checkpoint) {
+        self.pos = checkpoint.pos;
+        self.comment = checkpoint.comment;
+        self.out.truncate(checkpoint.out_len);
+        self.last_line = checkpoint.last_line;
+    }
+
+    /// Whether what was printed since `start` spans lines or overflows.
+    fn too_long(&self, start: &Checkpoint) -> bool {
+        let printed = &self.out[start.out_len..];
+        let line_start = self.out.rfind('\n').map_or(0, |idx| idx + 1);
+        printed.contains('\n') || self.out[line_start..].chars().count() > MAX_WIDTH
+    }
+}
+
+fn comment_end_line(comment: &Comment) -> usize {
+    comment.span.line + comment.text.matches('\n').count()
+}
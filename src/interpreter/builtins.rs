@@ -0,0 +1,546 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::cell::{Ref, RefMut};
+
+use super::{Interpreter, Value};
+use crate::core::memory::raw::{self, RawPtr};
+use crate::core::memory::safe::{self, HighPtr, HighView, ValidatedPtr};
+use crate::core::memory::{self, MemoryError, allocator};
+use crate::core::types;
+
+/// Arguments of one builtin call, converted on demand to runtime types.
+struct Args(Vec<Value>);
+
+macro_rules! ref_accessors {
+    ($($name:ident => $variant:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $name(&self, i: usize) -> Result<Ref<'_, $ty>, String> {
+                let Value::Ref(slot) = self.get(i)? else {
+                    return Err(self.mismatch(i, concat!("&", stringify!($variant))));
+                };
+                Ref::filter_map(slot.borrow(), |v| match v {
+                    Value::$variant(inner) => Some(inner),
+                    _ => None,
+                })
+                .map_err(|_| self.mismatch(i, concat!("&", stringify!($variant))))
+            }
+        )*
+    };
+}
+
+macro_rules! mut_accessors {
+    ($($name:ident => $variant:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $name(&self, i: usize) -> Result<RefMut<'_, $ty>, String> {
+                let Value::Ref(slot) = self.get(i)? else {
+                    return Err(self.mismatch(i, concat!("&mut ", stringify!($variant))));
+                };
+                let borrowed = slot
+                    .try_borrow_mut()
+                    .map_err(|_| format!("argument {i} is already borrowed"))?;
+                RefMut::filter_map(borrowed, |v| match v {
+                    Value::$variant(inner) => Some(inner),
+                    _ => None,
+                })
+                .map_err(|_| self.mismatch(i, concat!("&mut ", stringify!($variant))))
+            }
+        )*
+    };
+}
+
+macro_rules! copy_accessors {
+    ($($name:ident => $variant:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $name(&self, i: usize) -> Result<$ty, String> {
+                match self.get(i)? {
+                    Value::$variant(v) => Ok(*v),
+                    _ => Err(self.mismatch(i, stringify!($variant))),
+                }
+            }
+        )*
+    };
+}
+
+impl Args {
+    fn get(&self, i: usize) -> Result<&Value, String> {
+        self.0.get(i).ok_or_else(|| format!("missing argument {i}"))
+    }
+
+    fn mismatch(&self, i: usize, expected: &str) -> String {
+        match self.0.get(i) {
+            Some(value) => format!("argument {i}: expected {expected}, got {value}"),
+            None => format!("missing argument {i}"),
+        }
+    }
+
+    fn int<T: TryFrom<i128>>(&self, i: usize) -> Result<T, String> {
+        match self.get(i)? {
+            Value::Int(v) => T::try_from(*v).map_err(|_| {
+                format!(
+                    "argument {i}: {v} is out of range for {}",
+                    std::any::type_name::<T>()
+                )
+            }),
+            _ => Err(self.mismatch(i, "integer")),
+        }
+    }
+
+    copy_accessors!(
+        raw => RawPtr: RawPtr,
+        validated => ValidatedPtr: ValidatedPtr,
+        high => HighPtr: HighPtr,
+        view => HighView: HighView,
+        memory_error => MemoryError: MemoryError,
+    );
+
+    ref_accessors!(
+        string => Str: types::String,
+        list => List: types::List,
+        split => StringSplit: types::StringSplit,
+        string_list => StringList: types::StringList,
+    );
+
+    mut_accessors!(
+        string_mut => Str: types::String,
+        list_mut => List: types::List,
+    );
+
+    fn function(&self, i: usize) -> Result<&str, String> {
+        match self.get(i)? {
+            Value::Function(name) => Ok(name),
+            _ => Err(self.mismatch(i, "function")),
+        }
+    }
+
+    fn callable(&self, i: usize) -> Result<Value, String> {
+        match self.get(i)? {
+            callee @ (Value::Function(_) | Value::Closure(_)) => Ok(callee.clone()),
+            _ => Err(self.mismatch(i, "function or closure")),
+        }
+    }
+
+    fn option(&self, i: usize) -> Result<Option<&Value>, String> {
+        match self.get(i)? {
+            Value::Option(v) => Ok(v.as_deref()),
+            _ => Err(self.mismatch(i, "Option")),
+        }
+    }
+
+    fn result(&self, i: usize) -> Result<Result<&Value, &Value>, String> {
+        match self.get(i)? {
+            Value::Result(Ok(v)) => Ok(Ok(v)),
+            Value::Result(Err(e)) => Ok(Err(e)),
+            _ => Err(self.mismatch(i, "Result")),
+        }
+    }
+
+    /// The result of a validating call, passed by value or as `&result`.
+    fn validation(&self, i: usize) -> Result<types::Result<ValidatedPtr, MemoryError>, String> {
+        let convert = |value: &Value| match value {
+            Value::Result(Ok(ok)) => match ok.as_ref() {
+                Value::ValidatedPtr(ptr) => Some(types::Result::Ok(*ptr)),
+                _ => None,
+            },
+            Value::Result(Err(err)) => match err.as_ref() {
+                Value::MemoryError(err) => Some(types::Result::Err(*err)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let converted = match self.get(i)? {
+            Value::Ref(slot) => convert(&slot.borrow()),
+            value => convert(value),
+        };
+        converted.ok_or_else(|| self.mismatch(i, "Result<ValidatedPtr, MemoryError>"))
+    }
+
+    fn option_u8(&self, i: usize) -> Result<types::Option<u8>, String> {
+        Ok(match self.option(i)? {
+            Some(Value::Int(v)) => types::Option::Some(
+                u8::try_from(*v)
+                    .map_err(|_| format!("argument {i}: {v} is out of range for u8"))?,
+            ),
+            Some(_) => return Err(self.mismatch(i, "Option<u8>")),
+            None => types::Option::None,
+        })
+    }
+
+    fn result_u8_i32(&self, i: usize) -> Result<types::Result<u8, i32>, String> {
+        Ok(match self.result(i)? {
+            Ok(Value::Int(v)) => types::Result::Ok(
+                u8::try_from(*v)
+                    .map_err(|_| format!("argument {i}: {v} is out of range for u8"))?,
+            ),
+            Err(Value::Int(e)) => types::Result::Err(
+                i32::try_from(*e)
+                    .map_err(|_| format!("argument {i}: {e} is out of range for i32"))?,
+            ),
+            _ => return Err(self.mismatch(i, "Result<u8, i32>")),
+        })
+    }
+}
+
+impl Interpreter {
+    /// Runs the builtin registered under `canonical` in `std_api`.
+    // Unit-returning builtins go through `.into()` like every other arm.
+    #[allow(clippy::unit_arg)]
+    pub(super) fn call_builtin(
+        &mut self,
+        canonical: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, String> {
+        let a = Args(args);
+        let name = canonical.strip_prefix("core::").unwrap_or(canonical);
+        let value: Value = match name {
+            // core::memory::safe
+            "memory::safe::allocate_buffer" => safe::allocate_buffer(a.int(0)?).into(),
+            "memory::safe::deallocate_buffer" => safe::deallocate_buffer(a.high(0)?).into(),
+            "memory::safe::validate_raw" => safe::validate_raw(a.raw(0)?).into(),
+            "memory::safe::into_high" => safe::into_high(a.validated(0)?).into(),
+            "memory::safe::try_allocate_buffer" => safe::try_allocate_buffer(a.int(0)?).into(),
+            "memory::safe::try_deallocate_buffer" => safe::try_deallocate_buffer(a.high(0)?).into(),
+            "memory::safe::try_validate_raw" => safe::try_validate_raw(a.raw(0)?).into(),
+            "memory::safe::try_into_high" => safe::try_into_high(a.validated(0)?).into(),
+            "memory::safe::try_read_u8" => safe::try_read_u8(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::try_write_u8" => {
+                safe::try_write_u8(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::memory_error_code" => memory::memory_error_code(a.memory_error(0)?).into(),
+            "memory::safe::validate_raw_min_len" => {
+                safe::validate_raw_min_len(a.raw(0)?, a.int(1)?).into()
+            }
+            "memory::safe::validation_is_ok" => safe::validation_is_ok(&a.validation(0)?).into(),
+            "memory::safe::validation_unwrap" => safe::validation_unwrap(a.validation(0)?).into(),
+            "memory::safe::validation_error" => safe::validation_error(&a.validation(0)?).into(),
+            "memory::safe::read_u8" => safe::read_u8(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::write_u8" => safe::write_u8(a.high(0)?, a.int(1)?, a.int(2)?).into(),
+            "memory::safe::read_u16_le" => safe::read_u16_le(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::read_u16_be" => safe::read_u16_be(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::read_u32_le" => safe::read_u32_le(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::read_u32_be" => safe::read_u32_be(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::read_u64_le" => safe::read_u64_le(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::read_u64_be" => safe::read_u64_be(a.high(0)?, a.int(1)?).into(),
+            "memory::safe::write_u16_le" => {
+                safe::write_u16_le(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::write_u16_be" => {
+                safe::write_u16_be(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::write_u32_le" => {
+                safe::write_u32_le(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::write_u32_be" => {
+                safe::write_u32_be(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::write_u64_le" => {
+                safe::write_u64_le(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::write_u64_be" => {
+                safe::write_u64_be(a.high(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::copy_from" => {
+                safe::copy_from(a.high(0)?, a.int(1)?, a.high(2)?, a.int(3)?, a.int(4)?).into()
+            }
+            "memory::safe::copy_to" => {
+                safe::copy_to(a.high(0)?, a.int(1)?, a.high(2)?, a.int(3)?, a.int(4)?).into()
+            }
+            "memory::safe::fill" => safe::fill(a.high(0)?, a.int(1)?, a.int(2)?, a.int(3)?).into(),
+            "memory::safe::view_of" => safe::view_of(a.high(0)?).into(),
+            "memory::safe::view_range" => safe::view_range(a.high(0)?, a.int(1)?, a.int(2)?).into(),
+            "memory::safe::view_len" => safe::view_len(a.view(0)?).into(),
+            "memory::safe::view_sub" => safe::view_sub(a.view(0)?, a.int(1)?, a.int(2)?).into(),
+            "memory::safe::view_head" => safe::view_head(a.view(0)?, a.int(1)?).into(),
+            "memory::safe::view_tail" => safe::view_tail(a.view(0)?, a.int(1)?).into(),
+            "memory::safe::view_read_u8" => safe::view_read_u8(a.view(0)?, a.int(1)?).into(),
+            "memory::safe::view_write_u8" => {
+                safe::view_write_u8(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_read_u16_le" => {
+                safe::view_read_u16_le(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_read_u16_be" => {
+                safe::view_read_u16_be(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_read_u32_le" => {
+                safe::view_read_u32_le(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_read_u32_be" => {
+                safe::view_read_u32_be(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_read_u64_le" => {
+                safe::view_read_u64_le(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_read_u64_be" => {
+                safe::view_read_u64_be(a.view(0)?, a.int(1)?).into()
+            }
+            "memory::safe::view_write_u16_le" => {
+                safe::view_write_u16_le(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_write_u16_be" => {
+                safe::view_write_u16_be(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_write_u32_le" => {
+                safe::view_write_u32_le(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_write_u32_be" => {
+                safe::view_write_u32_be(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_write_u64_le" => {
+                safe::view_write_u64_le(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_write_u64_be" => {
+                safe::view_write_u64_be(a.view(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "memory::safe::view_copy_from" => {
+                safe::view_copy_from(a.view(0)?, a.int(1)?, a.view(2)?).into()
+            }
+            "memory::safe::view_fill" => safe::view_fill(a.view(0)?, a.int(1)?).into(),
+            "memory::allocator::use_arena_allocator" => {
+                allocator::use_arena_allocator(a.int(0)?).into()
+            }
+            "memory::allocator::use_pool_allocator" => {
+                allocator::use_pool_allocator(a.int(0)?, a.int(1)?).into()
+            }
+
+            // core::memory::raw
+            "memory::raw::alloc" => unsafe { raw::alloc(a.int(0)?) }.into(),
+            "memory::raw::deallocate" => unsafe { raw::deallocate(a.raw(0)?) }.into(),
+            "memory::raw::write" => unsafe { raw::write(a.raw(0)?, a.int(1)?, a.int(2)?) }.into(),
+            "memory::raw::read" => unsafe { raw::read(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::validate_with" => self.validate_with(a.raw(0)?, a.function(1)?)?,
+            "memory::raw::read_u16_le" => unsafe { raw::read_u16_le(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::read_u16_be" => unsafe { raw::read_u16_be(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::read_u32_le" => unsafe { raw::read_u32_le(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::read_u32_be" => unsafe { raw::read_u32_be(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::read_u64_le" => unsafe { raw::read_u64_le(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::read_u64_be" => unsafe { raw::read_u64_be(a.raw(0)?, a.int(1)?) }.into(),
+            "memory::raw::write_u16_le" => {
+                unsafe { raw::write_u16_le(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::write_u16_be" => {
+                unsafe { raw::write_u16_be(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::write_u32_le" => {
+                unsafe { raw::write_u32_le(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::write_u32_be" => {
+                unsafe { raw::write_u32_be(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::write_u64_le" => {
+                unsafe { raw::write_u64_le(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::write_u64_be" => {
+                unsafe { raw::write_u64_be(a.raw(0)?, a.int(1)?, a.int(2)?) }.into()
+            }
+            "memory::raw::copy_from" => {
+                unsafe { raw::copy_from(a.raw(0)?, a.int(1)?, a.raw(2)?, a.int(3)?, a.int(4)?) }
+                    .into()
+            }
+            "memory::raw::copy_to" => {
+                unsafe { raw::copy_to(a.raw(0)?, a.int(1)?, a.raw(2)?, a.int(3)?, a.int(4)?) }
+                    .into()
+            }
+            "memory::raw::fill" => {
+                unsafe { raw::fill(a.raw(0)?, a.int(1)?, a.int(2)?, a.int(3)?) }.into()
+            }
+
+            // core::types: Option / Result
+            "types::option_some_u8" => types::option_some_u8(a.int(0)?).into(),
+            "types::option_none_u8" => types::option_none_u8().into(),
+            "types::option_is_some_u8" => types::option_is_some_u8(a.option_u8(0)?).into(),
+            "types::option_unwrap_u8" => types::option_unwrap_u8(a.option_u8(0)?).into(),
+            "types::option_is_some" => a.option(0)?.is_some().into(),
+            "types::option_unwrap" => match a.option(0)? {
+                Some(v) => v.clone(),
+                None => panic!("called unwrap on None"),
+            },
+            "types::result_is_ok" => a.result(0)?.is_ok().into(),
+            "types::result_is_err" => a.result(0)?.is_err().into(),
+            "types::result_unwrap" => match a.result(0)? {
+                Ok(v) => v.clone(),
+                Err(_) => panic!("called unwrap on Err"),
+            },
+            "types::result_unwrap_err" => match a.result(0)? {
+                Ok(_) => panic!("called unwrap_err on Ok"),
+                Err(e) => e.clone(),
+            },
+            "types::result_ok_u8_i32" => types::result_ok_u8_i32(a.int(0)?).into(),
+            "types::result_err_u8_i32" => types::result_err_u8_i32(a.int(0)?).into(),
+            "types::result_is_ok_u8_i32" => types::result_is_ok_u8_i32(a.result_u8_i32(0)?).into(),
+            "types::result_unwrap_u8_i32" => {
+                types::result_unwrap_u8_i32(a.result_u8_i32(0)?).into()
+            }
+            "types::result_unwrap_err_u8_i32" => {
+                types::result_unwrap_err_u8_i32(a.result_u8_i32(0)?).into()
+            }
+
+            // core::types: String
+            "types::string_new" => types::string_new().into(),
+            "types::string_clone" => types::string_clone(&*a.string(0)?).into(),
+            "types::string_len" => types::string_len(&*a.string(0)?).into(),
+            "types::string_is_empty" => types::string_is_empty(&*a.string(0)?).into(),
+            "types::string_concat" => types::string_concat(&*a.string(0)?, &*a.string(1)?).into(),
+            "types::string_eq" => types::string_eq(&*a.string(0)?, &*a.string(1)?).into(),
+            "types::string_substr" => {
+                types::string_substr(&*a.string(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "types::string_starts_with" => {
+                types::string_starts_with(&*a.string(0)?, &*a.string(1)?).into()
+            }
+            "types::string_ends_with" => {
+                types::string_ends_with(&*a.string(0)?, &*a.string(1)?).into()
+            }
+            "types::string_contains" => {
+                types::string_contains(&*a.string(0)?, &*a.string(1)?).into()
+            }
+            "types::string_push" => types::string_push(&mut *a.string_mut(0)?, a.int(1)?).into(),
+            "types::string_push_bytes" => {
+                types::string_push_bytes(&mut *a.string_mut(0)?, &*a.list(1)?).into()
+            }
+            "types::string_push_str" => {
+                types::string_push_str(&mut *a.string_mut(0)?, &*a.string(1)?).into()
+            }
+            "types::string_clear" => types::string_clear(&mut *a.string_mut(0)?).into(),
+            "types::string_clear_with_capacity" => {
+                types::string_clear_with_capacity(&mut *a.string_mut(0)?).into()
+            }
+            "types::string_append_bytes" => {
+                types::string_append_bytes(&mut *a.string_mut(0)?, &*a.list(1)?).into()
+            }
+            "types::string_pop" => types::string_pop(&mut *a.string_mut(0)?).into(),
+            "types::string_pop_n" => types::string_pop_n(&mut *a.string_mut(0)?, a.int(1)?).into(),
+            "types::string_remove" => {
+                types::string_remove(&mut *a.string_mut(0)?, a.int(1)?).into()
+            }
+            "types::string_remove_range" => {
+                types::string_remove_range(&mut *a.string_mut(0)?, a.int(1)?, a.int(2)?).into()
+            }
+            "types::string_insert_bytes" => {
+                types::string_insert_bytes(&mut *a.string_mut(0)?, a.int(1)?, &*a.list(2)?).into()
+            }
+            "types::string_replace" => {
+                types::string_replace(&*a.string(0)?, &*a.string(1)?, &*a.string(2)?).into()
+            }
+            "types::string_trim" => types::string_trim(&*a.string(0)?).into(),
+            "types::string_trim_start" => types::string_trim_start(&*a.string(0)?).into(),
+            "types::string_trim_end" => types::string_trim_end(&*a.string(0)?).into(),
+            "types::string_split_once" => {
+                types::string_split_once(&*a.string(0)?, &*a.string(1)?).into()
+            }
+            "types::string_split_all" => {
+                types::string_split_all(&*a.string(0)?, &*a.string(1)?).into()
+            }
+            "types::string_split_n" => {
+                types::string_split_n(&*a.string(0)?, &*a.string(1)?, a.int(2)?).into()
+            }
+            "types::string_split_found" => types::string_split_found(&*a.split(0)?).into(),
+            "types::string_split_left" => types::string_split_left(&*a.split(0)?).into(),
+            "types::string_split_right" => types::string_split_right(&*a.split(0)?).into(),
+            "types::string_list_len" => types::string_list_len(&*a.string_list(0)?).into(),
+            "types::string_list_is_empty" => {
+                types::string_list_is_empty(&*a.string_list(0)?).into()
+            }
+            "types::string_list_get" => {
+                types::string_list_get(&*a.string_list(0)?, a.int(1)?).into()
+            }
+            "types::string_from_list" => types::string_from_list(&*a.list(0)?).into(),
+            "types::string_to_list" => types::string_to_list(&*a.string(0)?).into(),
+
+            // core::types: List
+            "types::list_new" => types::list_new().into(),
+            "types::list_len" => types::list_len(&*a.list(0)?).into(),
+            "types::list_is_empty" => types::list_is_empty(&*a.list(0)?).into(),
+            "types::list_push_u8" => types::list_push_u8(&mut *a.list_mut(0)?, a.int(1)?).into(),
+            "types::list_get_u8" => types::list_get_u8(&*a.list(0)?, a.int(1)?).into(),
+            "types::list_push_bytes" => {
+                types::list_push_bytes(&mut *a.list_mut(0)?, &*a.list(1)?).into()
+            }
+            "types::list_map_u8" => self.list_map_u8(&*a.list(0)?, &a.callable(1)?)?,
+            "types::list_filter_u8" => self.list_filter_u8(&*a.list(0)?, &a.callable(1)?)?,
+            _ => {
+                return Err(format!(
+                    "Builtin '{canonical}' is not supported by the interpreter"
+                ));
+            }
+        };
+        Ok(value)
+    }
+
+    /// `raw::validate_with` with an interpreted predicate.
+    fn validate_with(&mut self, ptr: RawPtr, predicate: &str) -> Result<Value, String> {
+        let mut failure = None;
+        let result = safe::validate_raw_checked(ptr, |bytes| {
+            let args = vec![Value::RawPtr(ptr), Value::from(bytes.len())];
+            match self.call_function(predicate, args) {
+                Ok(Value::Bool(accepted)) => accepted,
+                Ok(other) => {
+                    failure = Some(format!("Predicate '{predicate}' returned {other}"));
+                    false
+                }
+                Err(err) => {
+                    failure = Some(err);
+                    false
+                }
+            }
+        });
+        match failure {
+            Some(err) => Err(err),
+            None => Ok(result.into()),
+        }
+    }
+
+    /// `list_map_u8` with an interpreted function or closure.
+    fn list_map_u8(&mut self, list: &types::List, f: &Value) -> Result<Value, String> {
+        let mut failure = None;
+        let mapped = types::list_map_u8(list, |value| {
+            if failure.is_some() {
+                return value;
+            }
+            match self.call_value(f, vec![Value::from(value)]) {
+                Ok(Value::Int(out)) if u8::try_from(out).is_ok() => out as u8,
+                Ok(other) => {
+                    failure = Some(format!("{f} returned {other}, expected a u8"));
+                    value
+                }
+                Err(err) => {
+                    failure = Some(err);
+                    value
+                }
+            }
+        });
+        match failure {
+            Some(err) => Err(err),
+            None => Ok(mapped.into()),
+        }
+    }
+
+    /// `list_filter_u8` with an interpreted predicate.
+    fn list_filter_u8(&mut self, list: &types::List, keep: &Value) -> Result<Value, String> {
+        let mut failure = None;
+        let kept = types::list_filter_u8(list, |value| {
+            if failure.is_some() {
+                return false;
+            }
+            match self.call_value(keep, vec![Value::from(value)]) {
+                Ok(Value::Bool(accepted)) => accepted,
+                Ok(other) => {
+                    failure = Some(format!("{keep} returned {other}, expected a bool"));
+                    false
+                }
+                Err(err) => {
+                    failure = Some(err);
+                    false
+                }
+            }
+        });
+        match failure {
+            Some(err) => Err(err),
+            None => Ok(kept.into()),
+        }
+    }
+}
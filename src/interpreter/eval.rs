@@ -0,0 +1,485 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use super::{ClosureValue, Interpreter, Slot, Value, guarded};
+use crate::ast::*;
+use crate::std_api;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Outcome of running a statement or block.
+enum Flow {
+    Next(Value),
+    Break,
+    Continue,
+}
+
+impl Interpreter {
+    pub(super) fn call_function(&mut self, name: &str, args: Vec<Value>) -> Result<Value, String> {
+        let func = self
+            .functions
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Undefined function: '{name}'"))?;
+        if func.args.len() != args.len() {
+            return Err(format!(
+                "Function '{name}' expects {} arguments, got {}",
+                func.args.len(),
+                args.len()
+            ));
+        }
+
+        let frame = func
+            .args
+            .iter()
+            .zip(args)
+            .map(|(arg, value)| (arg.name.clone(), value.slot()))
+            .collect::<HashMap<_, _>>();
+        let caller = std::mem::replace(&mut self.scopes, vec![frame]);
+        let caller_file = std::mem::replace(&mut self.file, func.file.clone());
+        let result = self.exec_block(&func.body);
+        self.scopes = caller;
+        self.file = caller_file;
+
+        match result? {
+            Flow::Next(value) => Ok(value),
+            Flow::Break | Flow::Continue => {
+                Err(format!("'break'/'continue' escaped the body of '{name}'"))
+            }
+        }
+    }
+
+    pub(super) fn exec_top_level(&mut self, statements: &[Statement]) -> Result<Value, String> {
+        match self.exec_statements(statements)? {
+            Flow::Next(value) => Ok(value),
+            Flow::Break | Flow::Continue => Err("'break'/'continue' outside of a loop".to_string()),
+        }
+    }
+
+    fn exec_block(&mut self, block: &Block) -> Result<Flow, String> {
+        self.scopes.push(HashMap::new());
+        let flow = self.exec_statements(&block.statements);
+        self.scopes.pop();
+        flow
+    }
+
+    fn exec_statements(&mut self, statements: &[Statement]) -> Result<Flow, String> {
+        let mut last = Value::Unit;
+        for (idx, stmt) in statements.iter().enumerate() {
+            let is_last = idx + 1 == statements.len();
+            last = Value::Unit;
+            match stmt {
+                Statement::Let(l) => {
+                    let value = self.eval(&l.value)?;
+                    self.bind(&l.name, value);
+                }
+                Statement::LetTuple(l) => {
+                    let Value::Tuple(values) = self.eval(&l.value)? else {
+                        return Err("Only a tuple can be destructured".to_string());
+                    };
+                    for (name, value) in l.names.iter().zip(values) {
+                        self.bind(name, value);
+                    }
+                }
+                Statement::Const(c) => {
+                    let value = self.eval(&c.value)?;
+                    self.bind(&c.name, value);
+                }
+                Statement::If(stmt) => {
+                    let matched = match &stmt.pattern {
+                        Some(pattern) => {
+                            let value = self.eval(&stmt.condition)?;
+                            Self::match_pattern(pattern, value)
+                        }
+                        None => self.eval_bool(&stmt.condition)?.then(Vec::new),
+                    };
+                    let flow = if let Some(bindings) = matched {
+                        self.exec_arm(bindings, &stmt.then_block)?
+                    } else if let Some(else_block) = &stmt.else_block {
+                        self.exec_block(else_block)?
+                    } else {
+                        Flow::Next(Value::Unit)
+                    };
+                    if !matches!(flow, Flow::Next(_)) {
+                        return Ok(flow);
+                    }
+                }
+                Statement::For(stmt) => self.exec_for(stmt)?,
+                Statement::Break => return Ok(Flow::Break),
+                Statement::Continue => return Ok(Flow::Continue),
+                Statement::Expr(Expression::Match { value, arms }) => {
+                    // `break`/`continue` in an arm leave the enclosing loop.
+                    match self.exec_match(value, arms)? {
+                        Flow::Next(value) if is_last => last = value,
+                        Flow::Next(_) => {}
+                        flow => return Ok(flow),
+                    }
+                }
+                Statement::Expr(e) => {
+                    let value = self.eval(e)?;
+                    if is_last {
+                        last = value;
+                    }
+                }
+            }
+        }
+        Ok(Flow::Next(last))
+    }
+
+    fn exec_for(&mut self, stmt: &ForStatement) -> Result<(), String> {
+        let start = self.eval_int(&stmt.start)?;
+        let end = self.eval_int(&stmt.end)?;
+        let end = if stmt.inclusive { end + 1 } else { end };
+
+        for i in start..end {
+            self.scopes.push(HashMap::from([(
+                stmt.var_name.clone(),
+                Value::Int(i).slot(),
+            )]));
+            let flow = self.exec_block(&stmt.body);
+            self.scopes.pop();
+            match flow? {
+                Flow::Break => break,
+                Flow::Next(_) | Flow::Continue => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn exec_match(&mut self, value: &Expression, arms: &[MatchArm]) -> Result<Flow, String> {
+        let value = self.eval(value)?;
+        for arm in arms {
+            if let Some(bindings) = Self::match_pattern(&arm.pattern, value.clone()) {
+                return self.exec_arm(bindings, &arm.body);
+            }
+        }
+        Err(format!("No match arm for {value}"))
+    }
+
+    /// Runs `body` with the bindings of its pattern in scope.
+    fn exec_arm(&mut self, bindings: Vec<(String, Value)>, body: &Block) -> Result<Flow, String> {
+        self.scopes.push(
+            bindings
+                .into_iter()
+                .map(|(name, value)| (name, value.slot()))
+                .collect(),
+        );
+        let flow = self.exec_block(body);
+        self.scopes.pop();
+        flow
+    }
+
+    /// The bindings made by matching `value`, or `None` if it does not match.
+    fn match_pattern(pattern: &Pattern, value: Value) -> Option<Vec<(String, Value)>> {
+        let Pattern::Variant {
+            variant, bindings, ..
+        } = pattern
+        else {
+            return Some(Vec::new());
+        };
+        match value {
+            Value::Variant {
+                variant: actual,
+                payload,
+                ..
+            } if actual == *variant => Some(
+                bindings
+                    .iter()
+                    .cloned()
+                    .zip(payload)
+                    .filter(|(name, _)| name != "_")
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn bind(&mut self, name: &str, value: Value) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), value.slot());
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Slot> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    pub(super) fn eval(&mut self, expr: &Expression) -> Result<Value, String> {
+        match expr {
+            Expression::Literal(lit) => Ok(match lit {
+                Literal::Integer(i) => Value::Int(i128::from(*i)),
+                Literal::String(s) => Value::Str(s.as_str().into()),
+                Literal::Bool(b) => Value::Bool(*b),
+            }),
+            Expression::Variable(name) => {
+                if let Some(slot) = self.lookup(name) {
+                    return Ok(slot.borrow().clone());
+                }
+                if self.functions.contains_key(name) {
+                    return Ok(Value::Function(name.clone()));
+                }
+                Err(format!("Undefined variable: '{name}'"))
+            }
+            Expression::Binary { op, left, right } => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                Self::compare(op, &left, &right).map(Value::Bool)
+            }
+            Expression::Ref { expr, .. } => {
+                if let Expression::Variable(name) = expr.as_ref()
+                    && let Some(slot) = self.lookup(name)
+                {
+                    return Ok(Value::Ref(Rc::clone(slot)));
+                }
+                Ok(Value::Ref(self.eval(expr)?.slot()))
+            }
+            Expression::Call(call) => {
+                if let Some(slot) = self.lookup(&call.func_name) {
+                    let callee = slot.borrow().clone();
+                    if !matches!(callee, Value::Function(_) | Value::Closure(_)) {
+                        return Err(format!("'{}' is not a function: {callee}", call.func_name));
+                    }
+                    let args = call
+                        .args
+                        .iter()
+                        .map(|arg| self.eval(arg))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    return self.call_value(&callee, args);
+                }
+                let name = self.resolve_alias(&call.func_name)?;
+                let canonical = std_api::canonical_name(&name).unwrap_or(&name);
+                if std_api::is_testing_function(canonical) {
+                    return self.eval_testing_call(canonical, call);
+                }
+                if std_api::is_cast_function(canonical) {
+                    return self.eval_cast_call(canonical, call);
+                }
+                let args = call
+                    .args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if self.functions.contains_key(&name) {
+                    return self.call_function(&name, args);
+                }
+                if std_api::is_print_function(&name) || std_api::is_printl_function(&name) {
+                    for arg in &args {
+                        print!("{arg}");
+                    }
+                    if std_api::is_printl_function(&name) {
+                        println!();
+                    }
+                    return Ok(Value::Unit);
+                }
+                self.call_builtin(canonical, args)
+            }
+            Expression::Tuple(elements) => Ok(Value::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.eval(element))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Expression::Field { expr, index } => match self.eval(expr)? {
+                Value::Tuple(mut values) if *index < values.len() => Ok(values.swap_remove(*index)),
+                other => Err(format!("No field .{index} on {other}")),
+            },
+            Expression::Cast { expr, ty } => {
+                let value = self.eval_int(expr)?;
+                let (name, min, max) = Self::integer_range(ty)?;
+                if !(min..=max).contains(&value) {
+                    return Err(format!("'as' cannot convert {value} to {name}"));
+                }
+                Ok(Value::Int(value))
+            }
+            Expression::Variant {
+                enum_name,
+                variant,
+                args,
+            } => Ok(Value::Variant {
+                enum_name: enum_name.clone(),
+                variant: variant.clone(),
+                payload: args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<_, _>>()?,
+            }),
+            Expression::Match { value, arms } => match self.exec_match(value, arms)? {
+                Flow::Next(value) => Ok(value),
+                Flow::Break | Flow::Continue => {
+                    Err("'break'/'continue' inside a match used as a value".to_string())
+                }
+            },
+            Expression::Block(block) => match self.exec_block(block)? {
+                Flow::Next(value) => Ok(value),
+                Flow::Break | Flow::Continue => {
+                    Err("'break'/'continue' inside a block expression".to_string())
+                }
+            },
+            Expression::Closure(closure) => {
+                let mut captured = HashMap::new();
+                for capture in closure.captures() {
+                    let Some(slot) = self.lookup(&capture.name) else {
+                        continue;
+                    };
+                    let slot = match capture.mode {
+                        CaptureMode::Ref => Rc::clone(slot),
+                        CaptureMode::Move | CaptureMode::RefMut => slot.borrow().clone().slot(),
+                    };
+                    captured.insert(capture.name, slot);
+                }
+                Ok(Value::Closure(Rc::new(ClosureValue {
+                    params: closure.params.iter().map(|p| p.name.clone()).collect(),
+                    body: closure.body.as_ref().clone(),
+                    captured,
+                })))
+            }
+        }
+    }
+
+    /// Calls a function or closure value.
+    pub(super) fn call_value(&mut self, callee: &Value, args: Vec<Value>) -> Result<Value, String> {
+        let closure = match callee {
+            Value::Function(name) => return self.call_function(name, args),
+            Value::Closure(closure) => Rc::clone(closure),
+            other => return Err(format!("{other} is not a function")),
+        };
+        if closure.params.len() != args.len() {
+            return Err(format!(
+                "Closure expects {} arguments, got {}",
+                closure.params.len(),
+                args.len()
+            ));
+        }
+        let mut frame = closure.captured.clone();
+        for (param, value) in closure.params.iter().zip(args) {
+            frame.insert(param.clone(), value.slot());
+        }
+        let caller = std::mem::replace(&mut self.scopes, vec![frame]);
+        let result = self.eval(&closure.body);
+        self.scopes = caller;
+        result
+    }
+
+    /// `assert`, `assert_eq` and `expect_panic`, failing with the SAFE?
+    /// source location like the generated Rust does.
+    fn eval_testing_call(&mut self, canonical: &str, call: &CallExpr) -> Result<Value, String> {
+        let location = std_api::source_location(self.file.as_deref(), call.line);
+        if canonical == "core::testing::expect_panic" {
+            let scopes = self.scopes.clone();
+            let file = self.file.clone();
+            let outcome = guarded(|| self.eval(&call.args[0]));
+            self.scopes = scopes;
+            self.file = file;
+            return match outcome {
+                Ok(_) => Err(format!("expected a panic at {location}")),
+                Err(_) => Ok(Value::Unit),
+            };
+        }
+
+        let args = call
+            .args
+            .iter()
+            .map(|arg| self.eval(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        match (canonical, args.as_slice()) {
+            ("core::testing::assert", [Value::Bool(true)]) => Ok(Value::Unit),
+            ("core::testing::assert", [Value::Bool(false)]) => {
+                Err(format!("assertion failed at {location}"))
+            }
+            ("core::testing::assert_eq", [left, right]) => match left.equals(right) {
+                Some(true) => Ok(Value::Unit),
+                _ => Err(format!(
+                    "assertion failed at {location}: left {left} != right {right}"
+                )),
+            },
+            _ => Err(format!("Invalid arguments to '{canonical}'")),
+        }
+    }
+
+    /// `checked_cast`, `wrapping_cast` and `saturating_cast` to the type
+    /// argument of the call.
+    fn eval_cast_call(&mut self, canonical: &str, call: &CallExpr) -> Result<Value, String> {
+        let [target] = call.type_args.as_slice() else {
+            return Err(format!("'{canonical}' needs a target type"));
+        };
+        let value = self.eval_int(&call.args[0])?;
+        let (_, min, max) = Self::integer_range(target)?;
+        Ok(match canonical {
+            "core::types::checked_cast" => Value::Option(
+                (min..=max)
+                    .contains(&value)
+                    .then(|| Box::new(Value::Int(value))),
+            ),
+            "core::types::wrapping_cast" => {
+                Value::Int((value - min).rem_euclid(max - min + 1) + min)
+            }
+            _ => Value::Int(value.clamp(min, max)),
+        })
+    }
+
+    /// Name and bounds of the integer type `ty`.
+    fn integer_range(ty: &Type) -> Result<(&str, i128, i128), String> {
+        // Compiled code casts on the host too, so `isize` and `usize` take
+        // its width.
+        let host = |name: &str| match name {
+            "isize" => Some((isize::MIN as i128, isize::MAX as i128)),
+            "usize" => Some((0, usize::MAX as i128)),
+            _ => std_api::integer_range(name),
+        };
+        if let Type::Path(name) = ty
+            && let Some((min, max)) = host(name)
+        {
+            return Ok((name, min, max));
+        }
+        Err("Casts need an integer target type".to_string())
+    }
+
+    fn eval_bool(&mut self, expr: &Expression) -> Result<bool, String> {
+        match self.eval(expr)? {
+            Value::Bool(b) => Ok(b),
+            other => Err(format!("Expected bool, got {other}")),
+        }
+    }
+
+    fn eval_int(&mut self, expr: &Expression) -> Result<i128, String> {
+        match self.eval(expr)? {
+            Value::Int(i) => Ok(i),
+            other => Err(format!("Expected integer, got {other}")),
+        }
+    }
+
+    fn compare(op: &BinaryOp, left: &Value, right: &Value) -> Result<bool, String> {
+        let mismatch = || format!("Cannot compare {left} with {right}");
+        match op {
+            BinaryOp::Equal => left.equals(right).ok_or_else(mismatch),
+            BinaryOp::NotEqual => left.equals(right).map(|eq| !eq).ok_or_else(mismatch),
+            _ => {
+                let (Value::Int(a), Value::Int(b)) = (left, right) else {
+                    return Err(mismatch());
+                };
+                Ok(match op {
+                    BinaryOp::LessThan => a < b,
+                    BinaryOp::LessEqual => a <= b,
+                    BinaryOp::GreaterThan => a > b,
+                    _ => a >= b,
+                })
+            }
+        }
+    }
+
+    fn resolve_alias(&self, name: &str) -> Result<String, String> {
+        let mut seen = HashSet::new();
+        let mut current = name.to_string();
+        while let Some(next) = self.aliases.get(&current) {
+            if !seen.insert(current.clone()) {
+                return Err(format!("Alias cycle detected: '{current}'"));
+            }
+            current = next.clone();
+        }
+        Ok(current)
+    }
+}
@@ -0,0 +1,101 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Tree-walking interpreter over a molded and type-checked `SourceFile`.
+//!
+//! Builtins call straight into `core::memory` and `core::types`, so a
+//! program behaves as its generated Rust would, without invoking rustc.
+
+mod builtins;
+mod eval;
+mod repl;
+mod value;
+
+pub use repl::Repl;
+pub use value::{ClosureValue, Slot, Value};
+
+use crate::ast::*;
+use crate::core::testing;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct Interpreter {
+    functions: HashMap<String, Function>,
+    aliases: HashMap<String, String>,
+    scopes: Vec<HashMap<String, Slot>>,
+    /// File of the running function, for assertion locations.
+    file: Option<String>,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the functions and aliases of `source`, replacing earlier
+    /// definitions with the same name.
+    pub fn load(&mut self, source: &SourceFile) {
+        for item in &source.items {
+            match item {
+                Item::Function(func) => {
+                    self.functions.insert(func.name.clone(), func.clone());
+                }
+                Item::Alias(alias) => {
+                    self.aliases
+                        .insert(alias.name.clone(), alias.target.clone());
+                }
+                Item::Struct(_) | Item::Enum(_) => {}
+            }
+        }
+    }
+
+    pub fn has_function(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Calls `main` with no arguments.
+    pub fn run_main(&mut self) -> Result<Value, String> {
+        if !self.has_function("main") {
+            return Err("No 'main' function to run".to_string());
+        }
+        self.call("main", Vec::new())
+    }
+
+    /// Calls a user function. Runtime panics (bounds checks, invalid
+    /// pointers) are reported as errors.
+    pub fn call(&mut self, name: &str, args: Vec<Value>) -> Result<Value, String> {
+        let saved = std::mem::take(&mut self.scopes);
+        let file = self.file.take();
+        let result = guarded(|| self.call_function(name, args));
+        self.scopes = saved;
+        self.file = file;
+        result
+    }
+
+    /// Runs `statements` in the persistent top-level scope, keeping their
+    /// bindings for later calls. Returns the value of a trailing expression.
+    pub fn run_statements(&mut self, statements: &[Statement]) -> Result<Value, String> {
+        if self.scopes.is_empty() {
+            self.scopes.push(HashMap::new());
+        }
+        guarded(|| self.exec_top_level(statements))
+    }
+}
+
+fn guarded<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    testing::catch_silently(f).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(format!("Runtime panic: {message}"))
+    })
+}
+
+#[cfg(test)]
+mod tests;
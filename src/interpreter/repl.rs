@@ -0,0 +1,177 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::io::{self, BufRead, Write};
+
+use super::Interpreter;
+use crate::ast::*;
+use crate::cli;
+
+/// Wrapper function that holds the statements typed into the session.
+const SESSION_FN: &str = "__repl";
+const PROMPT: &str = "safe> ";
+const CONTINUATION_PROMPT: &str = "  ... ";
+
+/// Interactive session. Every input is molded and type-checked together with
+/// everything accepted before it, then only the new statements are executed,
+/// so bindings persist between lines.
+#[derive(Default)]
+pub struct Repl {
+    interpreter: Interpreter,
+    definitions: String,
+    history: Vec<String>,
+    executed: usize,
+}
+
+impl Repl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluates one complete input: definitions if it parses as items, or
+    /// statements. Returns the value of a trailing expression when it is not
+    /// `()`.
+    pub fn eval(&mut self, input: &str) -> Result<Option<String>, String> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+
+        if Self::is_definition(trimmed) {
+            let definitions = format!("{}{}\n", self.definitions, input);
+            let checked = Self::check(&definitions, &self.history)?;
+            self.interpreter.load(&checked);
+            self.definitions = definitions;
+            return Ok(None);
+        }
+
+        let mut history = self.history.clone();
+        history.push(input.to_string());
+        let checked = Self::check(&self.definitions, &history)?;
+        let statements = Self::session_statements(&checked)?;
+        // The last statement is the `()` tail added by `check`.
+        let total = statements.len() - 1;
+
+        self.interpreter.load(&checked);
+        let value = self
+            .interpreter
+            .run_statements(&statements[self.executed..total])?;
+        self.history = history;
+        self.executed = total;
+        Ok((!value.is_unit()).then(|| value.to_string()))
+    }
+
+    /// Reads inputs from `input` until EOF or `:quit`. Lines are joined until
+    /// their braces balance, so functions and blocks can span several lines,
+    /// and attribute lines wait for the item or statement they annotate.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        let mut pending = String::new();
+        write!(output, "{PROMPT}")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+            if pending.is_empty() {
+                match line.trim() {
+                    ":quit" | ":q" => return Ok(()),
+                    ":reset" => {
+                        *self = Self::new();
+                        write!(output, "{PROMPT}")?;
+                        output.flush()?;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            pending.push_str(&line);
+            pending.push('\n');
+            if Self::brace_depth(&pending) > 0 || Self::only_attributes(&pending) {
+                write!(output, "{CONTINUATION_PROMPT}")?;
+                output.flush()?;
+                continue;
+            }
+
+            match self.eval(&pending) {
+                Ok(Some(value)) => writeln!(output, "{value}")?,
+                Ok(None) => {}
+                Err(err) => writeln!(output, "error: {err}")?,
+            }
+            pending.clear();
+            write!(output, "{PROMPT}")?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+
+    /// Whether `input` parses on its own as one or more items.
+    fn is_definition(input: &str) -> bool {
+        cli::parse_source(input).is_ok_and(|source| !source.items.is_empty())
+    }
+
+    fn only_attributes(input: &str) -> bool {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .all(|line| line.starts_with("#["))
+    }
+
+    fn brace_depth(input: &str) -> i32 {
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        for ch in input.chars() {
+            match ch {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => depth -= 1,
+                _ => {}
+            }
+        }
+        depth
+    }
+
+    /// Molds and type-checks the definitions plus the session function.
+    fn check(definitions: &str, history: &[String]) -> Result<SourceFile, String> {
+        let text = format!(
+            "{definitions}\nsafe fn {SESSION_FN}() {{\n{}\n}}\n",
+            history.join("\n")
+        );
+        let mut source = cli::parse_source(&text)?;
+        // A bare expression on the last line is echoed, not returned, so
+        // the session function always ends in `()`.
+        for item in &mut source.items {
+            if let Item::Function(func) = item
+                && func.name == SESSION_FN
+            {
+                func.body
+                    .statements
+                    .push(Statement::Expr(Expression::Block(Block {
+                        statements: Vec::new(),
+                        unsafe_block: false,
+                    })));
+            }
+        }
+        cli::mold_and_check(source)
+    }
+
+    fn session_statements(source: &SourceFile) -> Result<Vec<Statement>, String> {
+        source
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Function(func) if func.name == SESSION_FN => {
+                    Some(func.body.statements.clone())
+                }
+                _ => None,
+            })
+            .ok_or_else(|| "REPL session function is missing".to_string())
+    }
+}
@@ -0,0 +1,361 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use super::{Interpreter, Repl, Value};
+use crate::cli::{mold_and_check, parse_source};
+
+fn load(code: &str) -> Interpreter {
+    let source = mold_and_check(parse_source(code).expect("parse")).expect("check");
+    let mut interpreter = Interpreter::new();
+    interpreter.load(&source);
+    interpreter
+}
+
+fn call(code: &str, name: &str) -> Result<Value, String> {
+    load(code).call(name, Vec::new())
+}
+
+#[test]
+fn test_interpreter_runs_promotion_chain_against_runtime() {
+    let code = r#"
+safe fn roundtrip() -> u8 {
+    let high_out = unsafe {
+        let raw_p = raw_alloc(4)
+        raw_write(raw_p, 1, 42)
+        let validated_p = validate_raw(raw_p)
+        into_high(validated_p)
+    }
+    let high_byte = buffer_read_u8(high_out, 1)
+    deallocate_buffer(high_out)
+    high_byte
+}
+"#;
+    let value = call(code, "roundtrip").expect("run");
+    assert!(matches!(value, Value::Int(42)));
+}
+
+#[test]
+fn test_interpreter_cast_functions() {
+    let code = r#"
+safe fn casts() -> i64 {
+    let high_checked = checked_cast::<u8>(300)
+    assert_eq(option_is_some(high_checked), false)
+    assert_eq(wrapping_cast::<u8>(300), 44)
+    assert_eq(saturating_cast::<i8>(-300), -128)
+    let high_byte: u8 = 200
+    high_byte as i64
+}
+"#;
+    let value = call(code, "casts").expect("run");
+    assert!(matches!(value, Value::Int(200)));
+}
+
+#[test]
+fn test_interpreter_tuples() {
+    let code = r#"
+safe fn split(high_len: usize) -> (u8, usize) {
+    (7, high_len)
+}
+
+safe fn sum() -> usize {
+    let (high_tag, high_used) = split(3)
+    let high_pair = (high_used, (high_tag, 10))
+    assert_eq(high_pair.1.0, 7)
+    high_pair.0
+}
+"#;
+    let value = call(code, "sum").expect("run");
+    assert!(matches!(value, Value::Int(3)));
+}
+
+#[test]
+fn test_interpreter_enums() {
+    let code = r#"
+enum Packet {
+    Ping,
+    Data(u16),
+    Error(i32),
+}
+
+safe fn weight(high_p: Packet) -> i32 {
+    match high_p {
+        Packet::Data(high_len) => { high_len as i32 }
+        Packet::Error(high_code) => { high_code }
+        _ => { 1 }
+    }
+}
+
+safe fn total() -> i32 {
+    assert_eq(weight(Packet::Ping), 1)
+    assert_eq(weight(Packet::Data(40)), 40)
+    let high_buf = allocate_buffer(1)
+    for high_i in 0..3 {
+        match Packet::Error(high_i) {
+            Packet::Error(high_code) => {
+                if high_code == 1 {
+                    continue
+                }
+            }
+            _ => {}
+        }
+        buffer_write_u8(high_buf, 0, wrapping_cast::<u8>(high_i))
+    }
+    assert_eq(buffer_read_u8(high_buf, 0), 2)
+    if let Packet::Data(high_len) = Packet::Error(-1) {
+        buffer_write_u8(high_buf, 0, 9)
+    } else {
+        buffer_write_u8(high_buf, 0, 5)
+    }
+    assert_eq(buffer_read_u8(high_buf, 0), 5)
+    weight(Packet::Error(-1))
+}
+"#;
+    let value = call(code, "total").expect("run");
+    assert!(matches!(value, Value::Int(-1)));
+}
+
+#[test]
+fn test_interpreter_function_values() {
+    let code = r#"
+safe fn is_small(high_x: u8) -> bool {
+    high_x < 3
+}
+
+raw fn peek(raw_x: u8) -> u8 {
+    raw_x
+}
+
+safe fn last_match(high_pred: fn(u8) -> bool) -> u8 {
+    let high_buf = allocate_buffer(1)
+    for high_i in 0..10 {
+        if high_pred(high_i) {
+            buffer_write_u8(high_buf, 0, high_i)
+        }
+    }
+    buffer_read_u8(high_buf, 0)
+}
+
+safe fn run() -> u8 {
+    let high_check = is_small
+    assert_eq(high_check(5), false)
+    assert_eq(last_match(high_check), 2)
+    unsafe {
+        let raw_f = peek
+        raw_f(9)
+    }
+}
+"#;
+    let value = call(code, "run").expect("run");
+    assert!(matches!(value, Value::Int(9)));
+}
+
+#[test]
+fn test_interpreter_closures() {
+    let code = r#"
+safe fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+safe fn run() -> u8 {
+    let high_table = string_to_list(&"abc")
+    let high_limit: u8 = 99
+    let high_lookup = |high_i: u8| option_unwrap_u8(list_get_u8(&high_table, high_i as usize))
+    assert_eq(high_lookup(2), 99)
+    let high_small = list_filter_u8(&high_table, |high_x| high_x < high_limit)
+    assert_eq(list_len(&high_small), 2)
+    let high_shifted = list_map_u8(&list_new(), high_lookup)
+    assert_eq(list_len(&high_shifted), 0)
+    assert_eq(apply(|high_x| high_x, 4), 4)
+    unsafe {
+        let raw_v: u8 = 7
+        let raw_f = |high_x: u8| high_x == raw_v
+        assert(raw_f(7))
+    }
+    high_lookup(0)
+}
+"#;
+    let value = call(code, "run").expect("run");
+    assert!(matches!(value, Value::Int(97)));
+}
+
+#[test]
+fn test_interpreter_for_if_break_continue() {
+    let code = r#"
+safe fn count() -> u8 {
+    let high_buf = allocate_buffer(1)
+    for high_i in 0..10 {
+        if high_i == 1 {
+            buffer_write_u8(high_buf, 0, 9)
+        }
+        if high_i == 2 {
+            continue
+        }
+        if high_i == 2 {
+            buffer_write_u8(high_buf, 0, 5)
+        }
+        if high_i == 6 {
+            break
+        }
+        if high_i == 7 {
+            buffer_write_u8(high_buf, 0, 1)
+        }
+    }
+    buffer_read_u8(high_buf, 0)
+}
+"#;
+    let value = call(code, "count").expect("run");
+    assert!(matches!(value, Value::Int(9)));
+}
+
+#[test]
+fn test_interpreter_mutable_references_update_bindings() {
+    let code = r#"
+safe fn build() -> usize {
+    let high_text = string_new()
+    let high_tail = "abc"
+    string_push_str(&mut high_text, &high_tail)
+    string_push(&mut high_text, 100)
+    string_len(&high_text)
+}
+"#;
+    let value = call(code, "build").expect("run");
+    assert!(matches!(value, Value::Int(4)));
+}
+
+#[test]
+fn test_interpreter_calls_user_predicate_for_validate_raw_with() {
+    let code = r#"
+raw fn is_packet(raw_pkt: RawPtr, raw_len: usize) -> bool {
+    raw_read(raw_pkt, 0) == 1
+}
+
+safe fn check() -> i32 {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_res = validate_raw_with(raw_x, is_packet)
+        memory_error_code(validation_error(&validated_res))
+    }
+}
+"#;
+    let value = call(code, "check").expect("run");
+    assert!(matches!(value, Value::Int(8)));
+}
+
+#[test]
+fn test_interpreter_reports_runtime_panics_as_errors() {
+    let code = r#"
+safe fn overflow() {
+    let high_buf = allocate_buffer(2)
+    buffer_write_u8(high_buf, 5, 1)
+}
+"#;
+    let err = call(code, "overflow").expect_err("out of bounds write");
+    assert!(err.contains("Runtime panic"));
+    assert!(err.contains("out of bounds"));
+}
+
+#[test]
+fn test_interpreter_runs_assertions_with_locations() {
+    let code = r#"
+test fn checks() {
+    let high_buf = allocate_buffer(2)
+    assert_eq(string_len(&"ab"), 2)
+    expect_panic(buffer_write_u8(high_buf, 5, 1))
+}
+
+test fn fails() {
+    assert_eq(string_len(&"ab"), 3)
+}
+
+test fn never_panics() {
+    expect_panic(string_len(&"ab"))
+}
+"#;
+    let mut interpreter = load(code);
+    assert!(interpreter.call("checks", Vec::new()).is_ok());
+    assert_eq!(
+        interpreter.call("fails", Vec::new()).expect_err("fails"),
+        "assertion failed at line 9: left 2 != right 3"
+    );
+    assert_eq!(
+        interpreter
+            .call("never_panics", Vec::new())
+            .expect_err("never panics"),
+        "expected a panic at line 13"
+    );
+}
+
+#[test]
+fn test_repl_keeps_bindings_between_inputs() {
+    let mut repl = Repl::new();
+    assert_eq!(repl.eval("let high_buf = allocate_buffer(2)"), Ok(None));
+    assert_eq!(repl.eval("buffer_write_u8(high_buf, 1, 7)"), Ok(None));
+    assert_eq!(
+        repl.eval("buffer_read_u8(high_buf, 1)"),
+        Ok(Some("7".to_string()))
+    );
+
+    repl.eval("safe fn is_seven(high_v: u8) -> bool {\n    high_v == 7\n}")
+        .expect("definition");
+    assert_eq!(
+        repl.eval("is_seven(buffer_read_u8(high_buf, 1))"),
+        Ok(Some("true".to_string()))
+    );
+}
+
+#[test]
+fn test_repl_rejected_input_does_not_enter_history() {
+    let mut repl = Repl::new();
+    let err = repl.eval("missing(1)").expect_err("unknown function");
+    assert!(err.contains("Undefined function"));
+
+    let err = repl
+        .eval("let high_buf = allocate_buffer(1)\nbuffer_read_u8(high_buf, 3)")
+        .expect_err("runtime panic");
+    assert!(err.contains("out of bounds"));
+
+    assert_eq!(repl.eval("let high_buf = allocate_buffer(1)"), Ok(None));
+}
+
+#[test]
+fn test_repl_accepts_every_kind_of_item() {
+    let mut repl = Repl::new();
+    repl.eval("fn two() -> u8 {\n    2\n}").expect("plain fn");
+    repl.eval("enum Kind {\n    Ping,\n    Pong(u8),\n}")
+        .expect("enum");
+    repl.eval("test fn two_is_two() {\n    assert_eq(two(), 2)\n}")
+        .expect("test fn");
+    repl.eval("#[inline]\nfn three() -> u8 {\n    3\n}")
+        .expect("attributed fn");
+    assert_eq!(repl.eval("three()"), Ok(Some("3".to_string())));
+    assert_eq!(
+        repl.eval(
+            "match Kind::Pong(two()) {\n    Kind::Pong(high_n) => { high_n }\n    _ => { 0 }\n}"
+        ),
+        Ok(Some("2".to_string()))
+    );
+}
+
+#[test]
+fn test_repl_run_holds_attribute_lines_for_the_next_item() {
+    let input = b"#[inline]\nfn one() -> u8 {\n    1\n}\none()\n";
+    let mut output = Vec::new();
+    Repl::new().run(&input[..], &mut output).expect("repl io");
+    let output = String::from_utf8(output).expect("utf8");
+    assert!(output.contains("> 1\n"), "{output}");
+    assert!(!output.contains("error"), "{output}");
+}
+
+#[test]
+fn test_repl_run_reads_multi_line_input() {
+    let input = b"safe fn one() -> u8 {\n    1\n}\none()\n:quit\nignored()\n";
+    let mut output = Vec::new();
+    Repl::new().run(&input[..], &mut output).expect("repl io");
+    let output = String::from_utf8(output).expect("utf8");
+    assert!(output.contains("\n1\n") || output.contains("> 1\n"));
+    assert!(!output.contains("error"));
+}
@@ -0,0 +1,264 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::ast::Expression;
+use crate::core::memory::MemoryError;
+use crate::core::memory::raw::RawPtr;
+use crate::core::memory::safe::{HighPtr, HighView, ValidatedPtr};
+use crate::core::types;
+
+/// A closure and the bindings it captured: one captured by reference shares
+/// the slot of the binding, one moved into the closure holds a copy.
+#[derive(Debug)]
+pub struct ClosureValue {
+    pub params: Vec<String>,
+    pub body: Expression,
+    pub captured: HashMap<String, Slot>,
+}
+
+/// Storage of one binding. `&x` / `&mut x` evaluate to a shared handle on it.
+pub type Slot = Rc<RefCell<Value>>;
+
+/// Runtime value of the interpreter.
+///
+/// Integers of every width are kept as `i128` and range-checked when they
+/// are handed to the runtime; the type checker has already fixed their type.
+#[derive(Debug)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    Int(i128),
+    Str(types::String),
+    List(types::List),
+    StringSplit(types::StringSplit),
+    StringList(types::StringList),
+    RawPtr(RawPtr),
+    ValidatedPtr(ValidatedPtr),
+    HighPtr(HighPtr),
+    HighView(HighView),
+    MemoryError(MemoryError),
+    Option(Option<Box<Value>>),
+    Result(Result<Box<Value>, Box<Value>>),
+    Function(String),
+    Closure(Rc<ClosureValue>),
+    Ref(Slot),
+    Tuple(Vec<Value>),
+    /// A value of a user enum.
+    Variant {
+        enum_name: String,
+        variant: String,
+        payload: Vec<Value>,
+    },
+}
+
+impl Clone for Value {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unit => Self::Unit,
+            Self::Bool(v) => Self::Bool(*v),
+            Self::Int(v) => Self::Int(*v),
+            Self::Str(v) => Self::Str(v.clone()),
+            Self::List(v) => {
+                let mut copy = types::List::new();
+                types::list_push_bytes(&mut copy, v);
+                Self::List(copy)
+            }
+            Self::StringSplit(v) => Self::StringSplit(v.clone()),
+            Self::StringList(v) => Self::StringList(v.clone()),
+            Self::RawPtr(v) => Self::RawPtr(*v),
+            Self::ValidatedPtr(v) => Self::ValidatedPtr(*v),
+            Self::HighPtr(v) => Self::HighPtr(*v),
+            Self::HighView(v) => Self::HighView(*v),
+            Self::MemoryError(v) => Self::MemoryError(*v),
+            Self::Option(v) => Self::Option(v.clone()),
+            Self::Result(v) => Self::Result(v.clone()),
+            Self::Function(v) => Self::Function(v.clone()),
+            Self::Closure(v) => Self::Closure(Rc::clone(v)),
+            Self::Ref(v) => Self::Ref(Rc::clone(v)),
+            Self::Tuple(v) => Self::Tuple(v.clone()),
+            Self::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => Self::Variant {
+                enum_name: enum_name.clone(),
+                variant: variant.clone(),
+                payload: payload.clone(),
+            },
+        }
+    }
+}
+
+impl Value {
+    pub fn slot(self) -> Slot {
+        Rc::new(RefCell::new(self))
+    }
+
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Self::Unit)
+    }
+
+    /// Equality used by `==` / `!=`.
+    pub fn equals(&self, other: &Value) -> Option<bool> {
+        Some(match (self, other) {
+            (Self::Unit, Self::Unit) => true,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Str(a), Self::Str(b)) => types::string_eq(a, b),
+            (Self::RawPtr(a), Self::RawPtr(b)) => a == b,
+            (Self::ValidatedPtr(a), Self::ValidatedPtr(b)) => a == b,
+            (Self::HighPtr(a), Self::HighPtr(b)) => a == b,
+            (Self::HighView(a), Self::HighView(b)) => a == b,
+            (Self::MemoryError(a), Self::MemoryError(b)) => a == b,
+            (Self::Ref(a), Self::Ref(b)) => return a.borrow().equals(&b.borrow()),
+            (Self::Tuple(a), Self::Tuple(b)) => {
+                for (a, b) in a.iter().zip(b) {
+                    if !a.equals(b)? {
+                        return Some(false);
+                    }
+                }
+                a.len() == b.len()
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unit => write!(f, "()"),
+            Self::Bool(v) => write!(f, "{v}"),
+            Self::Int(v) => write!(f, "{v}"),
+            Self::Str(v) => write!(f, "{}", v.to_std_string()),
+            Self::List(v) => write!(f, "List{:?}", v.to_vec()),
+            Self::StringSplit(v) => {
+                if types::string_split_found(v) {
+                    write!(
+                        f,
+                        "StringSplit({:?}, {:?})",
+                        types::string_split_left(v).to_std_string(),
+                        types::string_split_right(v).to_std_string()
+                    )
+                } else {
+                    write!(f, "StringSplit(not found)")
+                }
+            }
+            Self::StringList(v) => {
+                let parts = (0..types::string_list_len(v))
+                    .filter_map(|i| match types::string_list_get(v, i) {
+                        types::Option::Some(s) => Some(format!("{:?}", s.to_std_string())),
+                        types::Option::None => None,
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "StringList[{}]", parts.join(", "))
+            }
+            Self::RawPtr(v) => write!(f, "RawPtr({:#x})", v.addr()),
+            Self::ValidatedPtr(v) => write!(f, "ValidatedPtr({:#x})", v.addr()),
+            Self::HighPtr(v) => write!(f, "HighPtr({:#x})", v.addr()),
+            Self::HighView(v) => write!(
+                f,
+                "HighView({:#x}, offset {}, len {})",
+                v.ptr().addr(),
+                v.offset(),
+                v.len()
+            ),
+            Self::MemoryError(v) => write!(f, "MemoryError({v})"),
+            Self::Option(Some(v)) => write!(f, "Some({v})"),
+            Self::Option(None) => write!(f, "None"),
+            Self::Result(Ok(v)) => write!(f, "Ok({v})"),
+            Self::Result(Err(v)) => write!(f, "Err({v})"),
+            Self::Function(name) => write!(f, "fn {name}"),
+            Self::Closure(closure) => write!(f, "closure |{}|", closure.params.join(", ")),
+            Self::Ref(slot) => write!(f, "{}", slot.borrow()),
+            Self::Tuple(v) => {
+                let parts = v.iter().map(Value::to_string).collect::<Vec<_>>();
+                write!(f, "({})", parts.join(", "))
+            }
+            Self::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => {
+                write!(f, "{enum_name}::{variant}")?;
+                if !payload.is_empty() {
+                    let parts = payload.iter().map(Value::to_string).collect::<Vec<_>>();
+                    write!(f, "({})", parts.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! value_from_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+value_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! value_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+value_from!(
+    bool => Bool,
+    types::String => Str,
+    types::List => List,
+    types::StringSplit => StringSplit,
+    types::StringList => StringList,
+    RawPtr => RawPtr,
+    ValidatedPtr => ValidatedPtr,
+    HighPtr => HighPtr,
+    HighView => HighView,
+    MemoryError => MemoryError,
+);
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Self::Unit
+    }
+}
+
+impl<T: Into<Value>> From<types::Option<T>> for Value {
+    fn from(value: types::Option<T>) -> Self {
+        match value {
+            types::Option::Some(v) => Self::Option(Some(Box::new(v.into()))),
+            types::Option::None => Self::Option(None),
+        }
+    }
+}
+
+impl<T: Into<Value>, E: Into<Value>> From<types::Result<T, E>> for Value {
+    fn from(value: types::Result<T, E>) -> Self {
+        match value {
+            types::Result::Ok(v) => Self::Result(Ok(Box::new(v.into()))),
+            types::Result::Err(e) => Self::Result(Err(Box::new(e.into()))),
+        }
+    }
+}
@@ -12,7 +12,7 @@ use nom::character::complete::multispace0;
 
 use super::parse::{keyword_or_identifier, literal, symbol};
 use super::position::{build_line_starts, line_col_from_offset};
-use super::token::{Span, Token};
+use super::token::{Comment, Span, Token};
 
 fn lex_token<'a>(
     original_input: &'a str,
@@ -50,17 +50,46 @@ fn lex_token<'a>(
 }
 
 pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    lex(input, None)
+}
+
+/// Like `tokenize`, also returning the comments in source order.
+pub fn tokenize_with_comments(input: &str) -> Result<(Vec<Token>, Vec<Comment>), String> {
+    let mut comments = Vec::new();
+    let tokens = lex(input, Some(&mut comments))?;
+    let line_starts = build_line_starts(input);
+    let comments = comments
+        .into_iter()
+        .map(|text| {
+            let start = input.offset(text);
+            let (line, column) = line_col_from_offset(input, &line_starts, start);
+            Comment {
+                text: text.to_string(),
+                span: Span {
+                    start,
+                    end: start + text.len(),
+                    line,
+                    column,
+                },
+            }
+        })
+        .collect();
+    Ok((tokens, comments))
+}
+
+fn lex<'a>(input: &'a str, mut comments: Option<&mut Vec<&'a str>>) -> Result<Vec<Token>, String> {
     let mut tokens = Vec::new();
     let mut current_input = input;
     let line_starts = build_line_starts(input);
     let mut parse_next = lex_token(input, &line_starts);
 
     loop {
-        current_input = skip_whitespace_and_comments(current_input).map_err(|near| {
-            let offset = input.offset(near);
-            let (line, column) = line_col_from_offset(input, &line_starts, offset);
-            format!("Lexing error at line {line}, column {column} near: '{near}'")
-        })?;
+        current_input = skip_whitespace_and_comments(current_input, comments.as_deref_mut())
+            .map_err(|near| {
+                let offset = input.offset(near);
+                let (line, column) = line_col_from_offset(input, &line_starts, offset);
+                format!("Lexing error at line {line}, column {column} near: '{near}'")
+            })?;
 
         if current_input.is_empty() {
             break;
@@ -89,22 +118,30 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
     Ok(tokens)
 }
 
-fn skip_whitespace_and_comments(mut input: &str) -> Result<&str, &str> {
+/// Skips whitespace and comments, pushing each skipped comment to
+/// `comments` when given.
+fn skip_whitespace_and_comments<'a>(
+    mut input: &'a str,
+    mut comments: Option<&mut Vec<&'a str>>,
+) -> Result<&'a str, &'a str> {
     loop {
         let before = input;
         input = input.trim_start();
 
         if let Some(rest) = input.strip_prefix("//") {
-            if let Some((idx, _)) = rest.char_indices().find(|(_, ch)| *ch == '\n') {
-                input = &rest[idx + 1..];
-            } else {
-                input = "";
+            let len = rest.find('\n').map_or(rest.len(), |idx| idx);
+            if let Some(comments) = comments.as_deref_mut() {
+                comments.push(input[..len + 2].trim_end());
             }
+            input = rest[len..].strip_prefix('\n').unwrap_or("");
             continue;
         }
 
         if let Some(rest) = input.strip_prefix("/*") {
             if let Some(idx) = rest.find("*/") {
+                if let Some(comments) = comments.as_deref_mut() {
+                    comments.push(&input[..idx + 4]);
+                }
                 input = &rest[idx + 2..];
                 continue;
             }
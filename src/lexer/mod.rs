@@ -14,5 +14,5 @@ mod token;
 #[cfg(test)]
 mod tests;
 
-pub use lexer::tokenize;
-pub use token::{Span, Token, TokenKind};
+pub use lexer::{tokenize, tokenize_with_comments};
+pub use token::{Comment, Span, Token, TokenKind};
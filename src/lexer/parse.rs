@@ -9,21 +9,33 @@ use nom::IResult;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while1};
 use nom::character::complete::{alpha1, alphanumeric1};
-use nom::combinator::{map, recognize};
+use nom::combinator::{map, opt, recognize};
 use nom::multi::{many0, many1};
 use nom::sequence::pair;
 
 use super::token::TokenKind;
 
 pub fn symbol(input: &str) -> IResult<&str, TokenKind> {
+    alt((operator, punctuation))(input)
+}
+
+/// Multi-character symbols, tried before their one-character prefixes.
+fn operator(input: &str) -> IResult<&str, TokenKind> {
     alt((
         map(tag("..="), |_| TokenKind::DotDotEqual),
         map(tag(".."), |_| TokenKind::DotDot),
         map(tag("->"), |_| TokenKind::Arrow),
+        map(tag("=>"), |_| TokenKind::FatArrow),
+        map(tag("::"), |_| TokenKind::ColonColon),
         map(tag("<="), |_| TokenKind::LessEqual),
         map(tag(">="), |_| TokenKind::GreaterEqual),
         map(tag("!="), |_| TokenKind::NotEqual),
         map(tag("=="), |_| TokenKind::EqualEqual),
+    ))(input)
+}
+
+fn punctuation(input: &str) -> IResult<&str, TokenKind> {
+    alt((
         map(tag("<"), |_| TokenKind::LessThan),
         map(tag(">"), |_| TokenKind::GreaterThan),
         map(tag("("), |_| TokenKind::OpenParen),
@@ -37,13 +49,24 @@ pub fn symbol(input: &str) -> IResult<&str, TokenKind> {
         map(tag(","), |_| TokenKind::Comma),
         map(tag("&"), |_| TokenKind::Ampersand),
         map(tag("*"), |_| TokenKind::Star),
+        map(tag("#"), |_| TokenKind::Hash),
+        map(tag("|"), |_| TokenKind::Pipe),
+        map(tag("."), |_| TokenKind::Dot),
     ))(input)
 }
 
-pub fn keyword_or_identifier(input: &str) -> IResult<&str, TokenKind> {
-    let (input, name) = recognize(pair(
+fn identifier_segment(input: &str) -> IResult<&str, &str> {
+    recognize(pair(
         alt((alpha1, tag("_"))),
         many0(alt((alphanumeric1, tag("_")))),
+    ))(input)
+}
+
+/// Identifiers may be `::`-separated paths (`mem::alloc`, `core::types::Option`).
+pub fn keyword_or_identifier(input: &str) -> IResult<&str, TokenKind> {
+    let (input, name) = recognize(pair(
+        identifier_segment,
+        many0(pair(tag("::"), identifier_segment)),
     ))(input)?;
 
     let kind = match name {
@@ -62,6 +85,9 @@ pub fn keyword_or_identifier(input: &str) -> IResult<&str, TokenKind> {
         "continue" => TokenKind::Continue,
         "true" => TokenKind::True,
         "false" => TokenKind::False,
+        "as" => TokenKind::As,
+        "enum" => TokenKind::Enum,
+        "match" => TokenKind::Match,
         _ => TokenKind::Identifier(name.to_string()),
     };
     Ok((input, kind))
@@ -70,8 +96,12 @@ pub fn keyword_or_identifier(input: &str) -> IResult<&str, TokenKind> {
 pub fn literal(input: &str) -> IResult<&str, TokenKind> {
     alt((
         map(parse_raw_string_literal, TokenKind::StringLiteral),
+        // There is no subtraction, so a leading `-` always negates.
         map(
-            recognize(many1(take_while1(|c: char| c.is_ascii_digit()))),
+            recognize(pair(
+                opt(tag("-")),
+                many1(take_while1(|c: char| c.is_ascii_digit())),
+            )),
             |s: &str| TokenKind::Integer(s.to_string()),
         ),
         map(parse_string_literal, TokenKind::StringLiteral),
@@ -5,7 +5,7 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-use super::{TokenKind, tokenize};
+use super::{TokenKind, tokenize, tokenize_with_comments};
 
 #[test]
 fn test_lexer_simple() {
@@ -59,6 +59,25 @@ fn test_block_comment_is_ignored() {
     );
 }
 
+#[test]
+fn test_tokenize_with_comments_keeps_comment_spans() {
+    let input = "// head\nlet high_x = 1 /* mid\nline */ // tail  \n";
+    let (tokens, comments) = tokenize_with_comments(input).expect("Lexing failed");
+    assert_eq!(tokens, tokenize(input).expect("Lexing failed"));
+
+    let texts: Vec<_> = comments.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts, ["// head", "/* mid\nline */", "// tail"]);
+    assert_eq!((comments[1].span.line, comments[1].span.column), (2, 16));
+    assert_eq!(comments[2].span.line, 3);
+}
+
+#[test]
+fn test_negative_integer_literal() {
+    let tokens = tokenize("f(-1) -> x").expect("Lexing failed");
+    assert_eq!(tokens[2].kind, TokenKind::Integer("-1".to_string()));
+    assert_eq!(tokens[4].kind, TokenKind::Arrow);
+}
+
 #[test]
 fn test_new_keywords() {
     let input = "const if else for in break continue true false";
@@ -101,3 +120,40 @@ fn test_raw_string_literal_with_extra_hashes() {
         TokenKind::StringLiteral(ref s) if s == "line1\n\"# inside\"\nline2"
     )));
 }
+
+#[test]
+fn test_lexer_cast_and_type_arguments() {
+    let tokens = tokenize("checked_cast::<u8>(high_x as u64)").expect("Lexing failed");
+    assert!(matches!(tokens[0].kind, TokenKind::Identifier(ref s) if s == "checked_cast"));
+    assert!(matches!(tokens[1].kind, TokenKind::ColonColon));
+    assert!(matches!(tokens[2].kind, TokenKind::LessThan));
+    assert!(matches!(tokens[7].kind, TokenKind::As));
+}
+
+#[test]
+fn test_lexer_tuple_field_and_ranges() {
+    let tokens = tokenize("high_p.0 0..2").expect("Lexing failed");
+    assert!(matches!(tokens[1].kind, TokenKind::Dot));
+    assert!(matches!(tokens[2].kind, TokenKind::Integer(ref s) if s == "0"));
+    assert!(matches!(tokens[4].kind, TokenKind::DotDot));
+}
+
+#[test]
+fn test_lexer_enum_and_match() {
+    let tokens = tokenize("enum match PacketKind::Data(high_n) => _").expect("Lexing failed");
+    assert!(matches!(tokens[0].kind, TokenKind::Enum));
+    assert!(matches!(tokens[1].kind, TokenKind::Match));
+    assert!(matches!(tokens[2].kind, TokenKind::Identifier(ref s) if s == "PacketKind::Data"));
+    assert!(matches!(tokens[6].kind, TokenKind::FatArrow));
+    assert!(matches!(tokens[7].kind, TokenKind::Identifier(ref s) if s == "_"));
+}
+
+#[test]
+fn test_lexer_path_identifiers() {
+    let tokens =
+        tokenize("let high_x: usize = mem::alloc(core::types::len)").expect("Lexing failed");
+    assert!(matches!(tokens[1].kind, TokenKind::Identifier(ref s) if s == "high_x"));
+    assert!(matches!(tokens[2].kind, TokenKind::Colon));
+    assert!(matches!(tokens[5].kind, TokenKind::Identifier(ref s) if s == "mem::alloc"));
+    assert!(matches!(tokens[7].kind, TokenKind::Identifier(ref s) if s == "core::types::len"));
+}
@@ -13,6 +13,14 @@ pub struct Span {
     pub column: usize,
 }
 
+/// A `//` or `/* */` comment, kept by `tokenize_with_comments`. `text`
+/// includes the delimiters; a line comment stops before its newline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub text: String,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenKind {
     // Keywords
@@ -31,6 +39,9 @@ pub enum TokenKind {
     Continue,
     True,
     False,
+    As,
+    Enum,
+    Match,
 
     // Symbols
     OpenParen,    // (
@@ -40,13 +51,18 @@ pub enum TokenKind {
     OpenBracket,  // [
     CloseBracket, // ]
     Colon,        // :
+    ColonColon,   // :: (before `<` in `f::<T>(...)`)
     Equal,        // =
     Arrow,        // ->
+    FatArrow,     // =>
     Comma,        // ,
     Ampersand,    // &
     Star,         // *
+    Hash,         // #
+    Pipe,         // | (closure parameters)
     DotDot,       // ..
     DotDotEqual,  // ..=
+    Dot,          // .
 
     // Comparison & Generics
     LessThan,     // <
@@ -9,6 +9,8 @@ pub mod ast;
 pub mod cli;
 pub mod codegen;
 pub mod core;
+pub mod formatter;
+pub mod interpreter;
 pub mod lexer;
 pub mod molding;
 pub mod parser;
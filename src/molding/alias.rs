@@ -8,122 +8,233 @@
 use crate::ast::*;
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::PathBuf;
 
 use super::Molder;
 
+/// A file of `alias name = target` lines. With a namespace, each alias is
+/// registered as `namespace::name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasFile {
+    pub path: PathBuf,
+    pub namespace: Option<String>,
+}
+
+impl AliasFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            namespace: None,
+        }
+    }
+
+    pub fn namespaced(path: impl Into<PathBuf>, namespace: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            namespace: Some(namespace.into()),
+        }
+    }
+}
+
 impl Molder {
     // Phase 1: Alias Expansion
     pub(super) fn phase1_expand_aliases(&mut self) -> Result<(), String> {
-        self.load_aliases_from_file()?;
-        let mut new_items = Vec::new();
+        self.load_alias_files()?;
 
-        for item in self.source.items.drain(..) {
+        let mut new_items = Vec::new();
+        for item in std::mem::take(&mut self.source.items) {
             if let Item::Alias(alias) = item {
-                Self::validate_alias(&alias)?;
-
-                if self
-                    .aliases
-                    .insert(alias.name.clone(), alias.target)
-                    .is_some()
-                {
-                    return Err(format!(
-                        "Phase 1 Error: Duplicate alias '{}' is not allowed",
-                        alias.name
-                    ));
-                }
+                let origin = match (&alias.file, alias.line) {
+                    (_, 0) => "source".to_string(),
+                    (Some(file), line) => format!("{file}:{line}"),
+                    (None, line) => format!("line {line}"),
+                };
+                self.register_alias(alias, origin)?;
             } else {
                 new_items.push(item);
             }
         }
-
         self.source.items = new_items;
 
-        let mut resolved_aliases = HashMap::new();
-        for name in self.aliases.keys() {
-            let resolved = Self::resolve_alias_target(name, &self.aliases)?;
-            resolved_aliases.insert(name.clone(), resolved);
+        // Sorted, so a cycle is always reported from the same alias.
+        let mut names: Vec<&String> = self.aliases.keys().collect();
+        names.sort();
+        let mut functions = HashMap::new();
+        for name in names {
+            let resolved = self.resolve_alias_target(name)?;
+            functions.insert(name.clone(), resolved);
+        }
+
+        // Expand every type alias once so cycles and bad arguments are
+        // reported even for aliases nothing uses.
+        let mut names: Vec<String> = self.type_aliases.keys().cloned().collect();
+        names.sort();
+        for name in names {
+            let alias = self.type_aliases[&name].clone();
+            self.expand_type_alias(&alias, &alias.params, &mut Vec::new())?;
         }
 
-        for item in &mut self.source.items {
-            if let Item::Function(func) = item {
-                Self::expand_aliases_in_block(&mut func.body, &resolved_aliases);
+        let mut items = std::mem::take(&mut self.source.items);
+        for item in &mut items {
+            match item {
+                Item::Function(func) => {
+                    *self.item.get_mut() = func.name.clone();
+                    for arg in &mut func.args {
+                        self.expand_binding_type(&arg.name, &mut arg.ty)?;
+                    }
+                    if let Some(ret) = &mut func.ret_type {
+                        self.expand_binding_type("->", ret)?;
+                    }
+                    self.expand_aliases_in_block(&mut func.body, &functions)?;
+                }
+                Item::Struct(def) => {
+                    for field in &mut def.fields {
+                        self.expand_type_aliases(&mut field.ty)?;
+                    }
+                }
+                Item::Enum(def) => {
+                    *self.item.get_mut() = def.name.clone();
+                    for variant in &mut def.variants {
+                        for ty in &mut variant.payload {
+                            self.expand_binding_type(&variant.name, ty)?;
+                        }
+                    }
+                }
+                Item::Alias(_) => {}
             }
         }
+        self.source.items = items;
         Ok(())
     }
 
-    fn load_aliases_from_file(&mut self) -> Result<(), String> {
-        let path = Path::new("rules.safe");
-        if !path.exists() {
-            return Ok(());
+    fn load_alias_files(&mut self) -> Result<(), String> {
+        for file in self.alias_files.clone() {
+            let contents = fs::read_to_string(&file.path).map_err(|e| {
+                format!(
+                    "Phase 1 Error: Failed to read alias file '{}': {e}",
+                    file.path.display()
+                )
+            })?;
+            self.load_aliases(&file, &contents)?;
         }
+        Ok(())
+    }
 
-        let contents = fs::read_to_string(path)
-            .map_err(|e| format!("Phase 1 Error: Failed to read rules.safe: {e}"))?;
-
+    fn load_aliases(&mut self, file: &AliasFile, contents: &str) -> Result<(), String> {
         for (line_no, raw_line) in contents.lines().enumerate() {
+            let origin = format!("{}:{}", file.path.display(), line_no + 1);
             let line = raw_line.trim();
             if line.is_empty() || line.starts_with("//") || line.starts_with('#') {
                 continue;
             }
 
-            let rest = line.strip_prefix("alias ").ok_or_else(|| {
-                format!(
-                    "Phase 1 Error: Invalid alias syntax at line {}",
-                    line_no + 1
-                )
-            })?;
-            let parts: Vec<&str> = rest.split('=').map(|s| s.trim()).collect();
-            if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() {
-                return Err(format!(
-                    "Phase 1 Error: Invalid alias syntax at line {}",
-                    line_no + 1
-                ));
+            let invalid = || format!("Phase 1 Error: Invalid alias syntax at {origin}");
+            let rest = line.strip_prefix("alias ").ok_or_else(invalid)?;
+            let (name, target) = rest.split_once('=').ok_or_else(invalid)?;
+            let (head, target) = (name.trim(), target.trim());
+            let (name, params) = Self::split_alias_head(head).ok_or_else(invalid)?;
+            if !Self::is_alias_name(name) || target.is_empty() || target.contains('=') {
+                return Err(invalid());
             }
 
+            let name = match &file.namespace {
+                Some(namespace) => format!("{namespace}::{name}"),
+                None => name.to_string(),
+            };
             let alias = Alias {
-                name: parts[0].to_string(),
-                target: parts[1].to_string(),
+                name,
+                target: target.to_string(),
+                params,
+                line: line_no + 1,
+                file: None,
+                attributes: Vec::new(),
             };
-            Self::validate_alias(&alias)?;
+            self.register_alias(alias, origin)?;
+        }
 
-            if self
-                .aliases
-                .insert(alias.name.clone(), alias.target)
-                .is_some()
-            {
-                return Err(format!(
-                    "Phase 1 Error: Duplicate alias '{}' is not allowed",
-                    alias.name
-                ));
-            }
+        Ok(())
+    }
+
+    /// Splits `Res<T, E>` into `Res` and its generic parameters.
+    fn split_alias_head(head: &str) -> Option<(&str, Vec<String>)> {
+        let Some((name, params)) = head.split_once('<') else {
+            return Some((head, Vec::new()));
+        };
+        let params = params.strip_suffix('>')?;
+        let params: Vec<String> = params.split(',').map(|p| p.trim().to_string()).collect();
+        params
+            .iter()
+            .all(|p| !p.contains("::") && Self::is_alias_name(p))
+            .then_some((name.trim(), params))
+    }
+
+    fn is_alias_name(name: &str) -> bool {
+        name.split("::").all(|segment| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+    }
+
+    fn register_alias(&mut self, alias: Alias, origin: String) -> Result<(), String> {
+        Self::validate_alias(&alias, &origin)?;
+
+        if let Some(first) = self.alias_origins.get(&alias.name) {
+            return Err(format!(
+                "Phase 1 Error: Duplicate alias '{}' at {origin} (first defined at {first})",
+                alias.name
+            ));
         }
+        self.alias_origins.insert(alias.name.clone(), origin);
 
+        if alias.is_type() {
+            self.type_aliases.insert(alias.name.clone(), alias);
+        } else {
+            self.aliases.insert(alias.name, alias.target);
+        }
         Ok(())
     }
 
-    fn validate_alias(alias: &Alias) -> Result<(), String> {
+    fn validate_alias(alias: &Alias, origin: &str) -> Result<(), String> {
         if alias.target.contains("unsafe") {
             return Err(format!(
-                "Phase 1 Error: Alias target '{}' cannot include 'unsafe'",
-                alias.target
+                "Phase 1 Error: Alias target '{}' cannot include 'unsafe' (alias '{}' at {origin})",
+                alias.target, alias.name
+            ));
+        }
+        if !alias.params.is_empty() && !alias.is_type() {
+            return Err(format!(
+                "Phase 1 Error: Only type aliases take generic parameters (alias '{}' at {origin})",
+                alias.name
+            ));
+        }
+        let mut seen = HashSet::new();
+        if let Some(param) = alias.params.iter().find(|p| !seen.insert(p.as_str())) {
+            return Err(format!(
+                "Phase 1 Error: Duplicate generic parameter '{param}' (alias '{}' at {origin})",
+                alias.name
             ));
         }
         Ok(())
     }
 
-    fn resolve_alias_target(
-        name: &str,
-        aliases: &HashMap<String, String>,
-    ) -> Result<String, String> {
+    fn origin_of(&self, name: &str) -> &str {
+        self.alias_origins
+            .get(name)
+            .map_or("source", String::as_str)
+    }
+
+    fn resolve_alias_target(&self, name: &str) -> Result<String, String> {
         let mut seen = HashSet::new();
         let mut current = name.to_string();
 
-        while let Some(next) = aliases.get(&current) {
+        while let Some(next) = self.aliases.get(&current) {
             if !seen.insert(current.clone()) {
                 return Err(format!(
-                    "Phase 1 Error: Alias cycle detected while resolving '{name}'"
+                    "Phase 1 Error: Alias cycle detected while resolving '{name}' (defined at {})",
+                    self.origin_of(name)
                 ));
             }
             current = next.clone();
@@ -132,50 +243,389 @@ impl Molder {
         Ok(current)
     }
 
-    fn expand_aliases_in_block(block: &mut Block, aliases: &HashMap<String, String>) {
+    /// Expands every type alias in `text`. Names in `params` are generic
+    /// parameters of the alias being expanded and are left alone.
+    fn expand_type_text(
+        &mut self,
+        text: &str,
+        params: &[String],
+        stack: &mut Vec<String>,
+    ) -> Result<String, String> {
+        let mut expanded = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find(Self::is_type_name_char) {
+            expanded.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find(|c| !Self::is_type_name_char(c))
+                .map_or(rest.len(), |end| start + end);
+            let name = &rest[start..end];
+            rest = &rest[end..];
+
+            let alias = match self.type_aliases.get(name) {
+                Some(alias) if !params.iter().any(|p| p == name) => alias.clone(),
+                _ => {
+                    expanded.push_str(name);
+                    continue;
+                }
+            };
+            let args = match rest.strip_prefix('<') {
+                Some(inner) => {
+                    let close = Self::closing_angle(inner).ok_or_else(|| {
+                        format!("Phase 1 Error: Unclosed '<' after type alias '{name}'")
+                    })?;
+                    rest = &inner[close + 1..];
+                    Self::split_type_args(&inner[..close])
+                        .into_iter()
+                        .map(|arg| self.expand_type_text(arg, params, stack))
+                        .collect::<Result<Vec<_>, _>>()?
+                }
+                None => Vec::new(),
+            };
+            expanded.push_str(&self.expand_type_alias(&alias, &args, stack)?);
+        }
+        expanded.push_str(rest);
+        Ok(expanded)
+    }
+
+    /// Expands one use of `alias` with already expanded generic arguments.
+    fn expand_type_alias(
+        &mut self,
+        alias: &Alias,
+        args: &[String],
+        stack: &mut Vec<String>,
+    ) -> Result<String, String> {
+        if stack.contains(&alias.name) {
+            return Err(format!(
+                "Phase 1 Error: Alias cycle detected while resolving '{}' (defined at {})",
+                stack[0],
+                self.origin_of(&stack[0])
+            ));
+        }
+        if args.len() != alias.params.len() {
+            return Err(format!(
+                "Phase 1 Error: Type alias '{}' expects {} generic argument(s), found {} (defined at {})",
+                alias.name,
+                alias.params.len(),
+                args.len(),
+                self.origin_of(&alias.name)
+            ));
+        }
+
+        stack.push(alias.name.clone());
+        let target = self.expand_type_text(&alias.target, &alias.params, stack)?;
+        stack.pop();
+
+        let expanded: String = Self::type_words(&target)
+            .into_iter()
+            .map(
+                |(word, is_name)| match alias.params.iter().position(|p| is_name && p == word) {
+                    Some(index) => args[index].as_str(),
+                    None => word,
+                },
+            )
+            .collect();
+        // Only the alias written at the use site is named in the note.
+        if stack.is_empty() && Self::is_unsafe_type(&Self::type_from_text(&expanded)) {
+            self.last_unsafe_alias
+                .get_or_insert_with(|| alias.name.clone());
+        }
+        Ok(expanded)
+    }
+
+    fn is_type_name_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_' || c == ':'
+    }
+
+    /// Index of the `>` closing a `<` that has already been consumed.
+    fn closing_angle(text: &str) -> Option<usize> {
+        let mut depth = 1;
+        for (idx, ch) in text.char_indices() {
+            match ch {
+                '<' => depth += 1,
+                '>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(idx);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Splits generic arguments at top-level commas.
+    fn split_type_args(text: &str) -> Vec<&str> {
+        let mut args = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (idx, ch) in text.char_indices() {
+            match ch {
+                '<' | '[' | '(' => depth += 1,
+                // The arrow of a function type.
+                '>' if text[..idx].ends_with('-') => {}
+                '>' | ']' | ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    args.push(text[start..idx].trim());
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        args.push(text[start..].trim());
+        args
+    }
+
+    /// Splits a type string into names (`core::types::Option`) and the
+    /// punctuation between them.
+    fn type_words(text: &str) -> Vec<(&str, bool)> {
+        let mut words = Vec::new();
+        let mut start = 0;
+        let mut in_name = false;
+        for (idx, ch) in text.char_indices() {
+            if Self::is_type_name_char(ch) != in_name {
+                if idx > start {
+                    words.push((&text[start..idx], in_name));
+                }
+                start = idx;
+                in_name = !in_name;
+            }
+        }
+        if start < text.len() {
+            words.push((&text[start..], in_name));
+        }
+        words
+    }
+
+    /// Rebuilds pointers, references, tuples and function types that an
+    /// alias target spelled out, so later phases see `*u8` as a pointer
+    /// rather than a path.
+    fn type_from_text(text: &str) -> Type {
+        if let Some(inner) = text.strip_prefix('*') {
+            let inner = inner.strip_prefix("mut ").unwrap_or(inner);
+            return Type::RawPtr(Box::new(Self::type_from_text(inner.trim())));
+        }
+        if let Some(inner) = text.strip_prefix('&')
+            && !inner.trim_start_matches("mut ").starts_with('[')
+        {
+            let (mutable, inner) = match inner.strip_prefix("mut ") {
+                Some(inner) => (true, inner),
+                None => (false, inner),
+            };
+            return Type::Ref {
+                mutable,
+                inner: Box::new(Self::type_from_text(inner.trim())),
+            };
+        }
+        if let Some(inner) = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            let elements = Self::split_type_args(inner);
+            if elements.len() >= 2 {
+                return Type::Tuple(elements.into_iter().map(Self::type_from_text).collect());
+            }
+        }
+        if let Some((raw, params, ret)) = Self::fn_type_parts(text) {
+            return Type::Fn {
+                raw,
+                closure: false,
+                params: params.into_iter().map(Self::type_from_text).collect(),
+                ret: Box::new(Self::type_from_text(ret)),
+            };
+        }
+        Type::Path(text.to_string())
+    }
+
+    /// `[raw ]fn(A, B) -> R` split into its parameter and return types.
+    fn fn_type_parts(text: &str) -> Option<(bool, Vec<&str>, &str)> {
+        let (raw, rest) = match text.strip_prefix("raw ") {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let rest = rest.strip_prefix("fn(")?;
+        let mut depth = 0;
+        let close = rest.char_indices().find_map(|(idx, ch)| {
+            match ch {
+                '<' | '[' | '(' => depth += 1,
+                ')' if depth == 0 => return Some(idx),
+                '>' if rest[..idx].ends_with('-') => {}
+                '>' | ']' | ')' => depth -= 1,
+                _ => {}
+            }
+            None
+        })?;
+        let ret = rest[close + 1..].trim().strip_prefix("->")?.trim();
+        let params = match rest[..close].trim() {
+            "" => Vec::new(),
+            params => Self::split_type_args(params),
+        };
+        Some((raw, params, ret))
+    }
+
+    /// Expands the type declared for `binding` in the current item and
+    /// records the unsafe alias it was written with, if any, for the
+    /// Rule 3 note.
+    fn expand_binding_type(&mut self, binding: &str, ty: &mut Type) -> Result<(), String> {
+        self.last_unsafe_alias = None;
+        self.expand_type_aliases(ty)?;
+        let site = self.alias_site(binding);
+        let alias = self.last_unsafe_alias.take();
+        self.unsafe_alias_uses
+            .get_mut()
+            .entry(site)
+            .or_default()
+            .push_back(alias);
+        Ok(())
+    }
+
+    pub(super) fn alias_site(&self, binding: &str) -> String {
+        format!("{}/{binding}", self.item.borrow())
+    }
+
+    fn expand_type_aliases(&mut self, ty: &mut Type) -> Result<(), String> {
+        match ty {
+            Type::Path(path) => {
+                let expanded = self.expand_type_text(path, &[], &mut Vec::new())?;
+                if expanded != *path {
+                    *ty = Self::type_from_text(&expanded);
+                }
+                Ok(())
+            }
+            Type::RawPtr(inner) => self.expand_type_aliases(inner),
+            Type::Ref { inner, .. } => self.expand_type_aliases(inner),
+            Type::Tuple(elements) => elements
+                .iter_mut()
+                .try_for_each(|element| self.expand_type_aliases(element)),
+            Type::Fn { params, ret, .. } => {
+                for param in params {
+                    self.expand_type_aliases(param)?;
+                }
+                self.expand_type_aliases(ret)
+            }
+        }
+    }
+
+    fn expand_aliases_in_block(
+        &mut self,
+        block: &mut Block,
+        aliases: &HashMap<String, String>,
+    ) -> Result<(), String> {
         for stmt in &mut block.statements {
             match stmt {
                 Statement::Let(parse_let) => {
-                    Self::expand_aliases_in_expr(&mut parse_let.value, aliases)
+                    if let Some(ty) = &mut parse_let.ty {
+                        self.expand_binding_type(&parse_let.name, ty)?;
+                    }
+                    self.expand_aliases_in_expr(&mut parse_let.value, aliases)?;
+                }
+                Statement::LetTuple(parse_let) => {
+                    if let Some(ty) = &mut parse_let.ty {
+                        self.expand_binding_type(&parse_let.names.join(","), ty)?;
+                    }
+                    self.expand_aliases_in_expr(&mut parse_let.value, aliases)?;
                 }
                 Statement::Const(parse_const) => {
-                    Self::expand_aliases_in_expr(&mut parse_const.value, aliases)
+                    if let Some(ty) = &mut parse_const.ty {
+                        self.expand_binding_type(&parse_const.name, ty)?;
+                    }
+                    self.expand_aliases_in_expr(&mut parse_const.value, aliases)?;
                 }
                 Statement::If(stmt) => {
-                    Self::expand_aliases_in_expr(&mut stmt.condition, aliases);
-                    Self::expand_aliases_in_block(&mut stmt.then_block, aliases);
+                    if let Some(pattern) = &mut stmt.pattern {
+                        self.expand_aliases_in_pattern(pattern)?;
+                    }
+                    self.expand_aliases_in_expr(&mut stmt.condition, aliases)?;
+                    self.expand_aliases_in_block(&mut stmt.then_block, aliases)?;
                     if let Some(else_block) = &mut stmt.else_block {
-                        Self::expand_aliases_in_block(else_block, aliases);
+                        self.expand_aliases_in_block(else_block, aliases)?;
                     }
                 }
                 Statement::For(stmt) => {
-                    Self::expand_aliases_in_expr(&mut stmt.start, aliases);
-                    Self::expand_aliases_in_expr(&mut stmt.end, aliases);
-                    Self::expand_aliases_in_block(&mut stmt.body, aliases);
+                    self.expand_aliases_in_expr(&mut stmt.start, aliases)?;
+                    self.expand_aliases_in_expr(&mut stmt.end, aliases)?;
+                    self.expand_aliases_in_block(&mut stmt.body, aliases)?;
                 }
                 Statement::Break | Statement::Continue => {}
-                Statement::Expr(expr) => Self::expand_aliases_in_expr(expr, aliases),
+                Statement::Expr(expr) => self.expand_aliases_in_expr(expr, aliases)?,
             }
         }
+        Ok(())
     }
 
-    fn expand_aliases_in_expr(expr: &mut Expression, aliases: &HashMap<String, String>) {
+    fn expand_aliases_in_expr(
+        &mut self,
+        expr: &mut Expression,
+        aliases: &HashMap<String, String>,
+    ) -> Result<(), String> {
         match expr {
             Expression::Call(call) => {
                 if let Some(target) = aliases.get(&call.func_name) {
                     call.func_name = target.clone();
                 }
+                for ty in &mut call.type_args {
+                    self.expand_type_aliases(ty)?;
+                }
                 for arg in &mut call.args {
-                    Self::expand_aliases_in_expr(arg, aliases);
+                    self.expand_aliases_in_expr(arg, aliases)?;
+                }
+            }
+            Expression::Cast { expr, ty } => {
+                self.expand_type_aliases(ty)?;
+                self.expand_aliases_in_expr(expr, aliases)?;
+            }
+            Expression::Ref { expr, .. } | Expression::Field { expr, .. } => {
+                self.expand_aliases_in_expr(expr, aliases)?
+            }
+            Expression::Tuple(elements) => {
+                for element in elements {
+                    self.expand_aliases_in_expr(element, aliases)?;
+                }
+            }
+            Expression::Variant {
+                enum_name, args, ..
+            } => {
+                self.expand_enum_name(enum_name)?;
+                for arg in args {
+                    self.expand_aliases_in_expr(arg, aliases)?;
+                }
+            }
+            Expression::Match { value, arms } => {
+                self.expand_aliases_in_expr(value, aliases)?;
+                for arm in arms {
+                    self.expand_aliases_in_pattern(&mut arm.pattern)?;
+                    self.expand_aliases_in_block(&mut arm.body, aliases)?;
                 }
             }
-            Expression::Ref { expr, .. } => Self::expand_aliases_in_expr(expr, aliases),
             Expression::Binary { left, right, .. } => {
-                Self::expand_aliases_in_expr(left, aliases);
-                Self::expand_aliases_in_expr(right, aliases);
+                self.expand_aliases_in_expr(left, aliases)?;
+                self.expand_aliases_in_expr(right, aliases)?;
+            }
+            Expression::Block(block) => self.expand_aliases_in_block(block, aliases)?,
+            Expression::Closure(closure) => {
+                for param in &mut closure.params {
+                    if let Some(ty) = &mut param.ty {
+                        self.expand_binding_type(&param.name, ty)?;
+                    }
+                }
+                self.expand_aliases_in_expr(&mut closure.body, aliases)?;
             }
-            Expression::Block(block) => Self::expand_aliases_in_block(block, aliases),
             _ => {}
         }
+        Ok(())
+    }
+
+    fn expand_aliases_in_pattern(&mut self, pattern: &mut Pattern) -> Result<(), String> {
+        match pattern {
+            Pattern::Variant { enum_name, .. } => self.expand_enum_name(enum_name),
+            Pattern::Wildcard => Ok(()),
+        }
+    }
+
+    /// `K::Ping` with `alias K = PacketKind` names `PacketKind::Ping`.
+    fn expand_enum_name(&mut self, name: &mut String) -> Result<(), String> {
+        let mut ty = Type::Path(name.clone());
+        self.expand_type_aliases(&mut ty)?;
+        if let Type::Path(expanded) = ty {
+            *name = expanded;
+        }
+        Ok(())
     }
 }
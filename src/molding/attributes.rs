@@ -0,0 +1,228 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Registry of known attributes, checked before alias expansion.
+
+use crate::ast::*;
+
+use super::{Molder, Rule};
+
+/// What an attribute is written on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Function,
+    Struct,
+    Enum,
+    Alias,
+    Statement,
+}
+
+impl Target {
+    fn describe(self) -> &'static str {
+        match self {
+            Target::Function => "a function",
+            Target::Struct => "a struct",
+            Target::Enum => "an enum",
+            Target::Alias => "an alias",
+            Target::Statement => "a statement",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Args {
+    None,
+    /// One or more rule names: `rule4` and `rule5`, or also `rule3` and
+    /// `rule6` with `allow_safety_rules`.
+    Rules,
+    /// Exactly one argument.
+    One,
+}
+
+struct KnownAttribute {
+    name: &'static str,
+    targets: &'static [Target],
+    args: Args,
+}
+
+const KNOWN_ATTRIBUTES: &[KnownAttribute] = &[
+    // Suppresses the named rules in the function or statement, nested
+    // blocks included, or in the payload types of an enum.
+    KnownAttribute {
+        name: "allow",
+        targets: &[Target::Function, Target::Enum, Target::Statement],
+        args: Args::Rules,
+    },
+    // Passed through to the generated Rust.
+    KnownAttribute {
+        name: "inline",
+        targets: &[Target::Function],
+        args: Args::None,
+    },
+    // Same as `test fn`.
+    KnownAttribute {
+        name: "test",
+        targets: &[Target::Function],
+        args: Args::None,
+    },
+    // Emitted as a `///` comment in the generated Rust.
+    KnownAttribute {
+        name: "doc",
+        targets: &[
+            Target::Function,
+            Target::Struct,
+            Target::Enum,
+            Target::Alias,
+        ],
+        args: Args::One,
+    },
+];
+
+impl Molder {
+    // Phase 0: Attribute Validation
+    pub(super) fn phase0_check_attributes(&mut self) -> Result<(), String> {
+        let safety = self.rules.allow_safety_rules;
+        for item in &mut self.source.items {
+            match item {
+                Item::Function(func) => {
+                    Self::check_attributes(&func.attributes, Target::Function, safety)?;
+                    Self::check_block_attributes(&func.body, safety)?;
+                    if func.attributes.iter().any(|a| a.name == "test") {
+                        func.is_test = true;
+                    }
+                }
+                Item::Struct(s) => Self::check_attributes(&s.attributes, Target::Struct, safety)?,
+                Item::Enum(e) => Self::check_attributes(&e.attributes, Target::Enum, safety)?,
+                Item::Alias(alias) => {
+                    Self::check_attributes(&alias.attributes, Target::Alias, safety)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `safety` is `allow_safety_rules`, passed down to `check_attributes`.
+    fn check_block_attributes(block: &Block, safety: bool) -> Result<(), String> {
+        for stmt in &block.statements {
+            Self::check_attributes(stmt.attributes(), Target::Statement, safety)?;
+            match stmt {
+                Statement::If(stmt) => {
+                    Self::check_expr_attributes(&stmt.condition, safety)?;
+                    Self::check_block_attributes(&stmt.then_block, safety)?;
+                    if let Some(else_block) = &stmt.else_block {
+                        Self::check_block_attributes(else_block, safety)?;
+                    }
+                }
+                Statement::For(stmt) => Self::check_block_attributes(&stmt.body, safety)?,
+                Statement::Let(LetStatement { value, .. })
+                | Statement::LetTuple(LetTupleStatement { value, .. })
+                | Statement::Const(ConstStatement { value, .. })
+                | Statement::Expr(value) => Self::check_expr_attributes(value, safety)?,
+                Statement::Break | Statement::Continue => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn check_expr_attributes(expr: &Expression, safety: bool) -> Result<(), String> {
+        match expr {
+            Expression::Block(block) => Self::check_block_attributes(block, safety),
+            Expression::Call(call) => call
+                .args
+                .iter()
+                .try_for_each(|arg| Self::check_expr_attributes(arg, safety)),
+            Expression::Binary { left, right, .. } => {
+                Self::check_expr_attributes(left, safety)?;
+                Self::check_expr_attributes(right, safety)
+            }
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => Self::check_expr_attributes(expr, safety),
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => elements
+                .iter()
+                .try_for_each(|element| Self::check_expr_attributes(element, safety)),
+            Expression::Match { value, arms } => {
+                Self::check_expr_attributes(value, safety)?;
+                arms.iter()
+                    .try_for_each(|arm| Self::check_block_attributes(&arm.body, safety))
+            }
+            Expression::Closure(closure) => Self::check_expr_attributes(&closure.body, safety),
+            Expression::Literal(_) | Expression::Variable(_) => Ok(()),
+        }
+    }
+
+    fn check_attributes(
+        attributes: &[Attribute],
+        target: Target,
+        safety: bool,
+    ) -> Result<(), String> {
+        for attribute in attributes {
+            let line = attribute.line;
+            let Some(known) = KNOWN_ATTRIBUTES.iter().find(|k| k.name == attribute.name) else {
+                return Err(format!(
+                    "Attribute Error: Unknown attribute '#[{}]' at line {line}",
+                    attribute.name
+                ));
+            };
+            if !known.targets.contains(&target) {
+                return Err(format!(
+                    "Attribute Error: '#[{}]' cannot be used on {} (line {line})",
+                    known.name,
+                    target.describe()
+                ));
+            }
+            let args = &attribute.args;
+            match known.args {
+                Args::None if !args.is_empty() => {
+                    return Err(format!(
+                        "Attribute Error: '#[{}]' takes no arguments (line {line})",
+                        known.name
+                    ));
+                }
+                Args::One if args.len() != 1 => {
+                    return Err(format!(
+                        "Attribute Error: '#[{}]' takes exactly one argument (line {line})",
+                        known.name
+                    ));
+                }
+                Args::Rules => {
+                    if args.is_empty() {
+                        return Err(format!(
+                            "Attribute Error: '#[{}]' needs at least one rule (line {line})",
+                            known.name
+                        ));
+                    }
+                    for arg in args {
+                        let Some(rule) = Rule::from_name(arg) else {
+                            return Err(format!(
+                                "Attribute Error: Unknown rule '{arg}' in '#[{}]' at line {line}; expected rule3, rule4, rule5 or rule6",
+                                known.name
+                            ));
+                        };
+                        if rule.is_safety_rule() && !safety {
+                            return Err(format!(
+                                "Attribute Error: '#[{}({arg})]' at line {line} needs `allow_safety_rules = true` in the [rules] table of Safe.toml",
+                                known.name
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rules named by the `#[allow(...)]` attributes in `attributes`.
+pub(super) fn allowed_rules(attributes: &[Attribute]) -> impl Iterator<Item = Rule> + '_ {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.name == "allow")
+        .flat_map(|attribute| attribute.args.iter())
+        .filter_map(|arg| Rule::from_name(arg))
+}
@@ -0,0 +1,86 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use serde::Deserialize;
+
+/// Molding rules whose severity can be configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Unsafe types outside `unsafe`.
+    Rule3,
+    /// Bindings that shadow a name of an enclosing block (a lint, off by
+    /// default).
+    Rule4,
+    /// `raw_` / `validated_` / `high_` prefixes.
+    Rule5,
+    /// Raw -> validated -> high promotion path.
+    Rule6,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    #[default]
+    Error,
+    Warn,
+    Off,
+}
+
+/// Severity of each rule, as read from the `[rules]` table of `Safe.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleConfig {
+    pub rule3: RuleLevel,
+    pub rule4: RuleLevel,
+    pub rule5: RuleLevel,
+    pub rule6: RuleLevel,
+    /// Lets `#[allow(...)]` name rule3 and rule6, the rules that keep raw
+    /// values inside `unsafe`.
+    pub allow_safety_rules: bool,
+}
+
+impl Default for RuleConfig {
+    fn default() -> Self {
+        Self {
+            rule3: RuleLevel::Error,
+            rule4: RuleLevel::Off,
+            rule5: RuleLevel::Error,
+            rule6: RuleLevel::Error,
+            allow_safety_rules: false,
+        }
+    }
+}
+
+impl Rule {
+    /// `rule3` ... `rule6`, as in `Safe.toml` and `#[allow(...)]`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "rule3" => Some(Rule::Rule3),
+            "rule4" => Some(Rule::Rule4),
+            "rule5" => Some(Rule::Rule5),
+            "rule6" => Some(Rule::Rule6),
+            _ => None,
+        }
+    }
+
+    /// Rules 3 and 6, which `#[allow(...)]` only names with
+    /// `allow_safety_rules`.
+    pub fn is_safety_rule(self) -> bool {
+        matches!(self, Rule::Rule3 | Rule::Rule6)
+    }
+}
+
+impl RuleConfig {
+    pub fn level(&self, rule: Rule) -> RuleLevel {
+        match rule {
+            Rule::Rule3 => self.rule3,
+            Rule::Rule4 => self.rule4,
+            Rule::Rule5 => self.rule5,
+            Rule::Rule6 => self.rule6,
+        }
+    }
+}
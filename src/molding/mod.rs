@@ -6,9 +6,13 @@
 // See: https://opensource.org/licenses/MIT
 
 mod alias;
+mod attributes;
+mod config;
 mod molder;
 mod normalize;
 mod rules;
 mod unsafe_wrap;
 
+pub use alias::AliasFile;
+pub use config::{Rule, RuleConfig, RuleLevel};
 pub use molder::Molder;
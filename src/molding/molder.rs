@@ -5,13 +5,32 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+use super::{AliasFile, Rule, RuleConfig, RuleLevel};
 use crate::ast::*;
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Molder {
     pub(super) source: SourceFile,
+    /// Function aliases.
     pub(super) aliases: HashMap<String, String>,
+    pub(super) type_aliases: HashMap<String, Alias>,
+    /// `path:line` or `line N` of each alias, for diagnostics.
+    pub(super) alias_origins: HashMap<String, String>,
+    /// For each `item/binding`, in source order, the type alias its type
+    /// was written with when that alias expands to an unsafe type.
+    pub(super) unsafe_alias_uses: RefCell<HashMap<String, VecDeque<Option<String>>>>,
+    /// The first unsafe type alias expanded at the current use site.
+    pub(super) last_unsafe_alias: Option<String>,
+    /// The function or enum whose types are being expanded or verified.
+    pub(super) item: RefCell<String>,
     pub(super) raw_functions: HashSet<String>,
+    pub(super) alias_files: Vec<AliasFile>,
+    pub(super) rules: RuleConfig,
+    warnings: RefCell<Vec<String>>,
+    /// Rules suppressed by `#[allow(...)]` on the enclosing function and
+    /// statements, innermost last.
+    allowed: RefCell<Vec<Rule>>,
 }
 
 impl Molder {
@@ -19,16 +38,44 @@ impl Molder {
         Self {
             source,
             aliases: HashMap::new(),
+            type_aliases: HashMap::new(),
+            alias_origins: HashMap::new(),
+            unsafe_alias_uses: RefCell::new(HashMap::new()),
+            last_unsafe_alias: None,
+            item: RefCell::new(String::new()),
             raw_functions: HashSet::new(),
+            alias_files: Vec::new(),
+            rules: RuleConfig::default(),
+            warnings: RefCell::new(Vec::new()),
+            allowed: RefCell::new(Vec::new()),
         }
     }
 
+    /// Alias files loaded before the source's own aliases. Each must exist.
+    pub fn with_alias_files(mut self, files: Vec<AliasFile>) -> Self {
+        self.alias_files = files;
+        self
+    }
+
+    pub fn with_rules(mut self, rules: RuleConfig) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// `raw fn`s declared outside the source, such as in the other files of
+    /// a program molded one file at a time.
+    pub fn with_raw_functions(mut self, names: impl IntoIterator<Item = String>) -> Self {
+        self.raw_functions.extend(names);
+        self
+    }
+
     pub fn mold(&mut self) -> Result<(), String> {
+        self.phase0_check_attributes()?;
         for item in &self.source.items {
-            if let Item::Function(func) = item {
-                if let SafetyLevel::Raw = func.safety {
-                    self.raw_functions.insert(func.name.clone());
-                }
+            if let Item::Function(func) = item
+                && let SafetyLevel::Raw = func.safety
+            {
+                self.raw_functions.insert(func.name.clone());
             }
         }
 
@@ -42,4 +89,41 @@ impl Molder {
     pub fn get_output(&self) -> &SourceFile {
         &self.source
     }
+
+    /// Violations of rules configured as `warn`.
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Reports a rule violation at its configured level: `Err` for `error`,
+    /// a recorded warning for `warn`, nothing for `off`.
+    pub(super) fn violation(&self, rule: Rule, message: String) -> Result<(), String> {
+        if self.allowed.borrow().contains(&rule) {
+            return Ok(());
+        }
+        match self.rules.level(rule) {
+            RuleLevel::Error => Err(message),
+            RuleLevel::Warn => {
+                self.warnings.borrow_mut().push(message);
+                Ok(())
+            }
+            RuleLevel::Off => Ok(()),
+        }
+    }
+
+    /// Runs `verify` with the rules of `#[allow(...)]` in `attributes`
+    /// suppressed.
+    pub(super) fn allowing(
+        &self,
+        attributes: &[Attribute],
+        verify: impl FnOnce() -> Result<(), String>,
+    ) -> Result<(), String> {
+        let depth = self.allowed.borrow().len();
+        self.allowed
+            .borrow_mut()
+            .extend(super::attributes::allowed_rules(attributes));
+        let result = verify();
+        self.allowed.borrow_mut().truncate(depth);
+        result
+    }
 }
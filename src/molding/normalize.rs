@@ -24,6 +24,11 @@ impl Molder {
                 Self::normalize_block_types(&mut func.body);
                 Self::normalize_block_calls(&mut func.body);
             }
+            if let Item::Enum(def) = item {
+                for ty in def.variants.iter_mut().flat_map(|v| &mut v.payload) {
+                    Self::normalize_type(ty);
+                }
+            }
         }
         Ok(())
     }
@@ -37,6 +42,12 @@ impl Molder {
                     }
                     Self::normalize_expr_types(&mut l.value);
                 }
+                Statement::LetTuple(l) => {
+                    if let Some(ty) = &mut l.ty {
+                        Self::normalize_type(ty);
+                    }
+                    Self::normalize_expr_types(&mut l.value);
+                }
                 Statement::Const(c) => {
                     if let Some(ty) = &mut c.ty {
                         Self::normalize_type(ty);
@@ -65,6 +76,9 @@ impl Molder {
         match expr {
             Expression::Block(block) => Self::normalize_block_types(block),
             Expression::Call(call) => {
+                for ty in &mut call.type_args {
+                    Self::normalize_type(ty);
+                }
                 for arg in &mut call.args {
                     Self::normalize_expr_types(arg);
                 }
@@ -73,24 +87,54 @@ impl Molder {
                 Self::normalize_expr_types(left);
                 Self::normalize_expr_types(right);
             }
-            Expression::Ref { expr, .. } => Self::normalize_expr_types(expr),
+            Expression::Ref { expr, .. } | Expression::Field { expr, .. } => {
+                Self::normalize_expr_types(expr)
+            }
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => {
+                elements.iter_mut().for_each(Self::normalize_expr_types)
+            }
+            Expression::Match { value, arms } => {
+                Self::normalize_expr_types(value);
+                for arm in arms {
+                    Self::normalize_block_types(&mut arm.body);
+                }
+            }
+            Expression::Cast { expr, ty } => {
+                Self::normalize_expr_types(expr);
+                Self::normalize_type(ty);
+            }
+            Expression::Closure(closure) => {
+                for ty in closure.params.iter_mut().filter_map(|p| p.ty.as_mut()) {
+                    Self::normalize_type(ty);
+                }
+                Self::normalize_expr_types(&mut closure.body);
+            }
             _ => {}
         }
     }
 
-    fn normalize_type(ty: &mut Type) {
+    pub(super) fn normalize_type(ty: &mut Type) {
         match ty {
             Type::Path(name) => {
                 if name == "HighPtr" {
                     *name = "core::memory::safe::HighPtr".to_string();
+                } else if name == "HighView" {
+                    *name = "core::memory::safe::HighView".to_string();
                 } else if name == "ValidatedPtr" {
                     *name = "core::memory::safe::ValidatedPtr".to_string();
                 } else if name == "RawPtr" {
                     *name = "core::memory::raw::RawPtr".to_string();
+                } else if name == "MemoryError" {
+                    *name = "core::memory::MemoryError".to_string();
                 }
             }
             Type::RawPtr(inner) => Self::normalize_type(inner),
             Type::Ref { inner, .. } => Self::normalize_type(inner),
+            Type::Tuple(elements) => elements.iter_mut().for_each(Self::normalize_type),
+            Type::Fn { params, ret, .. } => {
+                params.iter_mut().for_each(Self::normalize_type);
+                Self::normalize_type(ret);
+            }
         }
     }
 
@@ -98,6 +142,7 @@ impl Molder {
         for stmt in &mut block.statements {
             match stmt {
                 Statement::Let(l) => Self::normalize_expr_calls(&mut l.value),
+                Statement::LetTuple(l) => Self::normalize_expr_calls(&mut l.value),
                 Statement::Const(c) => Self::normalize_expr_calls(&mut c.value),
                 Statement::If(stmt) => {
                     Self::normalize_expr_calls(&mut stmt.condition);
@@ -129,8 +174,20 @@ impl Molder {
                 Self::normalize_expr_calls(left);
                 Self::normalize_expr_calls(right);
             }
-            Expression::Ref { expr, .. } => Self::normalize_expr_calls(expr),
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => Self::normalize_expr_calls(expr),
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => {
+                elements.iter_mut().for_each(Self::normalize_expr_calls)
+            }
+            Expression::Match { value, arms } => {
+                Self::normalize_expr_calls(value);
+                for arm in arms {
+                    Self::normalize_block_calls(&mut arm.body);
+                }
+            }
             Expression::Block(block) => Self::normalize_block_calls(block),
+            Expression::Closure(closure) => Self::normalize_expr_calls(&mut closure.body),
             _ => {}
         }
     }
@@ -6,20 +6,36 @@
 // See: https://opensource.org/licenses/MIT
 
 use crate::ast::*;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 
-use super::Molder;
+use super::{Molder, Rule};
 
 impl Molder {
     // Phase 4: Rule Verification
     pub(super) fn phase4_verify_rules(&self) -> Result<(), String> {
-        let mut global_vars = HashSet::new();
-
         for item in &self.source.items {
             if let Item::Function(func) = item {
+                *self.item.borrow_mut() = func.name.clone();
                 let func_unsafe = matches!(func.safety, SafetyLevel::Raw);
-                self.verify_function_signature(func, &mut global_vars, func_unsafe)?;
-                self.verify_rules_in_block(&func.body, &mut global_vars, func_unsafe)?;
+                // Parameters and the top level of the body share one scope.
+                let mut scopes = vec![HashSet::new()];
+                self.allowing(&func.attributes, || {
+                    self.verify_function_signature(func, &mut scopes, func_unsafe)?;
+                    self.verify_rules_in_statements(&func.body.statements, &mut scopes, func_unsafe)
+                })?;
+            }
+            if let Item::Enum(def) = item {
+                // Payloads are checked like parameter types: an enum must not
+                // smuggle a raw pointer out of unsafe code.
+                *self.item.borrow_mut() = def.name.clone();
+                self.allowing(&def.attributes, || {
+                    def.variants.iter().try_for_each(|variant| {
+                        variant
+                            .payload
+                            .iter()
+                            .try_for_each(|ty| self.verify_type_safety(&variant.name, ty, false))
+                    })
+                })?;
             }
         }
 
@@ -29,22 +45,23 @@ impl Molder {
     fn verify_function_signature(
         &self,
         func: &Function,
-        global_vars: &mut HashSet<String>,
+        scopes: &mut [HashSet<String>],
         in_unsafe: bool,
     ) -> Result<(), String> {
         for arg in &func.args {
-            if !global_vars.insert(arg.name.clone()) {
+            if scopes.iter().any(|scope| scope.contains(&arg.name)) {
                 return Err(format!(
-                    "Rule 4 Violation: Variable '{}' already defined.",
-                    arg.name
+                    "Phase 4 Error: Parameter '{}' is declared more than once in '{}'.",
+                    arg.name, func.name
                 ));
             }
+            self.declare(&arg.name, scopes)?;
             self.verify_var_prefix(&arg.name, in_unsafe)?;
-            self.verify_type_safety(&arg.ty, in_unsafe)?;
+            self.verify_type_safety(&arg.name, &arg.ty, in_unsafe)?;
         }
 
         if let Some(ret) = &func.ret_type {
-            self.verify_type_safety(ret, in_unsafe)?;
+            self.verify_type_safety("->", ret, in_unsafe)?;
         }
         Ok(())
     }
@@ -52,76 +69,144 @@ impl Molder {
     fn verify_rules_in_block(
         &self,
         block: &Block,
-        global_vars: &mut HashSet<String>,
+        scopes: &mut Vec<HashSet<String>>,
         in_unsafe: bool,
     ) -> Result<(), String> {
-        let current_unsafe = in_unsafe || block.unsafe_block;
+        scopes.push(HashSet::new());
+        let result = self.verify_rules_in_statements(
+            &block.statements,
+            scopes,
+            in_unsafe || block.unsafe_block,
+        );
+        scopes.pop();
+        result
+    }
 
-        for stmt in &block.statements {
-            self.verify_rules_in_stmt(stmt, global_vars, current_unsafe)?;
+    fn verify_rules_in_statements(
+        &self,
+        statements: &[Statement],
+        scopes: &mut Vec<HashSet<String>>,
+        in_unsafe: bool,
+    ) -> Result<(), String> {
+        for stmt in statements {
+            self.verify_rules_in_stmt(stmt, scopes, in_unsafe)?;
         }
         Ok(())
     }
 
+    /// Adds `name` to the innermost scope. Re-binding a name of the same
+    /// scope is plain shadowing; hiding one from an enclosing block is what
+    /// Rule 4 reports.
+    fn declare(&self, name: &str, scopes: &mut [HashSet<String>]) -> Result<(), String> {
+        let (current, enclosing) = scopes
+            .split_last_mut()
+            .expect("verification always has a scope");
+        if enclosing.iter().any(|scope| scope.contains(name)) {
+            self.violation(
+                Rule::Rule4,
+                format!(
+                    "Rule 4 Violation: Variable '{name}' shadows a binding of an enclosing block."
+                ),
+            )?;
+        }
+        current.insert(name.to_string());
+        Ok(())
+    }
+
     fn verify_rules_in_stmt(
         &self,
         stmt: &Statement,
-        global_vars: &mut HashSet<String>,
+        scopes: &mut Vec<HashSet<String>>,
+        in_unsafe: bool,
+    ) -> Result<(), String> {
+        self.allowing(stmt.attributes(), || {
+            self.verify_rules_in_stmt_kind(stmt, scopes, in_unsafe)
+        })
+    }
+
+    fn verify_rules_in_stmt_kind(
+        &self,
+        stmt: &Statement,
+        scopes: &mut Vec<HashSet<String>>,
         in_unsafe: bool,
     ) -> Result<(), String> {
         match stmt {
             Statement::Let(l) => {
-                if !global_vars.insert(l.name.clone()) {
-                    return Err(format!(
-                        "Rule 4 Violation: Variable '{}' already defined.",
-                        l.name
-                    ));
-                }
+                // The value is checked first: it still sees the old binding.
+                self.verify_rules_in_expr(&l.value, scopes, in_unsafe)?;
+                self.declare(&l.name, scopes)?;
                 self.verify_var_prefix(&l.name, in_unsafe)?;
                 if let Some(ty) = &l.ty {
-                    self.verify_type_safety(ty, in_unsafe)?;
+                    self.verify_type_safety(&l.name, ty, in_unsafe)?;
                 }
 
-                self.verify_rules_in_expr(&l.value, global_vars, in_unsafe)?;
                 self.verify_raw_to_high_rule(&l.name, &l.value, in_unsafe)?;
             }
-            Statement::Const(c) => {
-                if !global_vars.insert(c.name.clone()) {
-                    return Err(format!(
-                        "Rule 4 Violation: Variable '{}' already defined.",
-                        c.name
-                    ));
+            Statement::LetTuple(l) => {
+                self.verify_rules_in_expr(&l.value, scopes, in_unsafe)?;
+                if let Some(ty) = &l.ty {
+                    self.verify_type_safety(&l.names.join(","), ty, in_unsafe)?;
                 }
+                for (index, name) in l.names.iter().enumerate() {
+                    if l.names[..index].contains(name) {
+                        return Err(format!(
+                            "Phase 4 Error: Variable '{name}' is bound more than once in one 'let'."
+                        ));
+                    }
+                    self.declare(name, scopes)?;
+                    self.verify_var_prefix(name, in_unsafe)?;
+                    // Each name is checked against its own element when the
+                    // value spells the tuple out.
+                    let value = match &l.value {
+                        Expression::Tuple(elements) => elements.get(index).unwrap_or(&l.value),
+                        value => value,
+                    };
+                    self.verify_raw_to_high_rule(name, value, in_unsafe)?;
+                }
+            }
+            Statement::Const(c) => {
+                self.verify_rules_in_expr(&c.value, scopes, in_unsafe)?;
+                self.declare(&c.name, scopes)?;
                 self.verify_var_prefix(&c.name, in_unsafe)?;
                 if let Some(ty) = &c.ty {
-                    self.verify_type_safety(ty, in_unsafe)?;
+                    self.verify_type_safety(&c.name, ty, in_unsafe)?;
                 }
 
-                self.verify_rules_in_expr(&c.value, global_vars, in_unsafe)?;
                 self.verify_raw_to_high_rule(&c.name, &c.value, in_unsafe)?;
             }
             Statement::If(stmt) => {
-                self.verify_rules_in_expr(&stmt.condition, global_vars, in_unsafe)?;
-                self.verify_rules_in_block(&stmt.then_block, global_vars, in_unsafe)?;
+                self.verify_rules_in_expr(&stmt.condition, scopes, in_unsafe)?;
+                match &stmt.pattern {
+                    Some(pattern) => {
+                        self.verify_rules_in_arm(pattern, &stmt.then_block, scopes, in_unsafe)?
+                    }
+                    None => self.verify_rules_in_block(&stmt.then_block, scopes, in_unsafe)?,
+                }
                 if let Some(else_block) = &stmt.else_block {
-                    self.verify_rules_in_block(else_block, global_vars, in_unsafe)?;
+                    self.verify_rules_in_block(else_block, scopes, in_unsafe)?;
                 }
             }
             Statement::For(stmt) => {
-                if !global_vars.insert(stmt.var_name.clone()) {
-                    return Err(format!(
-                        "Rule 4 Violation: Variable '{}' already defined.",
-                        stmt.var_name
-                    ));
-                }
-                self.verify_var_prefix(&stmt.var_name, in_unsafe)?;
-                self.verify_rules_in_expr(&stmt.start, global_vars, in_unsafe)?;
-                self.verify_rules_in_expr(&stmt.end, global_vars, in_unsafe)?;
-                self.verify_rules_in_block(&stmt.body, global_vars, in_unsafe)?;
+                self.verify_rules_in_expr(&stmt.start, scopes, in_unsafe)?;
+                self.verify_rules_in_expr(&stmt.end, scopes, in_unsafe)?;
+                // The loop variable lives in the same scope as the body.
+                scopes.push(HashSet::new());
+                let result = self
+                    .declare(&stmt.var_name, scopes)
+                    .and_then(|()| self.verify_var_prefix(&stmt.var_name, in_unsafe))
+                    .and_then(|()| {
+                        self.verify_rules_in_statements(
+                            &stmt.body.statements,
+                            scopes,
+                            in_unsafe || stmt.body.unsafe_block,
+                        )
+                    });
+                scopes.pop();
+                result?;
             }
             Statement::Break | Statement::Continue => {}
             Statement::Expr(e) => {
-                self.verify_rules_in_expr(e, global_vars, in_unsafe)?;
+                self.verify_rules_in_expr(e, scopes, in_unsafe)?;
             }
         }
         Ok(())
@@ -130,64 +215,179 @@ impl Molder {
     fn verify_rules_in_expr(
         &self,
         expr: &Expression,
-        global_vars: &mut HashSet<String>,
+        scopes: &mut Vec<HashSet<String>>,
         in_unsafe: bool,
     ) -> Result<(), String> {
         match expr {
             Expression::Block(b) => {
-                self.verify_rules_in_block(b, global_vars, in_unsafe)?;
+                self.verify_rules_in_block(b, scopes, in_unsafe)?;
             }
             Expression::Call(c) => {
                 for arg in &c.args {
-                    self.verify_rules_in_expr(arg, global_vars, in_unsafe)?;
+                    self.verify_rules_in_expr(arg, scopes, in_unsafe)?;
                 }
             }
             Expression::Binary { left, right, .. } => {
-                self.verify_rules_in_expr(left, global_vars, in_unsafe)?;
-                self.verify_rules_in_expr(right, global_vars, in_unsafe)?;
+                self.verify_rules_in_expr(left, scopes, in_unsafe)?;
+                self.verify_rules_in_expr(right, scopes, in_unsafe)?;
             }
-            Expression::Ref { expr, .. } => {
-                self.verify_rules_in_expr(expr, global_vars, in_unsafe)?;
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => {
+                self.verify_rules_in_expr(expr, scopes, in_unsafe)?;
+            }
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => {
+                for element in elements {
+                    self.verify_rules_in_expr(element, scopes, in_unsafe)?;
+                }
+            }
+            Expression::Match { value, arms } => {
+                self.verify_rules_in_expr(value, scopes, in_unsafe)?;
+                for arm in arms {
+                    self.verify_rules_in_arm(&arm.pattern, &arm.body, scopes, in_unsafe)?;
+                }
+            }
+            Expression::Closure(closure) => {
+                scopes.push(HashSet::new());
+                let result = self
+                    .verify_closure_params(closure, scopes, in_unsafe)
+                    .and_then(|()| self.verify_rules_in_expr(&closure.body, scopes, in_unsafe));
+                scopes.pop();
+                result?;
             }
             _ => {}
         }
         Ok(())
     }
 
+    /// A `match` arm or `if let` body: the pattern's bindings live in the
+    /// same scope as the body, like a loop variable.
+    fn verify_rules_in_arm(
+        &self,
+        pattern: &Pattern,
+        body: &Block,
+        scopes: &mut Vec<HashSet<String>>,
+        in_unsafe: bool,
+    ) -> Result<(), String> {
+        scopes.push(HashSet::new());
+        let result = self
+            .verify_pattern_bindings(pattern, scopes, in_unsafe)
+            .and_then(|()| {
+                self.verify_rules_in_statements(
+                    &body.statements,
+                    scopes,
+                    in_unsafe || body.unsafe_block,
+                )
+            });
+        scopes.pop();
+        result
+    }
+
+    fn verify_pattern_bindings(
+        &self,
+        pattern: &Pattern,
+        scopes: &mut [HashSet<String>],
+        in_unsafe: bool,
+    ) -> Result<(), String> {
+        let names: Vec<&String> = pattern.bindings().collect();
+        for (index, name) in names.iter().enumerate() {
+            if names[..index].contains(name) {
+                return Err(format!(
+                    "Phase 4 Error: Variable '{name}' is bound more than once in one pattern."
+                ));
+            }
+            self.declare(name, scopes)?;
+            self.verify_var_prefix(name, in_unsafe)?;
+        }
+        Ok(())
+    }
+
+    /// Closure parameters are named and typed like function parameters, in
+    /// a scope around the body.
+    fn verify_closure_params(
+        &self,
+        closure: &Closure,
+        scopes: &mut [HashSet<String>],
+        in_unsafe: bool,
+    ) -> Result<(), String> {
+        for (index, param) in closure.params.iter().enumerate() {
+            if closure.params[..index].iter().any(|p| p.name == param.name) {
+                return Err(format!(
+                    "Phase 4 Error: Parameter '{}' is declared more than once in a closure.",
+                    param.name
+                ));
+            }
+            self.declare(&param.name, scopes)?;
+            self.verify_var_prefix(&param.name, in_unsafe)?;
+            if let Some(ty) = &param.ty {
+                self.verify_type_safety(&param.name, ty, in_unsafe)?;
+            }
+        }
+        Ok(())
+    }
+
     fn verify_var_prefix(&self, name: &str, in_unsafe: bool) -> Result<(), String> {
         if in_unsafe {
             if !(name.starts_with("raw_")
                 || name.starts_with("validated_")
                 || name.starts_with("high_"))
             {
-                return Err(format!(
+                return self.violation(Rule::Rule5, format!(
                     "Rule 5 Violation: Variable '{name}' in unsafe block must start with 'raw_', 'validated_', or 'high_'."
                 ));
             }
         } else if !name.starts_with("high_") {
-            return Err(format!(
-                "Rule 5 Violation: Variable '{name}' outside unsafe must start with 'high_'."
-            ));
+            return self.violation(
+                Rule::Rule5,
+                format!(
+                    "Rule 5 Violation: Variable '{name}' outside unsafe must start with 'high_'."
+                ),
+            );
         }
         Ok(())
     }
 
-    fn verify_type_safety(&self, ty: &Type, in_unsafe: bool) -> Result<(), String> {
+    /// Checks the type declared for `binding` in the current item. Every
+    /// declared type passes through here once, in the order phase 1
+    /// recorded the aliases they were written with.
+    fn verify_type_safety(&self, binding: &str, ty: &Type, in_unsafe: bool) -> Result<(), String> {
+        let alias = self
+            .unsafe_alias_uses
+            .borrow_mut()
+            .get_mut(&self.alias_site(binding))
+            .and_then(VecDeque::pop_front)
+            .flatten();
         if !in_unsafe && Self::is_unsafe_type(ty) {
-            return Err(format!(
-                "Rule 3 Violation: Unsafe type '{}' used outside unsafe block.",
-                Self::type_display(ty)
-            ));
+            let note = alias.map_or_else(String::new, |name| {
+                let origin = self
+                    .alias_origins
+                    .get(&name)
+                    .map_or("source", String::as_str);
+                format!(" (via alias '{name}' at {origin})")
+            });
+            return self.violation(
+                Rule::Rule3,
+                format!(
+                    "Rule 3 Violation: Unsafe type '{}' used outside unsafe block.{note}",
+                    Self::type_display(ty),
+                ),
+            );
         }
         Ok(())
     }
 
-    fn is_unsafe_type(ty: &Type) -> bool {
+    pub(super) fn is_unsafe_type(ty: &Type) -> bool {
         match ty {
             Type::RawPtr(_) => true,
             Type::Ref { inner, .. } => Self::is_unsafe_type(inner),
+            Type::Tuple(elements) => elements.iter().any(Self::is_unsafe_type),
+            Type::Fn {
+                raw, params, ret, ..
+            } => *raw || params.iter().any(Self::is_unsafe_type) || Self::is_unsafe_type(ret),
             Type::Path(name) => {
                 name.contains("::raw::")
+                    || name.contains("raw fn(")
+                    || name.contains("raw closure(")
                     || name.contains("Raw<")
                     || name.ends_with("RawPtr")
                     || name.contains("Validated<")
@@ -196,7 +396,7 @@ impl Molder {
         }
     }
 
-    fn type_display(ty: &Type) -> String {
+    pub(super) fn type_display(ty: &Type) -> String {
         match ty {
             Type::Path(s) => s.clone(),
             Type::RawPtr(inner) => format!("*{}", Self::type_display(inner)),
@@ -207,9 +407,60 @@ impl Molder {
                     format!("&{}", Self::type_display(inner))
                 }
             }
+            Type::Tuple(elements) => format!(
+                "({})",
+                elements
+                    .iter()
+                    .map(Self::type_display)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Fn {
+                raw,
+                closure,
+                params,
+                ret,
+            } => format!(
+                "{}{}({}) -> {}",
+                if *raw { "raw " } else { "" },
+                if *closure { "closure" } else { "fn" },
+                params
+                    .iter()
+                    .map(Self::type_display)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::type_display(ret)
+            ),
         }
     }
 
+    /// Calls that may create a `validated_` binding, with the prefix their
+    /// first argument must carry. Returns the short name for diagnostics.
+    fn validating_call(func_name: &str) -> Option<(&'static str, &'static str)> {
+        const VALIDATING_CALLS: &[(&str, &str, &str)] = &[
+            ("validate_raw", "core::memory::safe::validate_raw", "raw_"),
+            (
+                "validate_raw_with",
+                "core::memory::raw::validate_with",
+                "raw_",
+            ),
+            (
+                "validate_raw_min_len",
+                "core::memory::safe::validate_raw_min_len",
+                "raw_",
+            ),
+            (
+                "validation_unwrap",
+                "core::memory::safe::validation_unwrap",
+                "validated_",
+            ),
+        ];
+        VALIDATING_CALLS
+            .iter()
+            .find(|(short, canonical, _)| func_name == *short || func_name == *canonical)
+            .map(|(short, _, prefix)| (*short, *prefix))
+    }
+
     fn verify_raw_to_high_rule(
         &self,
         name: &str,
@@ -221,32 +472,26 @@ impl Molder {
         }
 
         if name.starts_with("validated_") {
-            match value {
-                Expression::Call(call) => {
-                    if call.func_name != "validate_raw"
-                        && call.func_name != "core::memory::safe::validate_raw"
-                    {
-                        return Err(format!(
-                            "Rule 6 Violation: Validated variable '{name}' must be created via validate_raw()."
-                        ));
-                    }
-                    if let Some(Expression::Variable(var)) = call.args.first() {
-                        if !var.starts_with("raw_") {
-                            return Err(format!(
-                                "Rule 6 Violation: validate_raw() must use a raw_ value (got '{var}')."
-                            ));
-                        }
-                    } else {
-                        return Err(format!(
-                            "Rule 6 Violation: validate_raw() must take a raw_ variable for '{name}'."
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(format!(
-                        "Rule 6 Violation: Validated variable '{name}' must be created via validate_raw()."
+            let Expression::Call(call) = value else {
+                return self.violation(Rule::Rule6, format!(
+                    "Rule 6 Violation: Validated variable '{name}' must be created via a validating call (validate_raw(), validate_raw_with(), validate_raw_min_len() or validation_unwrap())."
+                ));
+            };
+            let Some((func, source_prefix)) = Self::validating_call(&call.func_name) else {
+                return self.violation(Rule::Rule6, format!(
+                    "Rule 6 Violation: Validated variable '{name}' must be created via a validating call (validate_raw(), validate_raw_with(), validate_raw_min_len() or validation_unwrap())."
+                ));
+            };
+            if let Some(Expression::Variable(var)) = call.args.first() {
+                if !var.starts_with(source_prefix) {
+                    return self.violation(Rule::Rule6, format!(
+                        "Rule 6 Violation: {func}() must use a {source_prefix} value (got '{var}')."
                     ));
                 }
+            } else {
+                return self.violation(Rule::Rule6, format!(
+                    "Rule 6 Violation: {func}() must take a {source_prefix} variable for '{name}'."
+                ));
             }
         }
 
@@ -256,24 +501,24 @@ impl Molder {
                     if call.func_name != "into_high"
                         && call.func_name != "core::memory::safe::into_high"
                     {
-                        return Err(format!(
+                        return self.violation(Rule::Rule6, format!(
                             "Rule 6 Violation: High variable '{name}' in unsafe must be created via into_high()."
                         ));
                     }
                     if let Some(Expression::Variable(var)) = call.args.first() {
                         if !var.starts_with("validated_") {
-                            return Err(format!(
+                            return self.violation(Rule::Rule6, format!(
                                 "Rule 6 Violation: into_high() must use a validated_ value (got '{var}')."
                             ));
                         }
                     } else {
-                        return Err(format!(
+                        return self.violation(Rule::Rule6, format!(
                             "Rule 6 Violation: into_high() must take a validated_ variable for '{name}'."
                         ));
                     }
                 }
                 _ => {
-                    return Err(format!(
+                    return self.violation(Rule::Rule6, format!(
                         "Rule 6 Violation: High variable '{name}' in unsafe must be created via into_high()."
                     ));
                 }
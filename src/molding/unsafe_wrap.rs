@@ -39,6 +39,13 @@ impl Molder {
                         raw_functions,
                     )?;
                 }
+                Statement::LetTuple(l) => {
+                    l.value = Self::wrap_raw_calls_in_expr(
+                        l.value.clone(),
+                        current_unsafe,
+                        raw_functions,
+                    )?;
+                }
                 Statement::Const(c) => {
                     c.value = Self::wrap_raw_calls_in_expr(
                         c.value.clone(),
@@ -88,11 +95,22 @@ impl Molder {
         in_unsafe: bool,
         raw_functions: &HashSet<String>,
     ) -> Result<Expression, String> {
-        if let Expression::Block(mut block) = expr.clone() {
-            if block.unsafe_block {
-                let _ = Self::wrap_raw_calls_in_block(&mut block, true, raw_functions);
-                return Ok(Expression::Block(block));
+        if let Expression::Block(mut block) = expr.clone()
+            && block.unsafe_block
+        {
+            let _ = Self::wrap_raw_calls_in_block(&mut block, true, raw_functions);
+            return Ok(Expression::Block(block));
+        }
+        // Arms are wrapped statement by statement, like any other block.
+        if let Expression::Match { value, mut arms } = expr.clone() {
+            let value = Self::wrap_raw_calls_in_expr(*value, in_unsafe, raw_functions)?;
+            for arm in &mut arms {
+                Self::wrap_raw_calls_in_block(&mut arm.body, in_unsafe, raw_functions)?;
             }
+            return Ok(Expression::Match {
+                value: Box::new(value),
+                arms,
+            });
         }
 
         if in_unsafe {
@@ -152,10 +170,64 @@ impl Molder {
                     raw_functions,
                 )),
             },
+            Expression::Cast { expr, ty } => Expression::Cast {
+                expr: Box::new(Self::wrap_raw_calls_in_expr_inner(
+                    *expr,
+                    in_unsafe,
+                    raw_functions,
+                )),
+                ty,
+            },
+            Expression::Field { expr, index } => Expression::Field {
+                expr: Box::new(Self::wrap_raw_calls_in_expr_inner(
+                    *expr,
+                    in_unsafe,
+                    raw_functions,
+                )),
+                index,
+            },
+            Expression::Tuple(elements) => Expression::Tuple(
+                elements
+                    .into_iter()
+                    .map(|element| {
+                        Self::wrap_raw_calls_in_expr_inner(element, in_unsafe, raw_functions)
+                    })
+                    .collect(),
+            ),
+            Expression::Variant {
+                enum_name,
+                variant,
+                args,
+            } => Expression::Variant {
+                enum_name,
+                variant,
+                args: args
+                    .into_iter()
+                    .map(|arg| Self::wrap_raw_calls_in_expr_inner(arg, in_unsafe, raw_functions))
+                    .collect(),
+            },
+            Expression::Match { value, mut arms } => {
+                for arm in &mut arms {
+                    let _ = Self::wrap_raw_calls_in_block(&mut arm.body, in_unsafe, raw_functions);
+                }
+                Expression::Match {
+                    value: Box::new(Self::wrap_raw_calls_in_expr_inner(
+                        *value,
+                        in_unsafe,
+                        raw_functions,
+                    )),
+                    arms,
+                }
+            }
             Expression::Block(mut block) => {
                 let _ = Self::wrap_raw_calls_in_block(&mut block, in_unsafe, raw_functions);
                 Expression::Block(block)
             }
+            Expression::Closure(mut closure) => {
+                *closure.body =
+                    Self::wrap_raw_calls_in_expr_inner(*closure.body, in_unsafe, raw_functions);
+                Expression::Closure(closure)
+            }
             other => other,
         }
     }
@@ -173,9 +245,24 @@ impl Molder {
                 Self::expr_contains_raw_call(left, raw_functions)
                     || Self::expr_contains_raw_call(right, raw_functions)
             }
-            Expression::Ref { expr, .. } => Self::expr_contains_raw_call(expr, raw_functions),
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => Self::expr_contains_raw_call(expr, raw_functions),
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => elements
+                .iter()
+                .any(|element| Self::expr_contains_raw_call(element, raw_functions)),
+            Expression::Match { value, arms } => {
+                Self::expr_contains_raw_call(value, raw_functions)
+                    || arms.iter().any(|arm| {
+                        Self::expr_contains_raw_call(
+                            &Expression::Block(arm.body.clone()),
+                            raw_functions,
+                        )
+                    })
+            }
             Expression::Block(block) => block.statements.iter().any(|stmt| match stmt {
                 Statement::Let(l) => Self::expr_contains_raw_call(&l.value, raw_functions),
+                Statement::LetTuple(l) => Self::expr_contains_raw_call(&l.value, raw_functions),
                 Statement::Const(c) => Self::expr_contains_raw_call(&c.value, raw_functions),
                 Statement::If(stmt) => {
                     Self::expr_contains_raw_call(&stmt.condition, raw_functions)
@@ -201,6 +288,9 @@ impl Molder {
                 Statement::Break | Statement::Continue => false,
                 Statement::Expr(e) => Self::expr_contains_raw_call(e, raw_functions),
             }),
+            Expression::Closure(closure) => {
+                Self::expr_contains_raw_call(&closure.body, raw_functions)
+            }
             _ => false,
         }
     }
@@ -217,6 +307,9 @@ impl Molder {
                 Statement::Let(l) => {
                     Self::verify_unsafe_in_expr(&l.value, current_unsafe, raw_functions)?;
                 }
+                Statement::LetTuple(l) => {
+                    Self::verify_unsafe_in_expr(&l.value, current_unsafe, raw_functions)?;
+                }
                 Statement::Const(c) => {
                     Self::verify_unsafe_in_expr(&c.value, current_unsafe, raw_functions)?;
                 }
@@ -266,12 +359,28 @@ impl Molder {
                 Self::verify_unsafe_in_expr(left, in_unsafe, raw_functions)?;
                 Self::verify_unsafe_in_expr(right, in_unsafe, raw_functions)?;
             }
-            Expression::Ref { expr, .. } => {
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => {
                 Self::verify_unsafe_in_expr(expr, in_unsafe, raw_functions)?;
             }
+            Expression::Tuple(elements) | Expression::Variant { args: elements, .. } => {
+                for element in elements {
+                    Self::verify_unsafe_in_expr(element, in_unsafe, raw_functions)?;
+                }
+            }
+            Expression::Match { value, arms } => {
+                Self::verify_unsafe_in_expr(value, in_unsafe, raw_functions)?;
+                for arm in arms {
+                    Self::verify_unsafe_boundaries(&arm.body, in_unsafe, raw_functions)?;
+                }
+            }
             Expression::Block(b) => {
                 Self::verify_unsafe_boundaries(b, in_unsafe, raw_functions)?;
             }
+            Expression::Closure(closure) => {
+                Self::verify_unsafe_in_expr(&closure.body, in_unsafe, raw_functions)?;
+            }
             _ => {}
         }
         Ok(())
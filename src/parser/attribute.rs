@@ -0,0 +1,46 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use crate::ast::Attribute;
+use crate::lexer::TokenKind;
+use nom::IResult;
+use nom::branch::alt;
+use nom::combinator::opt;
+use nom::multi::{many0, separated_list1};
+use nom::sequence::delimited;
+
+use super::helpers::{Input, expect_token, identifier, integer_literal, string_literal};
+
+/// `#[name]` / `#[name(arg, ...)]`.
+fn parse_attribute(input: Input) -> IResult<Input, Attribute> {
+    let (input, hash) = expect_token(TokenKind::Hash)(input)?;
+    let line = hash.span.line;
+    let (input, _) = expect_token(TokenKind::OpenBracket)(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, args) = opt(delimited(
+        expect_token(TokenKind::OpenParen),
+        separated_list1(
+            expect_token(TokenKind::Comma),
+            alt((identifier, integer_literal, string_literal)),
+        ),
+        expect_token(TokenKind::CloseParen),
+    ))(input)?;
+    let (input, _) = expect_token(TokenKind::CloseBracket)(input)?;
+    Ok((
+        input,
+        Attribute {
+            name,
+            args: args.unwrap_or_default(),
+            line,
+        },
+    ))
+}
+
+/// Attributes in front of an item or statement, possibly none.
+pub fn parse_attributes(input: Input) -> IResult<Input, Vec<Attribute>> {
+    many0(parse_attribute)(input)
+}
@@ -9,11 +9,12 @@ use crate::ast::*;
 use crate::lexer::TokenKind;
 use nom::IResult;
 use nom::branch::alt;
-use nom::combinator::{map, map_res};
-use nom::multi::separated_list0;
+use nom::combinator::{map, map_opt, map_res, opt};
+use nom::multi::{many0, many1, separated_list0, separated_list1};
+use nom::sequence::{delimited, preceded};
 
 use super::helpers::{Input, expect_token, identifier, integer_literal, string_literal};
-use super::stmt::parse_block_content;
+use super::stmt::{parse_block, parse_block_content};
 use super::types::parse_type;
 
 pub fn parse_expression(input: Input) -> IResult<Input, Expression> {
@@ -21,7 +22,7 @@ pub fn parse_expression(input: Input) -> IResult<Input, Expression> {
 }
 
 fn parse_comparison(input: Input) -> IResult<Input, Expression> {
-    let (mut input, mut expr) = parse_primary_expression(input)?;
+    let (mut input, mut expr) = parse_cast(input)?;
 
     loop {
         let (next_input, op) =
@@ -41,7 +42,7 @@ fn parse_comparison(input: Input) -> IResult<Input, Expression> {
                 break;
             };
 
-        let (after_rhs, rhs) = parse_primary_expression(next_input)?;
+        let (after_rhs, rhs) = parse_cast(next_input)?;
         expr = Expression::Binary {
             op,
             left: Box::new(expr),
@@ -53,10 +54,42 @@ fn parse_comparison(input: Input) -> IResult<Input, Expression> {
     Ok((input, expr))
 }
 
+/// `expr as T as U ...`, binding tighter than comparisons.
+fn parse_cast(input: Input) -> IResult<Input, Expression> {
+    let (mut input, mut expr) = parse_field(input)?;
+    while let Ok((after_as, _)) = expect_token(TokenKind::As)(input) {
+        let (after_ty, ty) = parse_type(after_as)?;
+        expr = Expression::Cast {
+            expr: Box::new(expr),
+            ty,
+        };
+        input = after_ty;
+    }
+    Ok((input, expr))
+}
+
+/// `expr.0.1 ...`
+fn parse_field(input: Input) -> IResult<Input, Expression> {
+    let (mut input, mut expr) = parse_primary_expression(input)?;
+    while let Ok((after_dot, _)) = expect_token(TokenKind::Dot)(input) {
+        let (after_index, index) = map_res(integer_literal, |s| s.parse::<usize>())(after_dot)?;
+        expr = Expression::Field {
+            expr: Box::new(expr),
+            index,
+        };
+        input = after_index;
+    }
+    Ok((input, expr))
+}
+
 fn parse_primary_expression(input: Input) -> IResult<Input, Expression> {
     alt((
+        parse_closure,
+        parse_tuple,
         parse_ref_expr,
         parse_unsafe_block_expr,
+        parse_match,
+        parse_variant,
         parse_call,
         parse_variable,
         parse_literal,
@@ -79,6 +112,108 @@ fn parse_literal(input: Input) -> IResult<Input, Expression> {
     ))(input)
 }
 
+/// `(a, b, ...)` with at least two elements.
+fn parse_tuple(input: Input) -> IResult<Input, Expression> {
+    let (input, _) = expect_token(TokenKind::OpenParen)(input)?;
+    let (input, first) = parse_expression(input)?;
+    let (input, rest) = many1(preceded(expect_token(TokenKind::Comma), parse_expression))(input)?;
+    let (input, _) = expect_token(TokenKind::CloseParen)(input)?;
+    let mut elements = vec![first];
+    elements.extend(rest);
+    Ok((input, Expression::Tuple(elements)))
+}
+
+/// `|a, b: T| body`; `||` lexes as two pipes, so it needs no special case.
+fn parse_closure(input: Input) -> IResult<Input, Expression> {
+    let (input, _) = expect_token(TokenKind::Pipe)(input)?;
+    let (input, params) =
+        separated_list0(expect_token(TokenKind::Comma), parse_closure_param)(input)?;
+    let (input, _) = expect_token(TokenKind::Pipe)(input)?;
+    let (input, body) = parse_expression(input)?;
+    Ok((
+        input,
+        Expression::Closure(Closure {
+            params,
+            body: Box::new(body),
+        }),
+    ))
+}
+
+fn parse_closure_param(input: Input) -> IResult<Input, ClosureParam> {
+    let (input, name) = identifier(input)?;
+    let (input, ty) = opt(preceded(expect_token(TokenKind::Colon), parse_type))(input)?;
+    Ok((input, ClosureParam { name, ty }))
+}
+
+/// Splits `Enum::Variant` into its enum and variant names. Any `::` path
+/// whose last segment is capitalized names a variant.
+fn variant_path(name: &str) -> Option<(String, String)> {
+    let (enum_name, variant) = name.rsplit_once("::")?;
+    is_type_name(variant).then(|| (enum_name.to_string(), variant.to_string()))
+}
+
+/// `Enum::Variant` or `Enum::Variant(a, ...)`.
+fn parse_variant(input: Input) -> IResult<Input, Expression> {
+    let (input, (enum_name, variant)) = map_opt(identifier, |name| variant_path(&name))(input)?;
+    let (input, args) = opt(delimited(
+        expect_token(TokenKind::OpenParen),
+        separated_list1(expect_token(TokenKind::Comma), parse_expression),
+        expect_token(TokenKind::CloseParen),
+    ))(input)?;
+    Ok((
+        input,
+        Expression::Variant {
+            enum_name,
+            variant,
+            args: args.unwrap_or_default(),
+        },
+    ))
+}
+
+/// `match value { Pattern => { ... } ... }`
+fn parse_match(input: Input) -> IResult<Input, Expression> {
+    let (input, _) = expect_token(TokenKind::Match)(input)?;
+    let (input, value) = parse_expression(input)?;
+    let (input, _) = expect_token(TokenKind::OpenBrace)(input)?;
+    let (input, arms) = many0(parse_match_arm)(input)?;
+    let (input, _) = expect_token(TokenKind::CloseBrace)(input)?;
+    Ok((
+        input,
+        Expression::Match {
+            value: Box::new(value),
+            arms,
+        },
+    ))
+}
+
+fn parse_match_arm(input: Input) -> IResult<Input, MatchArm> {
+    let (input, pattern) = parse_pattern(input)?;
+    let (input, _) = expect_token(TokenKind::FatArrow)(input)?;
+    let (input, body) = parse_block(input)?;
+    Ok((input, MatchArm { pattern, body }))
+}
+
+/// `_`, `Enum::Variant` or `Enum::Variant(a, _, ...)`.
+pub fn parse_pattern(input: Input) -> IResult<Input, Pattern> {
+    if let Ok((rest, _)) = expect_token(TokenKind::Identifier("_".to_string()))(input) {
+        return Ok((rest, Pattern::Wildcard));
+    }
+    let (input, (enum_name, variant)) = map_opt(identifier, |name| variant_path(&name))(input)?;
+    let (input, bindings) = opt(delimited(
+        expect_token(TokenKind::OpenParen),
+        separated_list1(expect_token(TokenKind::Comma), identifier),
+        expect_token(TokenKind::CloseParen),
+    ))(input)?;
+    Ok((
+        input,
+        Pattern::Variant {
+            enum_name,
+            variant,
+            bindings: bindings.unwrap_or_default(),
+        },
+    ))
+}
+
 fn parse_variable(input: Input) -> IResult<Input, Expression> {
     map(identifier, Expression::Variable)(input)
 }
@@ -88,11 +223,30 @@ fn parse_call_args(input: Input) -> IResult<Input, Vec<Expression>> {
 }
 
 fn parse_call(input: Input) -> IResult<Input, Expression> {
+    let line = input.first().map_or(0, |token| token.span.line);
     let (input, func_name) = identifier(input)?;
+    let (input, type_args) = opt(parse_type_args)(input)?;
     let (input, _) = expect_token(TokenKind::OpenParen)(input)?;
     let (input, args) = parse_call_args(input)?;
     let (input, _) = expect_token(TokenKind::CloseParen)(input)?;
-    Ok((input, Expression::Call(CallExpr { func_name, args })))
+    Ok((
+        input,
+        Expression::Call(CallExpr {
+            func_name,
+            args,
+            type_args: type_args.unwrap_or_default(),
+            line,
+        }),
+    ))
+}
+
+/// `::<T, ...>` after a function name.
+fn parse_type_args(input: Input) -> IResult<Input, Vec<Type>> {
+    let (input, _) = expect_token(TokenKind::ColonColon)(input)?;
+    let (input, _) = expect_token(TokenKind::LessThan)(input)?;
+    let (input, types) = separated_list1(expect_token(TokenKind::Comma), parse_type)(input)?;
+    let (input, _) = expect_token(TokenKind::GreaterThan)(input)?;
+    Ok((input, types))
 }
 
 fn parse_unsafe_block_expr(input: Input) -> IResult<Input, Expression> {
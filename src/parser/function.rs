@@ -9,22 +9,88 @@ use crate::ast::*;
 use crate::lexer::TokenKind;
 use nom::IResult;
 use nom::branch::alt;
-use nom::multi::{many0, separated_list0};
+use nom::combinator::opt;
+use nom::multi::{many0, separated_list0, separated_list1};
+use nom::sequence::delimited;
 
+use super::attribute::parse_attributes;
 use super::expr::parse_arg;
 use super::helpers::{Input, expect_token, identifier};
 use super::stmt::parse_block_content;
-use super::types::parse_optional_type;
+use super::types::{parse_optional_type, parse_type, type_to_string};
 
 fn parse_alias(input: Input) -> IResult<Input, Item> {
+    let line = input.first().map_or(0, |token| token.span.line);
     let (input, _) = expect_token(TokenKind::Alias)(input)?;
     let (input, name) = identifier(input)?;
+    let (input, params) = if is_type_name(&name) {
+        opt(delimited(
+            expect_token(TokenKind::LessThan),
+            separated_list1(expect_token(TokenKind::Comma), identifier),
+            expect_token(TokenKind::GreaterThan),
+        ))(input)?
+    } else {
+        (input, None)
+    };
     let (input, _) = expect_token(TokenKind::Equal)(input)?;
-    let (input, target) = identifier(input)?;
-    Ok((input, Item::Alias(Alias { name, target })))
+    let (input, target) = if is_type_name(&name) {
+        let (input, ty) = parse_type(input)?;
+        (input, type_to_string(&ty))
+    } else {
+        identifier(input)?
+    };
+    Ok((
+        input,
+        Item::Alias(Alias {
+            name,
+            target,
+            params: params.unwrap_or_default(),
+            line,
+            file: None,
+            attributes: Vec::new(),
+        }),
+    ))
+}
+
+/// `enum Name { A, B(T, ...), ... }`, the last comma optional.
+fn parse_enum(input: Input) -> IResult<Input, Item> {
+    let (input, _) = expect_token(TokenKind::Enum)(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = expect_token(TokenKind::OpenBrace)(input)?;
+    let (input, variants) =
+        separated_list0(expect_token(TokenKind::Comma), parse_enum_variant)(input)?;
+    let (input, _) = opt(expect_token(TokenKind::Comma))(input)?;
+    let (input, _) = expect_token(TokenKind::CloseBrace)(input)?;
+    Ok((
+        input,
+        Item::Enum(Enum {
+            name,
+            variants,
+            attributes: Vec::new(),
+        }),
+    ))
+}
+
+fn parse_enum_variant(input: Input) -> IResult<Input, EnumVariant> {
+    let (input, name) = identifier(input)?;
+    let (input, payload) = opt(delimited(
+        expect_token(TokenKind::OpenParen),
+        separated_list1(expect_token(TokenKind::Comma), parse_type),
+        expect_token(TokenKind::CloseParen),
+    ))(input)?;
+    Ok((
+        input,
+        EnumVariant {
+            name,
+            payload: payload.unwrap_or_default(),
+        },
+    ))
 }
 
 pub fn parse_function(input: Input) -> IResult<Input, Item> {
+    // `test` is contextual: only a keyword right before a function.
+    let (input, is_test) = opt(expect_token(TokenKind::Identifier("test".to_string())))(input)?;
+
     // Optional safety qualifier. If omitted, default to safe.
     let (input, safety) = match expect_token(TokenKind::Safe)(input) {
         Ok((i, _)) => (i, SafetyLevel::Safe),
@@ -58,12 +124,28 @@ pub fn parse_function(input: Input) -> IResult<Input, Item> {
                 statements,
                 unsafe_block: false,
             },
+            is_test: is_test.is_some(),
+            file: None,
+            attributes: Vec::new(),
         }),
     ))
 }
 
+/// An alias, enum or function with the attributes written before it.
+fn parse_item(input: Input) -> IResult<Input, Item> {
+    let (input, attributes) = parse_attributes(input)?;
+    let (input, mut item) = alt((parse_alias, parse_enum, parse_function))(input)?;
+    match &mut item {
+        Item::Function(func) => func.attributes = attributes,
+        Item::Alias(alias) => alias.attributes = attributes,
+        Item::Struct(s) => s.attributes = attributes,
+        Item::Enum(e) => e.attributes = attributes,
+    }
+    Ok((input, item))
+}
+
 pub fn parse(input: Input) -> IResult<Input, SourceFile> {
-    let (input, items) = many0(alt((parse_alias, parse_function)))(input)?;
+    let (input, items) = many0(parse_item)(input)?;
 
     Ok((input, SourceFile { items }))
 }
@@ -5,6 +5,7 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+mod attribute;
 mod diagnostics;
 mod expr;
 mod function;
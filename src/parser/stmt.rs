@@ -10,15 +10,32 @@ use crate::lexer::TokenKind;
 use nom::IResult;
 use nom::branch::alt;
 use nom::combinator::{map, opt};
-use nom::multi::many0;
-use nom::sequence::preceded;
+use nom::multi::{many0, many1};
+use nom::sequence::{delimited, preceded};
 
-use super::expr::parse_expression;
+use super::attribute::parse_attributes;
+use super::expr::{parse_expression, parse_pattern};
 use super::helpers::{Input, expect_token, identifier};
 use super::types::parse_type;
 
 fn parse_let(input: Input) -> IResult<Input, Statement> {
+    let line = input.first().map_or(0, |token| token.span.line);
     let (input, _) = expect_token(TokenKind::Let)(input)?;
+    if let Ok((input, names)) = parse_tuple_names(input) {
+        let (input, ty) = opt(preceded(expect_token(TokenKind::Colon), parse_type))(input)?;
+        let (input, _) = expect_token(TokenKind::Equal)(input)?;
+        let (input, value) = parse_expression(input)?;
+        return Ok((
+            input,
+            Statement::LetTuple(LetTupleStatement {
+                names,
+                ty,
+                value,
+                attributes: Vec::new(),
+                line,
+            }),
+        ));
+    }
     let (input, name) = identifier(input)?;
 
     let (input, ty) = opt(preceded(expect_token(TokenKind::Colon), parse_type))(input)?;
@@ -26,10 +43,31 @@ fn parse_let(input: Input) -> IResult<Input, Statement> {
     let (input, _) = expect_token(TokenKind::Equal)(input)?;
     let (input, value) = parse_expression(input)?;
 
-    Ok((input, Statement::Let(LetStatement { name, ty, value })))
+    Ok((
+        input,
+        Statement::Let(LetStatement {
+            name,
+            ty,
+            value,
+            attributes: Vec::new(),
+            line,
+        }),
+    ))
+}
+
+/// `(a, b, ...)` on the left of a destructuring `let`.
+fn parse_tuple_names(input: Input) -> IResult<Input, Vec<String>> {
+    let (input, _) = expect_token(TokenKind::OpenParen)(input)?;
+    let (input, first) = identifier(input)?;
+    let (input, rest) = many1(preceded(expect_token(TokenKind::Comma), identifier))(input)?;
+    let (input, _) = expect_token(TokenKind::CloseParen)(input)?;
+    let mut names = vec![first];
+    names.extend(rest);
+    Ok((input, names))
 }
 
 fn parse_const(input: Input) -> IResult<Input, Statement> {
+    let line = input.first().map_or(0, |token| token.span.line);
     let (input, _) = expect_token(TokenKind::Const)(input)?;
     let (input, name) = identifier(input)?;
 
@@ -38,11 +76,25 @@ fn parse_const(input: Input) -> IResult<Input, Statement> {
     let (input, _) = expect_token(TokenKind::Equal)(input)?;
     let (input, value) = parse_expression(input)?;
 
-    Ok((input, Statement::Const(ConstStatement { name, ty, value })))
+    Ok((
+        input,
+        Statement::Const(ConstStatement {
+            name,
+            ty,
+            value,
+            attributes: Vec::new(),
+            line,
+        }),
+    ))
 }
 
 fn parse_if_statement(input: Input) -> IResult<Input, IfStatement> {
     let (input, _) = expect_token(TokenKind::If)(input)?;
+    let (input, pattern) = opt(delimited(
+        expect_token(TokenKind::Let),
+        parse_pattern,
+        expect_token(TokenKind::Equal),
+    ))(input)?;
     let (input, condition) = parse_expression(input)?;
     let (input, then_block) = parse_block(input)?;
 
@@ -66,9 +118,11 @@ fn parse_if_statement(input: Input) -> IResult<Input, IfStatement> {
     Ok((
         input,
         IfStatement {
+            pattern,
             condition,
             then_block,
             else_block,
+            attributes: Vec::new(),
         },
     ))
 }
@@ -101,6 +155,7 @@ fn parse_for(input: Input) -> IResult<Input, Statement> {
             end,
             inclusive,
             body,
+            attributes: Vec::new(),
         }),
     ))
 }
@@ -115,7 +170,7 @@ fn parse_continue(input: Input) -> IResult<Input, Statement> {
     Ok((input, Statement::Continue))
 }
 
-fn parse_block(input: Input) -> IResult<Input, Block> {
+pub fn parse_block(input: Input) -> IResult<Input, Block> {
     let (input, _) = expect_token(TokenKind::OpenBrace)(input)?;
     let (input, statements) = parse_block_content(input)?;
     let (input, _) = expect_token(TokenKind::CloseBrace)(input)?;
@@ -128,7 +183,31 @@ fn parse_block(input: Input) -> IResult<Input, Block> {
     ))
 }
 
+/// A statement with the attributes written before it. Only `let`, `const`,
+/// `if` and `for` take attributes.
 fn parse_statement(input: Input) -> IResult<Input, Statement> {
+    let (rest, attributes) = parse_attributes(input)?;
+    let (rest, mut stmt) = parse_plain_statement(rest)?;
+    if attributes.is_empty() {
+        return Ok((rest, stmt));
+    }
+    match &mut stmt {
+        Statement::Let(LetStatement { attributes: a, .. })
+        | Statement::LetTuple(LetTupleStatement { attributes: a, .. })
+        | Statement::Const(ConstStatement { attributes: a, .. })
+        | Statement::If(IfStatement { attributes: a, .. })
+        | Statement::For(ForStatement { attributes: a, .. }) => *a = attributes,
+        Statement::Break | Statement::Continue | Statement::Expr(_) => {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Verify,
+            )));
+        }
+    }
+    Ok((rest, stmt))
+}
+
+fn parse_plain_statement(input: Input) -> IResult<Input, Statement> {
     alt((
         parse_const,
         parse_let,
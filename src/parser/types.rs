@@ -9,7 +9,7 @@ use crate::ast::Type;
 use crate::lexer::TokenKind;
 use nom::IResult;
 use nom::combinator::opt;
-use nom::multi::separated_list0;
+use nom::multi::{many1, separated_list0};
 use nom::sequence::preceded;
 
 use super::helpers::{Input, expect_token, identifier};
@@ -55,6 +55,44 @@ fn parse_type_recursive(input: Input) -> IResult<Input, Type> {
         }
     }
 
+    // Function type `fn(A, ...) -> R` or `raw fn(A, ...) -> R`
+    let (rest, raw) = match expect_token(TokenKind::Raw)(input) {
+        Ok((rest, _)) => (rest, true),
+        Err(_) => (input, false),
+    };
+    if let Ok((rest, _)) = expect_token(TokenKind::Fn)(rest) {
+        let (rest, _) = expect_token(TokenKind::OpenParen)(rest)?;
+        let (rest, params) =
+            separated_list0(expect_token(TokenKind::Comma), parse_type_recursive)(rest)?;
+        let (rest, _) = expect_token(TokenKind::CloseParen)(rest)?;
+        let (rest, ret) = parse_optional_type(rest)?;
+        return Ok((
+            rest,
+            Type::Fn {
+                raw,
+                closure: false,
+                params,
+                ret: Box::new(ret.unwrap_or_else(|| Type::Path("()".to_string()))),
+            },
+        ));
+    }
+
+    // `()` or a tuple `(A, B, ...)`
+    if let Ok((rest, _)) = expect_token(TokenKind::OpenParen)(input) {
+        if let Ok((rest, _)) = expect_token(TokenKind::CloseParen)(rest) {
+            return Ok((rest, Type::Path("()".to_string())));
+        }
+        let (rest, first) = parse_type_recursive(rest)?;
+        let (rest, others) = many1(preceded(
+            expect_token(TokenKind::Comma),
+            parse_type_recursive,
+        ))(rest)?;
+        let (rest, _) = expect_token(TokenKind::CloseParen)(rest)?;
+        let mut elements = vec![first];
+        elements.extend(others);
+        return Ok((rest, Type::Tuple(elements)));
+    }
+
     // Check for `[` (Slice/Array)
     if let Ok((rest, _)) = expect_token(TokenKind::OpenBracket)(input) {
         let (rest, inner_ty) = parse_type_recursive(rest)?;
@@ -87,7 +125,7 @@ fn parse_type_recursive(input: Input) -> IResult<Input, Type> {
 }
 
 // Helper to convert Type back to string (since AST uses Type::Path(String))
-fn type_to_string(ty: &Type) -> String {
+pub fn type_to_string(ty: &Type) -> String {
     match ty {
         Type::Path(s) => s.clone(),
         Type::RawPtr(inner) => format!("*{}", type_to_string(inner)),
@@ -98,6 +136,30 @@ fn type_to_string(ty: &Type) -> String {
                 format!("&{}", type_to_string(inner))
             }
         }
+        Type::Tuple(elements) => format!(
+            "({})",
+            elements
+                .iter()
+                .map(type_to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Type::Fn {
+            raw,
+            closure,
+            params,
+            ret,
+        } => format!(
+            "{}{}({}) -> {}",
+            if *raw { "raw " } else { "" },
+            if *closure { "closure" } else { "fn" },
+            params
+                .iter()
+                .map(type_to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            type_to_string(ret)
+        ),
     }
 }
 
@@ -64,6 +64,455 @@ const API_FUNCTIONS: &[ApiFunction] = &[
         args: &["core::memory::safe::ValidatedPtr"],
         ret: Some("core::memory::safe::HighPtr"),
     },
+    ApiFunction {
+        name: "try_allocate_buffer",
+        canonical: "core::memory::safe::try_allocate_buffer",
+        args: &["usize"],
+        ret: Some("core::types::Result<core::memory::safe::HighPtr, core::memory::MemoryError>"),
+    },
+    ApiFunction {
+        name: "try_deallocate_buffer",
+        canonical: "core::memory::safe::try_deallocate_buffer",
+        args: &["core::memory::safe::HighPtr"],
+        ret: Some("core::types::Result<(), core::memory::MemoryError>"),
+    },
+    ApiFunction {
+        name: "try_validate_raw",
+        canonical: "core::memory::safe::try_validate_raw",
+        args: &["core::memory::raw::RawPtr"],
+        ret: Some(
+            "core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>",
+        ),
+    },
+    ApiFunction {
+        name: "try_into_high",
+        canonical: "core::memory::safe::try_into_high",
+        args: &["core::memory::safe::ValidatedPtr"],
+        ret: Some("core::types::Result<core::memory::safe::HighPtr, core::memory::MemoryError>"),
+    },
+    ApiFunction {
+        name: "try_read_u8",
+        canonical: "core::memory::safe::try_read_u8",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("core::types::Result<u8, core::memory::MemoryError>"),
+    },
+    ApiFunction {
+        name: "try_write_u8",
+        canonical: "core::memory::safe::try_write_u8",
+        args: &["core::memory::safe::HighPtr", "usize", "u8"],
+        ret: Some("core::types::Result<(), core::memory::MemoryError>"),
+    },
+    ApiFunction {
+        name: "memory_error_code",
+        canonical: "core::memory::memory_error_code",
+        args: &["core::memory::MemoryError"],
+        ret: Some("i32"),
+    },
+    ApiFunction {
+        name: "validate_raw_with",
+        canonical: "core::memory::raw::validate_with",
+        args: &[
+            "core::memory::raw::RawPtr",
+            "raw fn(core::memory::raw::RawPtr, usize) -> bool",
+        ],
+        ret: Some(
+            "core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>",
+        ),
+    },
+    ApiFunction {
+        name: "validate_raw_min_len",
+        canonical: "core::memory::safe::validate_raw_min_len",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some(
+            "core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>",
+        ),
+    },
+    ApiFunction {
+        name: "validation_is_ok",
+        canonical: "core::memory::safe::validation_is_ok",
+        args: &[
+            "&core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>",
+        ],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "validation_unwrap",
+        canonical: "core::memory::safe::validation_unwrap",
+        args: &["core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>"],
+        ret: Some("core::memory::safe::ValidatedPtr"),
+    },
+    ApiFunction {
+        name: "validation_error",
+        canonical: "core::memory::safe::validation_error",
+        args: &[
+            "&core::types::Result<core::memory::safe::ValidatedPtr, core::memory::MemoryError>",
+        ],
+        ret: Some("core::memory::MemoryError"),
+    },
+    ApiFunction {
+        name: "raw_read_u16_le",
+        canonical: "core::memory::raw::read_u16_le",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "raw_read_u16_be",
+        canonical: "core::memory::raw::read_u16_be",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "raw_read_u32_le",
+        canonical: "core::memory::raw::read_u32_le",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "raw_read_u32_be",
+        canonical: "core::memory::raw::read_u32_be",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "raw_read_u64_le",
+        canonical: "core::memory::raw::read_u64_le",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "raw_read_u64_be",
+        canonical: "core::memory::raw::read_u64_be",
+        args: &["core::memory::raw::RawPtr", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "raw_write_u16_le",
+        canonical: "core::memory::raw::write_u16_le",
+        args: &["core::memory::raw::RawPtr", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_write_u16_be",
+        canonical: "core::memory::raw::write_u16_be",
+        args: &["core::memory::raw::RawPtr", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_write_u32_le",
+        canonical: "core::memory::raw::write_u32_le",
+        args: &["core::memory::raw::RawPtr", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_write_u32_be",
+        canonical: "core::memory::raw::write_u32_be",
+        args: &["core::memory::raw::RawPtr", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_write_u64_le",
+        canonical: "core::memory::raw::write_u64_le",
+        args: &["core::memory::raw::RawPtr", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_write_u64_be",
+        canonical: "core::memory::raw::write_u64_be",
+        args: &["core::memory::raw::RawPtr", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_copy_from",
+        canonical: "core::memory::raw::copy_from",
+        args: &[
+            "core::memory::raw::RawPtr",
+            "usize",
+            "core::memory::raw::RawPtr",
+            "usize",
+            "usize",
+        ],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_copy_to",
+        canonical: "core::memory::raw::copy_to",
+        args: &[
+            "core::memory::raw::RawPtr",
+            "usize",
+            "core::memory::raw::RawPtr",
+            "usize",
+            "usize",
+        ],
+        ret: None,
+    },
+    ApiFunction {
+        name: "raw_fill",
+        canonical: "core::memory::raw::fill",
+        args: &["core::memory::raw::RawPtr", "usize", "usize", "u8"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_read_u8",
+        canonical: "core::memory::safe::read_u8",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u8"),
+    },
+    ApiFunction {
+        name: "buffer_write_u8",
+        canonical: "core::memory::safe::write_u8",
+        args: &["core::memory::safe::HighPtr", "usize", "u8"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_read_u16_le",
+        canonical: "core::memory::safe::read_u16_le",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "buffer_read_u16_be",
+        canonical: "core::memory::safe::read_u16_be",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "buffer_read_u32_le",
+        canonical: "core::memory::safe::read_u32_le",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "buffer_read_u32_be",
+        canonical: "core::memory::safe::read_u32_be",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "buffer_read_u64_le",
+        canonical: "core::memory::safe::read_u64_le",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "buffer_read_u64_be",
+        canonical: "core::memory::safe::read_u64_be",
+        args: &["core::memory::safe::HighPtr", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "buffer_write_u16_le",
+        canonical: "core::memory::safe::write_u16_le",
+        args: &["core::memory::safe::HighPtr", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_write_u16_be",
+        canonical: "core::memory::safe::write_u16_be",
+        args: &["core::memory::safe::HighPtr", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_write_u32_le",
+        canonical: "core::memory::safe::write_u32_le",
+        args: &["core::memory::safe::HighPtr", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_write_u32_be",
+        canonical: "core::memory::safe::write_u32_be",
+        args: &["core::memory::safe::HighPtr", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_write_u64_le",
+        canonical: "core::memory::safe::write_u64_le",
+        args: &["core::memory::safe::HighPtr", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_write_u64_be",
+        canonical: "core::memory::safe::write_u64_be",
+        args: &["core::memory::safe::HighPtr", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_copy_from",
+        canonical: "core::memory::safe::copy_from",
+        args: &[
+            "core::memory::safe::HighPtr",
+            "usize",
+            "core::memory::safe::HighPtr",
+            "usize",
+            "usize",
+        ],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_copy_to",
+        canonical: "core::memory::safe::copy_to",
+        args: &[
+            "core::memory::safe::HighPtr",
+            "usize",
+            "core::memory::safe::HighPtr",
+            "usize",
+            "usize",
+        ],
+        ret: None,
+    },
+    ApiFunction {
+        name: "buffer_fill",
+        canonical: "core::memory::safe::fill",
+        args: &["core::memory::safe::HighPtr", "usize", "usize", "u8"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "use_arena_allocator",
+        canonical: "core::memory::allocator::use_arena_allocator",
+        args: &["usize"],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "use_pool_allocator",
+        canonical: "core::memory::allocator::use_pool_allocator",
+        args: &["usize", "usize"],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "view_of",
+        canonical: "core::memory::safe::view_of",
+        args: &["core::memory::safe::HighPtr"],
+        ret: Some("core::memory::safe::HighView"),
+    },
+    ApiFunction {
+        name: "view_range",
+        canonical: "core::memory::safe::view_range",
+        args: &["core::memory::safe::HighPtr", "usize", "usize"],
+        ret: Some("core::memory::safe::HighView"),
+    },
+    ApiFunction {
+        name: "view_len",
+        canonical: "core::memory::safe::view_len",
+        args: &["core::memory::safe::HighView"],
+        ret: Some("usize"),
+    },
+    ApiFunction {
+        name: "view_sub",
+        canonical: "core::memory::safe::view_sub",
+        args: &["core::memory::safe::HighView", "usize", "usize"],
+        ret: Some("core::memory::safe::HighView"),
+    },
+    ApiFunction {
+        name: "view_head",
+        canonical: "core::memory::safe::view_head",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("core::memory::safe::HighView"),
+    },
+    ApiFunction {
+        name: "view_tail",
+        canonical: "core::memory::safe::view_tail",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("core::memory::safe::HighView"),
+    },
+    ApiFunction {
+        name: "view_read_u8",
+        canonical: "core::memory::safe::view_read_u8",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u8"),
+    },
+    ApiFunction {
+        name: "view_write_u8",
+        canonical: "core::memory::safe::view_write_u8",
+        args: &["core::memory::safe::HighView", "usize", "u8"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_read_u16_le",
+        canonical: "core::memory::safe::view_read_u16_le",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "view_read_u16_be",
+        canonical: "core::memory::safe::view_read_u16_be",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u16"),
+    },
+    ApiFunction {
+        name: "view_read_u32_le",
+        canonical: "core::memory::safe::view_read_u32_le",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "view_read_u32_be",
+        canonical: "core::memory::safe::view_read_u32_be",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u32"),
+    },
+    ApiFunction {
+        name: "view_read_u64_le",
+        canonical: "core::memory::safe::view_read_u64_le",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "view_read_u64_be",
+        canonical: "core::memory::safe::view_read_u64_be",
+        args: &["core::memory::safe::HighView", "usize"],
+        ret: Some("u64"),
+    },
+    ApiFunction {
+        name: "view_write_u16_le",
+        canonical: "core::memory::safe::view_write_u16_le",
+        args: &["core::memory::safe::HighView", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_write_u16_be",
+        canonical: "core::memory::safe::view_write_u16_be",
+        args: &["core::memory::safe::HighView", "usize", "u16"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_write_u32_le",
+        canonical: "core::memory::safe::view_write_u32_le",
+        args: &["core::memory::safe::HighView", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_write_u32_be",
+        canonical: "core::memory::safe::view_write_u32_be",
+        args: &["core::memory::safe::HighView", "usize", "u32"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_write_u64_le",
+        canonical: "core::memory::safe::view_write_u64_le",
+        args: &["core::memory::safe::HighView", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_write_u64_be",
+        canonical: "core::memory::safe::view_write_u64_be",
+        args: &["core::memory::safe::HighView", "usize", "u64"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_copy_from",
+        canonical: "core::memory::safe::view_copy_from",
+        args: &[
+            "core::memory::safe::HighView",
+            "usize",
+            "core::memory::safe::HighView",
+        ],
+        ret: None,
+    },
+    ApiFunction {
+        name: "view_fill",
+        canonical: "core::memory::safe::view_fill",
+        args: &["core::memory::safe::HighView", "u8"],
+        ret: None,
+    },
     ApiFunction {
         name: "option_some_u8",
         canonical: "core::types::option_some_u8",
@@ -88,6 +537,60 @@ const API_FUNCTIONS: &[ApiFunction] = &[
         args: &["core::types::Option<u8>"],
         ret: Some("u8"),
     },
+    ApiFunction {
+        name: "option_is_some",
+        canonical: "core::types::option_is_some",
+        args: &["core::types::Option<T>"],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "option_unwrap",
+        canonical: "core::types::option_unwrap",
+        args: &["core::types::Option<T>"],
+        ret: Some("T"),
+    },
+    ApiFunction {
+        name: "checked_cast",
+        canonical: "core::types::checked_cast",
+        args: &["S"],
+        ret: Some("core::types::Option<T>"),
+    },
+    ApiFunction {
+        name: "wrapping_cast",
+        canonical: "core::types::wrapping_cast",
+        args: &["S"],
+        ret: Some("T"),
+    },
+    ApiFunction {
+        name: "saturating_cast",
+        canonical: "core::types::saturating_cast",
+        args: &["S"],
+        ret: Some("T"),
+    },
+    ApiFunction {
+        name: "result_is_ok",
+        canonical: "core::types::result_is_ok",
+        args: &["core::types::Result<T, E>"],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "result_is_err",
+        canonical: "core::types::result_is_err",
+        args: &["core::types::Result<T, E>"],
+        ret: Some("bool"),
+    },
+    ApiFunction {
+        name: "result_unwrap",
+        canonical: "core::types::result_unwrap",
+        args: &["core::types::Result<T, E>"],
+        ret: Some("T"),
+    },
+    ApiFunction {
+        name: "result_unwrap_err",
+        canonical: "core::types::result_unwrap_err",
+        args: &["core::types::Result<T, E>"],
+        ret: Some("E"),
+    },
     ApiFunction {
         name: "result_ok_u8_i32",
         canonical: "core::types::result_ok_u8_i32",
@@ -374,6 +877,36 @@ const API_FUNCTIONS: &[ApiFunction] = &[
         args: &["&mut core::types::List", "&core::types::List"],
         ret: None,
     },
+    ApiFunction {
+        name: "list_map_u8",
+        canonical: "core::types::list_map_u8",
+        args: &["&core::types::List", "closure(u8) -> u8"],
+        ret: Some("core::types::List"),
+    },
+    ApiFunction {
+        name: "list_filter_u8",
+        canonical: "core::types::list_filter_u8",
+        args: &["&core::types::List", "closure(u8) -> bool"],
+        ret: Some("core::types::List"),
+    },
+    ApiFunction {
+        name: "assert",
+        canonical: "core::testing::assert",
+        args: &["bool"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "assert_eq",
+        canonical: "core::testing::assert_eq",
+        args: &["T", "T"],
+        ret: None,
+    },
+    ApiFunction {
+        name: "expect_panic",
+        canonical: "core::testing::expect_panic",
+        args: &["T"],
+        ret: None,
+    },
 ];
 
 const API_TYPES: &[(&str, &str)] = &[
@@ -384,18 +917,25 @@ const API_TYPES: &[(&str, &str)] = &[
     ("Option", "core::types::Option"),
     ("Result", "core::types::Result"),
     ("HighPtr", "core::memory::safe::HighPtr"),
+    ("HighView", "core::memory::safe::HighView"),
     ("ValidatedPtr", "core::memory::safe::ValidatedPtr"),
     ("RawPtr", "core::memory::raw::RawPtr"),
+    ("MemoryError", "core::memory::MemoryError"),
     ("core::types::String", "core::types::String"),
     ("core::types::StringSplit", "core::types::StringSplit"),
     ("core::types::StringList", "core::types::StringList"),
     ("core::types::List", "core::types::List"),
     ("core::memory::safe::HighPtr", "core::memory::safe::HighPtr"),
+    (
+        "core::memory::safe::HighView",
+        "core::memory::safe::HighView",
+    ),
     (
         "core::memory::safe::ValidatedPtr",
         "core::memory::safe::ValidatedPtr",
     ),
     ("core::memory::raw::RawPtr", "core::memory::raw::RawPtr"),
+    ("core::memory::MemoryError", "core::memory::MemoryError"),
 ];
 
 const VARIADIC_PRINT_FUNCTIONS: &[&str] = &["print", "core::types::print"];
@@ -432,6 +972,71 @@ pub fn canonical_name(name: &str) -> Option<&'static str> {
     None
 }
 
+/// Testing builtins. Calls to them also get the source location, and
+/// `expect_panic` evaluates its argument only under a panic guard.
+pub fn is_testing_function(canonical: &str) -> bool {
+    canonical.starts_with("core::testing::")
+}
+
+/// Location reported by failed assertions: `file:line`, or `line N` when
+/// the file is unknown.
+pub fn source_location(file: Option<&str>, line: usize) -> String {
+    match file {
+        Some(file) => format!("{file}:{line}"),
+        None => format!("line {line}"),
+    }
+}
+
+/// Type parameters usable in builtin signatures, e.g. `core::types::Result<T, E>`.
+/// The type checker binds them from the arguments of each call.
+pub fn is_type_param(name: &str) -> bool {
+    matches!(name, "T" | "E" | "S")
+}
+
+/// `checked_cast`, `wrapping_cast` and `saturating_cast`. Their target type
+/// `T` is given explicitly, `checked_cast::<u8>(x)`, and their argument `S`
+/// may be any integer.
+pub fn is_cast_function(canonical: &str) -> bool {
+    matches!(
+        canonical,
+        "core::types::checked_cast" | "core::types::wrapping_cast" | "core::types::saturating_cast"
+    )
+}
+
+/// Smallest and largest value of a primitive integer type on every target.
+/// `isize` and `usize` get the 32-bit range, the narrowest they are on a
+/// target with `std`.
+pub fn integer_range(ty: &str) -> Option<(i128, i128)> {
+    Some(match ty {
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" | "isize" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" | "usize" => (0, u32::MAX.into()),
+        "u64" => (0, u64::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// Smallest and largest value a primitive integer type may hold on some
+/// target: `integer_range`, except that `isize` and `usize` may be 64 bits
+/// wide.
+pub fn integer_reach(ty: &str) -> Option<(i128, i128)> {
+    match ty {
+        "isize" => integer_range("i64"),
+        "usize" => integer_range("u64"),
+        _ => integer_range(ty),
+    }
+}
+
+/// Result types that must be bound and checked rather than dropped.
+pub fn is_must_use_type(canonical: &str) -> bool {
+    canonical.starts_with("core::types::Result<")
+        && canonical.ends_with(", core::memory::MemoryError>")
+}
+
 pub fn type_from_str(name: &str) -> Type {
     Type::Path(name.to_string())
 }
@@ -451,11 +1056,15 @@ pub fn known_type_names() -> &'static [&'static str] {
         "core::types::Option",
         "core::types::Result",
         "HighPtr",
+        "HighView",
         "ValidatedPtr",
         "RawPtr",
         "core::memory::safe::HighPtr",
+        "core::memory::safe::HighView",
         "core::memory::safe::ValidatedPtr",
         "core::memory::raw::RawPtr",
+        "MemoryError",
+        "core::memory::MemoryError",
     ]
 }
 
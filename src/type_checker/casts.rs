@@ -0,0 +1,152 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Integer conversions.
+//!
+//! Integer types never convert implicitly. `expr as T` converts only when
+//! every value of the source type fits in `T`; narrowing goes through
+//! `checked_cast`, `wrapping_cast` or `saturating_cast`, which say what
+//! happens to values that do not fit.
+
+use crate::ast::*;
+use crate::std_api::{self, integer_range, integer_reach};
+use std::collections::HashMap;
+
+use super::TypeChecker;
+
+const NARROWING_HELP: &str = "use checked_cast, wrapping_cast or saturating_cast";
+
+impl TypeChecker {
+    /// `expr as ty`.
+    pub(super) fn infer_cast(
+        &self,
+        expr: &Expression,
+        ty: &Type,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<Type, String> {
+        self.validate_type(ty)?;
+        Self::check_cast_target(ty)?;
+        let source = self.infer_expr(expr, scope, loop_depth, Some(ty))?;
+        if Self::is_integer_var(&self.resolve(&source)) {
+            // An integer literal simply takes the target type.
+            self.unify(ty, &source, &self.site("an 'as' cast"))?;
+            return Ok(ty.clone());
+        }
+        let source = self.resolve(&source);
+        if !Self::is_integer_type(&source) {
+            return Err(format!(
+                "Cannot cast {} to {}: only integers can be cast",
+                Self::type_display(&source),
+                Self::type_display(ty)
+            ));
+        }
+        if !Self::is_widening(&source, ty) {
+            return Err(format!(
+                "Narrowing cast from {} to {} may lose data; {NARROWING_HELP}",
+                Self::type_display(&source),
+                Self::type_display(ty)
+            ));
+        }
+        Ok(ty.clone())
+    }
+
+    /// `checked_cast::<T>(x)` and friends: `T` comes from the type argument.
+    pub(super) fn infer_cast_call(
+        &self,
+        call: &CallExpr,
+        ret: &Type,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<Type, String> {
+        let [target] = call.type_args.as_slice() else {
+            return Err(format!(
+                "'{}' takes one type argument, the target integer type, e.g. '{}::<u8>(x)'",
+                call.func_name,
+                call.func_name
+                    .rsplit("::")
+                    .next()
+                    .unwrap_or(&call.func_name)
+            ));
+        };
+        self.validate_type(target)?;
+        Self::check_cast_target(target)?;
+        let [arg] = call.args.as_slice() else {
+            return Err(format!(
+                "Arg count mismatch for '{}': expected 1, got {}",
+                call.func_name,
+                call.args.len()
+            ));
+        };
+
+        let source = self.infer_expr(arg, scope, loop_depth, None)?;
+        self.default_integer(
+            &source,
+            &self.site(&format!("argument 1 of '{}'", call.func_name)),
+        )?;
+        let source = self.resolve(&source);
+        if !Self::is_integer_type(&source) {
+            return Err(format!(
+                "Type Mismatch in arg 1 of '{}': expected an integer, got {}",
+                call.func_name,
+                Self::type_display(&source)
+            ));
+        }
+        let bindings = HashMap::from([("T".to_string(), Self::type_display(target))]);
+        Self::substitute_type_params(ret, &bindings)
+            .ok_or_else(|| format!("Cannot infer type parameters of '{}'", call.func_name))
+    }
+
+    /// Explains a mismatch: how to convert an integer `actual` to
+    /// `expected`, or why a closure does not fit a plain function type.
+    pub(super) fn conversion_hint(&self, expected: &Type, actual: &Type) -> String {
+        let actual = self.resolve(actual);
+        if let (Type::Fn { raw: false, .. }, Type::Fn { raw: true, .. }) = (expected, &actual) {
+            return " (a raw closure or function only fits a 'raw' function type)".to_string();
+        }
+        if let (Type::Fn { closure: false, .. }, Type::Fn { closure: true, .. }) =
+            (expected, &actual)
+        {
+            return " (a closure that captures bindings cannot be used as a plain 'fn')"
+                .to_string();
+        }
+        if !Self::is_integer_type(expected) || !Self::is_integer_type(&actual) {
+            return String::new();
+        }
+        if Self::is_widening(&actual, expected) {
+            format!(" (widen it with 'as {}')", Self::type_display(expected))
+        } else {
+            format!(" (narrowing may lose data; {NARROWING_HELP})")
+        }
+    }
+
+    fn check_cast_target(ty: &Type) -> Result<(), String> {
+        if Self::is_integer_type(ty) {
+            return Ok(());
+        }
+        Err(format!(
+            "Cast target must be an integer type, got {}",
+            Self::type_display(ty)
+        ))
+    }
+
+    /// Every value of `from` fits in `to`, whatever the width of `isize`
+    /// and `usize` on the target.
+    fn is_widening(from: &Type, to: &Type) -> bool {
+        let reach = integer_reach(&Self::type_display(from));
+        match (reach, integer_range(&Self::type_display(to))) {
+            (Some((from_lo, from_hi)), Some((to_lo, to_hi))) => {
+                to_lo <= from_lo && from_hi <= to_hi
+            }
+            _ => false,
+        }
+    }
+
+    pub(super) fn is_cast_call(call: &CallExpr) -> bool {
+        std_api::is_cast_function(&call.func_name)
+    }
+}
@@ -7,12 +7,22 @@
 
 use crate::ast::*;
 use crate::std_api;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 
+use super::literals::IntVars;
+
 pub struct TypeChecker {
     pub(super) functions: HashMap<String, (Vec<Type>, Option<Type>)>,
     pub(super) builtins: HashSet<String>,
+    /// User functions declared `raw fn`.
+    pub(super) raw_functions: HashSet<String>,
     pub(super) known_types: HashSet<String>,
+    pub(super) enums: HashMap<String, Enum>,
+    /// Integer literals of the function being checked.
+    pub(super) literals: RefCell<IntVars>,
+    /// Line of the statement or call being checked, 0 when unknown.
+    pub(super) line: Cell<usize>,
 }
 
 impl Default for TypeChecker {
@@ -41,7 +51,7 @@ impl TypeChecker {
             let arg_types = func
                 .args
                 .iter()
-                .map(|name| std_api::type_from_str(name))
+                .map(|name| Self::type_from_path(name))
                 .collect::<Vec<_>>();
             let ret_type = func.ret.map(std_api::type_from_str);
 
@@ -70,7 +80,11 @@ impl TypeChecker {
         Self {
             functions,
             builtins,
+            raw_functions: HashSet::new(),
             known_types,
+            enums: HashMap::new(),
+            literals: RefCell::default(),
+            line: Cell::new(0),
         }
     }
 
@@ -86,9 +100,30 @@ impl TypeChecker {
     }
 
     pub fn check(&mut self, source: &SourceFile) -> Result<(), String> {
+        self.declare(source)?;
+        for item in &source.items {
+            if let Item::Function(func) = item {
+                self.check_function(func)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers the types and function signatures of `source`, so that
+    /// `check_function` can check bodies one at a time.
+    pub fn declare(&mut self, source: &SourceFile) -> Result<(), String> {
         for item in &source.items {
-            if let Item::Struct(s) = item {
-                self.known_types.insert(s.name.clone());
+            match item {
+                Item::Struct(s) => {
+                    self.known_types.insert(s.name.clone());
+                }
+                Item::Enum(def) => self.declare_enum(def)?,
+                Item::Function(_) | Item::Alias(_) => {}
+            }
+        }
+        for item in &source.items {
+            if let Item::Enum(def) = item {
+                self.check_enum(def)?;
             }
         }
 
@@ -109,47 +144,52 @@ impl TypeChecker {
                 if let Some(ret) = &func.ret_type {
                     self.validate_type(ret)?;
                 }
+                if matches!(func.safety, SafetyLevel::Raw) {
+                    self.raw_functions.insert(func.name.clone());
+                }
                 let arg_types = func.args.iter().map(|arg| arg.ty.clone()).collect();
                 self.functions
                     .insert(func.name.clone(), (arg_types, func.ret_type.clone()));
             }
         }
-
-        for item in &source.items {
-            if let Item::Function(func) = item {
-                self.check_function(func)?;
-            }
-        }
         Ok(())
     }
 
-    fn check_function(&self, func: &Function) -> Result<(), String> {
+    /// Checks the body of `func` against the declared signatures.
+    pub fn check_function(&self, func: &Function) -> Result<(), String> {
+        if func.is_test && (!func.args.is_empty() || func.ret_type.is_some()) {
+            return Err(format!(
+                "Test function '{}' must take no arguments and return nothing",
+                func.name
+            ));
+        }
+
         let mut symbols = HashMap::new();
         for arg in &func.args {
+            self.check_fn_holder(&arg.name, &arg.ty)?;
             symbols.insert(arg.name.clone(), arg.ty.clone());
         }
-
-        self.check_block(&func.body, &mut symbols, 0)?;
-
-        let inferred_return = self.infer_block_expr(&func.body, &symbols)?;
         let expected_return = func
             .ret_type
             .clone()
             .unwrap_or_else(|| Type::Path("()".to_string()));
 
-        if !Self::types_equal(&inferred_return, &expected_return) {
-            if Self::is_compatible_integer_return(&func.body, &expected_return) {
-                return Ok(());
-            }
+        *self.literals.borrow_mut() = IntVars::default();
+        let inferred_return = self.infer_block(&func.body, &symbols, 0, Some(&expected_return))?;
+        self.check_results_handled(func)?;
 
+        self.line.set(0);
+        let site = format!("the return type of '{}'", func.name);
+        if !self.unify(&expected_return, &inferred_return, &site)? {
             return Err(format!(
-                "Return Type Mismatch in '{}': expected {}, got {}",
+                "Return Type Mismatch in '{}': expected {}, got {}{}",
                 func.name,
                 Self::type_display(&expected_return),
-                Self::type_display(&inferred_return)
+                self.display(&inferred_return),
+                self.conversion_hint(&expected_return, &inferred_return)
             ));
         }
 
-        Ok(())
+        self.literals.borrow_mut().finish()
     }
 }
@@ -0,0 +1,161 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Closures: `|a, b: T| body`.
+//!
+//! A closure captures the local bindings its body uses. A binding used only
+//! as `&name`, or called, is captured by reference; any other use moves it into the
+//! closure, which is accepted for `Copy` values only so the binding stays
+//! usable afterwards. A closure that captures nothing has a plain `fn` type.
+//! Capturing a `raw_` or `validated_` binding, or calling a raw operation
+//! outside an `unsafe` block of the body, makes it a raw closure, so
+//! `check_fn_holder` keeps it in a `raw_` binding inside `unsafe`.
+
+use crate::ast::*;
+use crate::std_api;
+use std::collections::HashMap;
+
+use super::TypeChecker;
+
+impl TypeChecker {
+    /// Type of a closure. Parameters without a type take theirs from
+    /// `expected`, the function type the context asks for.
+    pub(super) fn infer_closure(
+        &self,
+        closure: &Closure,
+        scope: &HashMap<String, Type>,
+        expected: Option<&Type>,
+    ) -> Result<Type, String> {
+        let expected_fn = expected.map(|ty| self.resolve(ty)).filter(
+            |ty| matches!(ty, Type::Fn { params, .. } if params.len() == closure.params.len()),
+        );
+        let expected = match &expected_fn {
+            Some(Type::Fn { params, ret, .. }) => Some((params.clone(), ret.clone())),
+            _ => None,
+        };
+
+        let mut body_scope = scope.clone();
+        let mut params = Vec::new();
+        for (i, param) in closure.params.iter().enumerate() {
+            let ty = match (&param.ty, &expected) {
+                (Some(ty), _) => {
+                    self.validate_type(ty)?;
+                    ty.clone()
+                }
+                (None, Some((expected_params, _))) => expected_params[i].clone(),
+                (None, None) => {
+                    return Err(format!(
+                        "Cannot infer the type of closure parameter '{}'; annotate it",
+                        param.name
+                    ));
+                }
+            };
+            self.check_fn_holder(&param.name, &ty)?;
+            body_scope.insert(param.name.clone(), ty.clone());
+            params.push(ty);
+        }
+
+        let mut raw = false;
+        let mut captures = false;
+        let mut raw_call = None;
+        for capture in closure.captures() {
+            let name = &capture.name;
+            let Some(ty) = scope.get(name) else {
+                if capture.outside_unsafe && self.is_raw_call_name(name) {
+                    raw = true;
+                    raw_call.get_or_insert_with(|| name.clone());
+                }
+                continue;
+            };
+            captures = true;
+            match capture.mode {
+                CaptureMode::RefMut => {
+                    return Err(format!(
+                        "Closures cannot capture '{name}' by mutable reference"
+                    ));
+                }
+                CaptureMode::Move if !self.is_copy(ty) => {
+                    return Err(format!(
+                        "Closure moves '{name}' of non-Copy type {}; capture it by reference with '&{name}'",
+                        self.display(ty)
+                    ));
+                }
+                CaptureMode::Ref | CaptureMode::Move => {}
+            }
+            raw |= name.starts_with("raw_")
+                || name.starts_with("validated_")
+                || Self::holds_raw_fn(&self.resolve(ty));
+        }
+        if let (Some(name), Some(expected @ Type::Fn { raw: false, .. })) =
+            (&raw_call, &expected_fn)
+        {
+            return Err(format!(
+                "Closure calls raw function '{name}' outside an unsafe block of its body, so it is a raw closure and cannot be used as {}; wrap the call in 'unsafe {{ }}'",
+                Self::type_display(expected)
+            ));
+        }
+
+        // `break` and `continue` cannot leave the closure.
+        let expected_ret = expected.as_ref().map(|(_, ret)| ret.as_ref());
+        let ret = self.infer_expr(&closure.body, &body_scope, 0, expected_ret)?;
+        let site = self.site("the result of a closure");
+        match expected_ret {
+            // A mismatch is reported by the caller against the whole type.
+            Some(expected_ret) => drop(self.unify(expected_ret, &ret, &site)?),
+            None => self.default_integer(&ret, &site)?,
+        }
+        Ok(Type::Fn {
+            raw,
+            closure: captures || raw,
+            params,
+            ret: Box::new(self.resolve(&ret)),
+        })
+    }
+
+    /// A name whose call is a raw operation.
+    fn is_raw_call_name(&self, name: &str) -> bool {
+        name.starts_with("raw_") || name.contains("::raw::") || self.raw_functions.contains(name)
+    }
+
+    /// Whether a value of `ty` is copied rather than moved.
+    fn is_copy(&self, ty: &Type) -> bool {
+        let ty = self.resolve(ty);
+        match &ty {
+            Type::Ref { mutable, .. } => !mutable,
+            Type::RawPtr(_) => true,
+            Type::Fn { closure, .. } => !closure,
+            Type::Tuple(elements) => elements.iter().all(|element| self.is_copy(element)),
+            Type::Path(_) if Self::is_integer_var(&ty) || Self::is_integer_type(&ty) => true,
+            Type::Path(name) => match Self::type_from_path(name) {
+                Type::Path(name) => Self::is_copy_path(&name),
+                other => self.is_copy(&other),
+            },
+        }
+    }
+
+    fn is_copy_path(name: &str) -> bool {
+        if Self::is_integer_type(&Type::Path(name.to_string())) {
+            return true;
+        }
+        if let Ok(Some((base, args))) = Self::parse_generic_type(name) {
+            let base = std_api::normalize_type_name(base);
+            return matches!(base.as_str(), "core::types::Option" | "core::types::Result")
+                && args.into_iter().all(Self::is_copy_path);
+        }
+        matches!(
+            std_api::normalize_type_name(name).as_str(),
+            "bool"
+                | "char"
+                | "()"
+                | "core::memory::safe::HighPtr"
+                | "core::memory::safe::HighView"
+                | "core::memory::safe::ValidatedPtr"
+                | "core::memory::raw::RawPtr"
+                | "core::memory::MemoryError"
+        )
+    }
+}
@@ -0,0 +1,257 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! User enums: variant constructors, `match` and `if let`.
+//!
+//! An enum value has the type `Type::Path(name)`. A `match` must cover every
+//! variant, either by name or with a trailing `_` arm, and every arm must
+//! be reachable. Its value is the value of the arm that runs, so all arms
+//! share one type.
+
+use crate::ast::*;
+use std::collections::HashMap;
+
+use super::TypeChecker;
+
+impl TypeChecker {
+    /// Registers `def` as a type; its payloads are checked by `check_enum`
+    /// once every type is known.
+    pub(super) fn declare_enum(&mut self, def: &Enum) -> Result<(), String> {
+        if self.known_types.contains(&def.name) {
+            return Err(format!("Type '{}' is already defined", def.name));
+        }
+        if def.variants.is_empty() {
+            return Err(format!("Enum '{}' has no variants", def.name));
+        }
+        for (index, variant) in def.variants.iter().enumerate() {
+            if def.variants[..index].iter().any(|v| v.name == variant.name) {
+                return Err(format!(
+                    "Duplicate variant '{}' in enum '{}'",
+                    variant.name, def.name
+                ));
+            }
+        }
+        self.known_types.insert(def.name.clone());
+        self.enums.insert(def.name.clone(), def.clone());
+        Ok(())
+    }
+
+    pub(super) fn check_enum(&self, def: &Enum) -> Result<(), String> {
+        for variant in &def.variants {
+            for ty in &variant.payload {
+                self.validate_type(ty)?;
+                let display = Self::type_display(ty);
+                if Self::path_idents(&display).any(|ident| ident == def.name) {
+                    return Err(format!(
+                        "Enum '{}' cannot contain itself (variant '{}')",
+                        def.name, variant.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The enum a value of type `ty` belongs to, if it is a user enum.
+    pub(super) fn enum_of(&self, ty: &Type) -> Option<&Enum> {
+        match self.resolve(ty) {
+            Type::Path(name) => self.enums.get(&name),
+            _ => None,
+        }
+    }
+
+    /// Payload types of `enum_name::variant`.
+    fn variant_payload(&self, enum_name: &str, variant: &str) -> Result<&[Type], String> {
+        let def = self
+            .enums
+            .get(enum_name)
+            .ok_or_else(|| format!("Unknown enum '{enum_name}' in '{enum_name}::{variant}'"))?;
+        def.variant(variant)
+            .map(|v| v.payload.as_slice())
+            .ok_or_else(|| format!("Enum '{enum_name}' has no variant '{variant}'"))
+    }
+
+    /// `Enum::Variant(args)`.
+    pub(super) fn infer_variant(
+        &self,
+        enum_name: &str,
+        variant: &str,
+        args: &[Expression],
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<Type, String> {
+        let payload = self.variant_payload(enum_name, variant)?;
+        if args.len() != payload.len() {
+            return Err(format!(
+                "'{enum_name}::{variant}' takes {} value(s), got {}",
+                payload.len(),
+                args.len()
+            ));
+        }
+        for (i, (arg, expected)) in args.iter().zip(payload).enumerate() {
+            let site = self.site(&format!("value {} of '{enum_name}::{variant}'", i + 1));
+            let inferred = self.infer_expr(arg, scope, loop_depth, Some(expected))?;
+            if !self.unify(expected, &inferred, &site)? {
+                return Err(format!(
+                    "Type Mismatch in value {} of '{enum_name}::{variant}': expected {}, got {}{}",
+                    i + 1,
+                    Self::type_display(expected),
+                    self.display(&inferred),
+                    self.conversion_hint(expected, &inferred)
+                ));
+            }
+        }
+        Ok(Type::Path(enum_name.to_string()))
+    }
+
+    /// Names and types bound by matching `pattern` against a value of `ty`.
+    pub(super) fn pattern_bindings(
+        &self,
+        pattern: &Pattern,
+        ty: &Type,
+    ) -> Result<Vec<(String, Type)>, String> {
+        let Pattern::Variant {
+            enum_name,
+            variant,
+            bindings,
+        } = pattern
+        else {
+            return Ok(Vec::new());
+        };
+        let payload = self.variant_payload(enum_name, variant)?;
+        if !Self::types_equal(&self.resolve(ty), &Type::Path(enum_name.clone())) {
+            return Err(format!(
+                "Pattern '{enum_name}::{variant}' does not match a value of type {}",
+                self.display(ty)
+            ));
+        }
+        if bindings.len() != payload.len() {
+            return Err(format!(
+                "Pattern '{enum_name}::{variant}' binds {} value(s), but the variant has {}",
+                bindings.len(),
+                payload.len()
+            ));
+        }
+        let bound: Vec<(String, Type)> = bindings
+            .iter()
+            .zip(payload)
+            .filter(|(name, _)| *name != "_")
+            .map(|(name, ty)| (name.clone(), ty.clone()))
+            .collect();
+        for (name, ty) in &bound {
+            self.check_fn_holder(name, ty)?;
+        }
+        Ok(bound)
+    }
+
+    /// `match value { ... }`: the type shared by its arms.
+    pub(super) fn infer_match(
+        &self,
+        value: &Expression,
+        arms: &[MatchArm],
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+        expected: Option<&Type>,
+    ) -> Result<Type, String> {
+        let value_ty = self.infer_expr(value, scope, loop_depth, None)?;
+        let def = self.enum_of(&value_ty).ok_or_else(|| {
+            format!(
+                "'match' needs an enum value, got {}",
+                self.display(&value_ty)
+            )
+        })?;
+        let mut result: Option<Type> = None;
+        for (index, arm) in arms.iter().enumerate() {
+            let mut arm_scope = scope.clone();
+            arm_scope.extend(self.pattern_bindings(&arm.pattern, &value_ty)?);
+            let expected = result.as_ref().or(expected);
+            let arm_ty = self.infer_block(&arm.body, &arm_scope, loop_depth, expected)?;
+            let Some(first) = &result else {
+                result = Some(arm_ty);
+                continue;
+            };
+            let site = self.site(&format!("arm {} of the match", index + 1));
+            if !self.unify(first, &arm_ty, &site)? {
+                return Err(format!(
+                    "Match arms have different types: {} vs {}",
+                    self.display(first),
+                    self.display(&arm_ty)
+                ));
+            }
+        }
+        self.check_coverage(def, arms)?;
+        Ok(result.unwrap_or_else(|| Type::Path("()".to_string())))
+    }
+
+    /// Every variant of `def` is matched, and every arm can run.
+    fn check_coverage(&self, def: &Enum, arms: &[MatchArm]) -> Result<(), String> {
+        let mut covered: Vec<&str> = Vec::new();
+        let mut wildcard = false;
+        for arm in arms {
+            let name = match &arm.pattern {
+                Pattern::Wildcard => "_".to_string(),
+                Pattern::Variant {
+                    enum_name, variant, ..
+                } => format!("{enum_name}::{variant}"),
+            };
+            let reachable = match &arm.pattern {
+                Pattern::Wildcard => !wildcard && covered.len() < def.variants.len(),
+                Pattern::Variant { variant, .. } => {
+                    !wildcard && !covered.contains(&variant.as_str())
+                }
+            };
+            if !reachable {
+                return Err(format!("Unreachable match arm '{name}'"));
+            }
+            match &arm.pattern {
+                Pattern::Wildcard => wildcard = true,
+                Pattern::Variant { variant, .. } => covered.push(variant),
+            }
+        }
+        if wildcard {
+            return Ok(());
+        }
+        let missing: Vec<String> = def
+            .variants
+            .iter()
+            .filter(|variant| !covered.contains(&variant.name.as_str()))
+            .map(|variant| format!("{}::{}", def.name, variant.name))
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Non-exhaustive match on {}: missing {}",
+                def.name,
+                missing.join(", ")
+            ))
+        }
+    }
+
+    /// `if let pattern = value { ... } else { ... }`.
+    pub(super) fn check_if_let(
+        &self,
+        stmt: &IfStatement,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<(), String> {
+        let Some(pattern) = &stmt.pattern else {
+            return Ok(());
+        };
+        if matches!(pattern, Pattern::Wildcard) {
+            return Err("'if let _' always matches; use the block directly".to_string());
+        }
+        let value_ty = self.infer_expr(&stmt.condition, scope, loop_depth, None)?;
+        let mut then_scope = scope.clone();
+        then_scope.extend(self.pattern_bindings(pattern, &value_ty)?);
+        self.check_block(&stmt.then_block, &then_scope, loop_depth)?;
+        if let Some(else_block) = &stmt.else_block {
+            self.check_block(else_block, scope, loop_depth)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Functions as values: references by name and indirect calls.
+//!
+//! A function name that is not shadowed by a binding has the type
+//! `fn(A, ...) -> R`, or `raw fn(...)` for a `raw fn`. A safe function may be
+//! passed where a raw one is expected, never the reverse. A raw function
+//! value may only be held by a `raw_` binding, so calling it is a raw
+//! operation by name and Phase 3 keeps the call inside `unsafe`.
+
+use crate::ast::*;
+use std::collections::HashMap;
+
+use super::TypeChecker;
+
+impl TypeChecker {
+    /// Type of the function `name` used as a value.
+    pub(super) fn function_ref_type(&self, name: &str) -> Result<Type, String> {
+        if self.builtins.contains(name) {
+            return Err(format!(
+                "Builtin function '{name}' cannot be used as a value; wrap it in a function"
+            ));
+        }
+        let (args, ret) = self
+            .functions
+            .get(name)
+            .ok_or_else(|| format!("Undefined variable: '{name}'"))?;
+        Ok(Self::fn_type(
+            self.raw_functions.contains(name),
+            args,
+            ret.as_ref(),
+        ))
+    }
+
+    /// `name(args)` where `name` is a binding of type `callee`.
+    pub(super) fn infer_indirect_call(
+        &self,
+        call: &CallExpr,
+        callee: &Type,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<Type, String> {
+        let name = &call.func_name;
+        let Type::Fn { params, ret, .. } = self.resolve(callee) else {
+            return Err(format!(
+                "'{name}' is not a function: it has type {}",
+                self.display(callee)
+            ));
+        };
+        if !call.type_args.is_empty() {
+            return Err(format!("'{name}' does not take type arguments"));
+        }
+        if call.args.len() != params.len() {
+            return Err(format!(
+                "Arg count mismatch for '{name}': expected {}, got {}",
+                params.len(),
+                call.args.len()
+            ));
+        }
+        for (i, (arg, param)) in call.args.iter().zip(&params).enumerate() {
+            let site = self.site(&format!("argument {} of '{name}'", i + 1));
+            let inferred = self.infer_expr(arg, scope, loop_depth, Some(param))?;
+            if !self.unify(param, &inferred, &site)? {
+                return Err(format!(
+                    "Type Mismatch in arg {} of '{name}': expected {}, got {}{}",
+                    i + 1,
+                    Self::type_display(param),
+                    self.display(&inferred),
+                    self.conversion_hint(param, &inferred)
+                ));
+            }
+        }
+        Ok(*ret)
+    }
+
+    /// Rejects binding a raw function value to a name other than `raw_*`.
+    pub(super) fn check_fn_holder(&self, name: &str, ty: &Type) -> Result<(), String> {
+        let ty = self.resolve(ty);
+        if name.starts_with("raw_") || !Self::holds_raw_fn(&ty) {
+            return Ok(());
+        }
+        Err(format!(
+            "'{name}' cannot hold a raw function ({}); bind it to a 'raw_' name inside unsafe",
+            Self::type_display(&ty)
+        ))
+    }
+
+    pub(super) fn holds_raw_fn(ty: &Type) -> bool {
+        match ty {
+            Type::Fn { raw, .. } => *raw,
+            Type::Ref { inner, .. } => Self::holds_raw_fn(inner),
+            Type::Tuple(elements) => elements.iter().any(Self::holds_raw_fn),
+            Type::Path(name) => name.contains("raw fn(") || name.contains("raw closure("),
+            Type::RawPtr(_) => false,
+        }
+    }
+}
@@ -11,318 +11,351 @@ use std::collections::HashMap;
 
 use super::TypeChecker;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum LiteralKind {
-    Integer,
-    String,
-}
-
 impl TypeChecker {
     pub(super) fn check_block(
         &self,
         block: &Block,
-        symbols: &mut HashMap<String, Type>,
+        scope: &HashMap<String, Type>,
         loop_depth: usize,
     ) -> Result<(), String> {
-        let mut scope = symbols.clone();
+        self.infer_block(block, scope, loop_depth, None).map(drop)
+    }
 
-        for stmt in &block.statements {
-            match stmt {
-                Statement::Let(l) => {
-                    let rhs_type =
-                        self.check_binding(&l.name, &l.ty, &l.value, &scope, loop_depth)?;
-                    scope.insert(l.name.clone(), rhs_type);
-                }
-                Statement::Const(c) => {
-                    let rhs_type =
-                        self.check_binding(&c.name, &c.ty, &c.value, &scope, loop_depth)?;
-                    scope.insert(c.name.clone(), rhs_type);
-                }
-                Statement::If(stmt) => {
-                    let cond_ty = self.infer_expr_with_loop(&stmt.condition, &scope, loop_depth)?;
-                    if !Self::types_equal(&cond_ty, &Type::Path("bool".to_string())) {
+    /// Checks a block in its own scope and returns the type of its tail
+    /// expression, `()` if it has none. `expected` is passed on to the tail.
+    pub(super) fn infer_block(
+        &self,
+        block: &Block,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
+        expected: Option<&Type>,
+    ) -> Result<Type, String> {
+        let mut block_scope = scope.clone();
+        let Some((last, init)) = block.statements.split_last() else {
+            return Ok(Type::Path("()".to_string()));
+        };
+        for stmt in init {
+            self.check_statement(stmt, &mut block_scope, loop_depth)?;
+        }
+        match last {
+            Statement::Expr(e) => {
+                self.line.set(0);
+                self.infer_expr(e, &block_scope, loop_depth, expected)
+            }
+            _ => {
+                self.check_statement(last, &mut block_scope, loop_depth)?;
+                Ok(Type::Path("()".to_string()))
+            }
+        }
+    }
+
+    fn check_statement(
+        &self,
+        stmt: &Statement,
+        scope: &mut HashMap<String, Type>,
+        loop_depth: usize,
+    ) -> Result<(), String> {
+        self.line.set(0);
+        match stmt {
+            Statement::Let(l) => {
+                let ty = self.check_binding(&l.name, &l.ty, &l.value, l.line, scope, loop_depth)?;
+                self.check_fn_holder(&l.name, &ty)?;
+                scope.insert(l.name.clone(), ty);
+            }
+            Statement::LetTuple(l) => {
+                let what = format!("({})", l.names.join(", "));
+                let ty = self.check_binding(&what, &l.ty, &l.value, l.line, scope, loop_depth)?;
+                let elements = match self.tuple_elements_of(&ty) {
+                    Some(elements) if elements.len() == l.names.len() => elements,
+                    _ => {
                         return Err(format!(
-                            "If condition must be bool, got {}",
-                            Self::type_display(&cond_ty)
+                            "Cannot destructure {} into {} names",
+                            self.display(&ty),
+                            l.names.len()
                         ));
                     }
-                    let mut then_scope = scope.clone();
-                    self.check_block(&stmt.then_block, &mut then_scope, loop_depth)?;
-                    if let Some(else_block) = &stmt.else_block {
-                        let mut else_scope = scope.clone();
-                        self.check_block(else_block, &mut else_scope, loop_depth)?;
-                    }
+                };
+                for (name, ty) in l.names.iter().zip(elements) {
+                    self.check_fn_holder(name, &ty)?;
+                    scope.insert(name.clone(), ty);
                 }
-                Statement::For(stmt) => {
-                    let start_ty = self.infer_expr_with_loop(&stmt.start, &scope, loop_depth)?;
-                    let end_ty = self.infer_expr_with_loop(&stmt.end, &scope, loop_depth)?;
-                    let loop_var_ty =
-                        Self::infer_for_loop_var_type(&stmt.start, &start_ty, &stmt.end, &end_ty)?;
-                    let mut loop_scope = scope.clone();
-                    loop_scope.insert(stmt.var_name.clone(), loop_var_ty);
-                    self.check_block(&stmt.body, &mut loop_scope, loop_depth + 1)?;
+            }
+            Statement::Const(c) => {
+                let ty = self.check_binding(&c.name, &c.ty, &c.value, c.line, scope, loop_depth)?;
+                self.check_fn_holder(&c.name, &ty)?;
+                scope.insert(c.name.clone(), ty);
+            }
+            Statement::If(stmt) if stmt.pattern.is_some() => {
+                self.check_if_let(stmt, scope, loop_depth)?;
+            }
+            Statement::If(stmt) => {
+                let bool_ty = Type::Path("bool".to_string());
+                let cond_ty =
+                    self.infer_expr(&stmt.condition, scope, loop_depth, Some(&bool_ty))?;
+                if !self.unify(&bool_ty, &cond_ty, "an if condition")? {
+                    return Err(format!(
+                        "If condition must be bool, got {}",
+                        self.display(&cond_ty)
+                    ));
                 }
-                Statement::Break | Statement::Continue => {
-                    if loop_depth == 0 {
-                        return Err("break/continue can only be used inside for-loops".to_string());
-                    }
+                self.check_block(&stmt.then_block, scope, loop_depth)?;
+                if let Some(else_block) = &stmt.else_block {
+                    self.check_block(else_block, scope, loop_depth)?;
                 }
-                Statement::Expr(e) => {
-                    self.infer_expr_with_loop(e, &scope, loop_depth)?;
+            }
+            Statement::For(stmt) => {
+                let loop_var_ty = self.infer_for_range(stmt, scope, loop_depth)?;
+                let mut loop_scope = scope.clone();
+                loop_scope.insert(stmt.var_name.clone(), loop_var_ty);
+                self.check_block(&stmt.body, &loop_scope, loop_depth + 1)?;
+            }
+            Statement::Break | Statement::Continue => {
+                if loop_depth == 0 {
+                    return Err("break/continue can only be used inside for-loops".to_string());
                 }
             }
+            Statement::Expr(e) => {
+                self.infer_expr(e, scope, loop_depth, None)?;
+            }
         }
         Ok(())
     }
 
-    fn infer_expr_with_loop(
+    /// Infers the type of `expr`. `expected` is the type the context asks
+    /// for; it decides the type of integer literals and is otherwise only a
+    /// hint, so callers still check the result.
+    pub(super) fn infer_expr(
         &self,
         expr: &Expression,
         scope: &HashMap<String, Type>,
         loop_depth: usize,
+        expected: Option<&Type>,
     ) -> Result<Type, String> {
         match expr {
             Expression::Literal(lit) => match lit {
-                Literal::Integer(_) => Ok(Type::Path("i32".to_string())),
+                Literal::Integer(value) => self.integer_literal_type(*value, expected),
                 Literal::String(_) => Ok(Type::Path("String".to_string())),
                 Literal::Bool(_) => Ok(Type::Path("bool".to_string())),
             },
-            Expression::Variable(name) => scope
-                .get(name)
-                .cloned()
-                .ok_or_else(|| format!("Undefined variable: '{name}'")),
+            Expression::Variable(name) => match scope.get(name) {
+                Some(ty) => Ok(ty.clone()),
+                None => self.function_ref_type(name),
+            },
             Expression::Binary { op, left, right } => {
-                let left_ty = self.infer_expr_with_loop(left, scope, loop_depth)?;
-                let right_ty = self.infer_expr_with_loop(right, scope, loop_depth)?;
-                match op {
-                    BinaryOp::Equal | BinaryOp::NotEqual => {
-                        if Self::types_equal(&left_ty, &right_ty)
-                            || (Self::is_compatible_int_target(&left_ty)
-                                && Self::is_compatible_int_target(&right_ty))
-                        {
-                            Ok(Type::Path("bool".to_string()))
-                        } else {
-                            Err(format!(
-                                "Comparison type mismatch: {} vs {}",
-                                Self::type_display(&left_ty),
-                                Self::type_display(&right_ty)
-                            ))
-                        }
-                    }
-                    BinaryOp::LessThan
-                    | BinaryOp::LessEqual
-                    | BinaryOp::GreaterThan
-                    | BinaryOp::GreaterEqual => {
-                        if Self::is_compatible_int_target(&left_ty)
-                            && Self::is_compatible_int_target(&right_ty)
-                        {
-                            Ok(Type::Path("bool".to_string()))
-                        } else {
-                            Err(format!(
-                                "Ordered comparison requires integer operands: {} and {}",
-                                Self::type_display(&left_ty),
-                                Self::type_display(&right_ty)
-                            ))
-                        }
-                    }
+                let left_ty = self.infer_expr(left, scope, loop_depth, None)?;
+                let right_ty = self.infer_expr(right, scope, loop_depth, Some(&left_ty))?;
+                if let Some(def) = self.enum_of(&left_ty) {
+                    return Err(format!(
+                        "Values of enum '{}' cannot be compared; use 'match' or 'if let'",
+                        def.name
+                    ));
+                }
+                if let Type::Fn { .. } = self.resolve(&left_ty) {
+                    return Err(format!(
+                        "Function values cannot be compared: {}",
+                        self.display(&left_ty)
+                    ));
+                }
+                let ordered = !matches!(op, BinaryOp::Equal | BinaryOp::NotEqual);
+                if ordered && (!self.is_integer(&left_ty) || !self.is_integer(&right_ty)) {
+                    return Err(format!(
+                        "Ordered comparison requires integer operands: {} and {}",
+                        self.display(&left_ty),
+                        self.display(&right_ty)
+                    ));
+                }
+                if !self.unify(&left_ty, &right_ty, &self.site("a comparison"))? {
+                    return Err(format!(
+                        "Comparison type mismatch: {} vs {}",
+                        self.display(&left_ty),
+                        self.display(&right_ty)
+                    ));
                 }
+                Ok(Type::Path("bool".to_string()))
             }
             Expression::Ref { mutable, expr } => {
-                let inner_ty = self.infer_expr_with_loop(expr, scope, loop_depth)?;
+                let expected_inner = match expected {
+                    Some(Type::Ref { inner, .. }) => Some(inner.as_ref()),
+                    _ => None,
+                };
+                let inner_ty = self.infer_expr(expr, scope, loop_depth, expected_inner)?;
                 Ok(Type::Ref {
                     mutable: *mutable,
                     inner: Box::new(inner_ty),
                 })
             }
             Expression::Call(call) => {
-                if std_api::is_print_function(&call.func_name)
-                    || std_api::is_printl_function(&call.func_name)
-                {
-                    for arg in &call.args {
-                        let inferred = self.infer_expr_with_loop(arg, scope, loop_depth)?;
-                        if !Self::is_printable_type(&inferred) {
-                            return Err(format!(
-                                "print/printl does not support type {}",
-                                Self::type_display(&inferred)
-                            ));
-                        }
-                    }
-                    return Ok(Type::Path("()".to_string()));
-                }
-
-                let (arg_types, ret_type) = self
-                    .functions
-                    .get(&call.func_name)
-                    .ok_or_else(|| format!("Undefined function: '{}'", call.func_name))?;
-
-                if call.args.len() != arg_types.len() {
-                    return Err(format!(
-                        "Arg count mismatch for '{}': expected {}, got {}",
-                        call.func_name,
-                        arg_types.len(),
-                        call.args.len()
-                    ));
-                }
-
-                for (i, arg_expr) in call.args.iter().enumerate() {
-                    let inferred = self.infer_expr_with_loop(arg_expr, scope, loop_depth)?;
-
-                    if let Some(LiteralKind::Integer) = Self::literal_kind(arg_expr) {
-                        if Self::is_compatible_int_target(&arg_types[i]) {
-                            continue;
-                        }
-                    }
-
-                    if !Self::types_equal(&inferred, &arg_types[i]) {
-                        return Err(format!(
-                            "Type Mismatch in arg {} of '{}': expected {}, got {}",
-                            i + 1,
-                            call.func_name,
-                            Self::type_display(&arg_types[i]),
-                            Self::type_display(&inferred)
-                        ));
+                let outer_line = self.line.replace(call.line);
+                let result = self.infer_call(call, scope, loop_depth);
+                self.line.set(outer_line);
+                result
+            }
+            Expression::Block(b) => self.infer_block(b, scope, loop_depth, expected),
+            Expression::Cast { expr, ty } => self.infer_cast(expr, ty, scope, loop_depth),
+            Expression::Tuple(elements) => {
+                let expected = match expected {
+                    Some(Type::Tuple(types)) if types.len() == elements.len() => Some(types),
+                    _ => None,
+                };
+                let types = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| {
+                        let expected = expected.map(|types| &types[i]);
+                        self.infer_expr(element, scope, loop_depth, expected)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(Type::Tuple(types))
+            }
+            Expression::Variant {
+                enum_name,
+                variant,
+                args,
+            } => self.infer_variant(enum_name, variant, args, scope, loop_depth),
+            Expression::Match { value, arms } => {
+                self.infer_match(value, arms, scope, loop_depth, expected)
+            }
+            Expression::Closure(closure) => self.infer_closure(closure, scope, expected),
+            Expression::Field { expr, index } => {
+                let ty = self.infer_expr(expr, scope, loop_depth, None)?;
+                match self.tuple_elements_of(&ty) {
+                    Some(mut elements) if *index < elements.len() => {
+                        Ok(elements.swap_remove(*index))
                     }
+                    Some(elements) => Err(format!(
+                        "Tuple index {index} out of range: the tuple has {} elements",
+                        elements.len()
+                    )),
+                    None => Err(format!(
+                        "Field '.{index}' needs a tuple, got {}",
+                        self.display(&ty)
+                    )),
                 }
-
-                Ok(ret_type
-                    .clone()
-                    .unwrap_or_else(|| Type::Path("()".to_string())))
             }
-            Expression::Block(b) => self.infer_block_expr_with_loop(b, scope, loop_depth),
         }
     }
 
-    pub(super) fn infer_block_expr(
+    fn infer_call(
         &self,
-        block: &Block,
-        scope: &HashMap<String, Type>,
-    ) -> Result<Type, String> {
-        self.infer_block_expr_with_loop(block, scope, 0)
-    }
-
-    fn infer_block_expr_with_loop(
-        &self,
-        block: &Block,
+        call: &CallExpr,
         scope: &HashMap<String, Type>,
         loop_depth: usize,
     ) -> Result<Type, String> {
-        let mut block_scope = scope.clone();
-
-        for stmt in &block.statements[..block.statements.len().saturating_sub(1)] {
-            match stmt {
-                Statement::Let(l) => {
-                    let rhs_type =
-                        self.check_binding(&l.name, &l.ty, &l.value, &block_scope, loop_depth)?;
-                    block_scope.insert(l.name.clone(), rhs_type);
-                }
-                Statement::Const(c) => {
-                    let rhs_type =
-                        self.check_binding(&c.name, &c.ty, &c.value, &block_scope, loop_depth)?;
-                    block_scope.insert(c.name.clone(), rhs_type);
-                }
-                Statement::If(stmt) => {
-                    let cond_ty =
-                        self.infer_expr_with_loop(&stmt.condition, &block_scope, loop_depth)?;
-                    if !Self::types_equal(&cond_ty, &Type::Path("bool".to_string())) {
-                        return Err(format!(
-                            "If condition must be bool, got {}",
-                            Self::type_display(&cond_ty)
-                        ));
-                    }
-                    let _ = self.infer_block_expr_with_loop(
-                        &stmt.then_block,
-                        &block_scope,
-                        loop_depth,
-                    )?;
-                    if let Some(else_block) = &stmt.else_block {
-                        let _ =
-                            self.infer_block_expr_with_loop(else_block, &block_scope, loop_depth)?;
-                    }
-                }
-                Statement::For(stmt) => {
-                    let start_ty =
-                        self.infer_expr_with_loop(&stmt.start, &block_scope, loop_depth)?;
-                    let end_ty = self.infer_expr_with_loop(&stmt.end, &block_scope, loop_depth)?;
-                    let loop_var_ty =
-                        Self::infer_for_loop_var_type(&stmt.start, &start_ty, &stmt.end, &end_ty)?;
-                    let mut loop_scope = block_scope.clone();
-                    loop_scope.insert(stmt.var_name.clone(), loop_var_ty);
-                    self.check_block(&stmt.body, &mut loop_scope, loop_depth + 1)?;
-                }
-                Statement::Break | Statement::Continue => {
-                    if loop_depth == 0 {
-                        return Err("break/continue can only be used inside for-loops".to_string());
-                    }
-                }
-                Statement::Expr(e) => {
-                    self.infer_expr_with_loop(e, &block_scope, loop_depth)?;
+        if let Some(callee) = scope.get(&call.func_name) {
+            return self.infer_indirect_call(call, callee, scope, loop_depth);
+        }
+        if std_api::is_print_function(&call.func_name)
+            || std_api::is_printl_function(&call.func_name)
+        {
+            for arg in &call.args {
+                let inferred = self.resolve(&self.infer_expr(arg, scope, loop_depth, None)?);
+                if !Self::is_printable_type(&inferred) && !Self::is_integer_var(&inferred) {
+                    return Err(format!(
+                        "print/printl does not support type {}",
+                        Self::type_display(&inferred)
+                    ));
                 }
             }
+            return Ok(Type::Path("()".to_string()));
+        }
+
+        let (arg_types, ret_type) = self
+            .functions
+            .get(&call.func_name)
+            .ok_or_else(|| format!("Undefined function: '{}'", call.func_name))?;
+        if Self::is_cast_call(call) {
+            let ret = ret_type.as_ref().expect("cast functions return a value");
+            return self.infer_cast_call(call, ret, scope, loop_depth);
+        }
+        if !call.type_args.is_empty() {
+            return Err(format!("'{}' does not take type arguments", call.func_name));
+        }
+
+        if call.args.len() != arg_types.len() {
+            return Err(format!(
+                "Arg count mismatch for '{}': expected {}, got {}",
+                call.func_name,
+                arg_types.len(),
+                call.args.len()
+            ));
         }
 
-        if let Some(last_stmt) = block.statements.last() {
-            match last_stmt {
-                Statement::Let(l) => {
-                    let _ =
-                        self.check_binding(&l.name, &l.ty, &l.value, &block_scope, loop_depth)?;
-                    Ok(Type::Path("()".to_string()))
+        let mismatch = |i: usize, expected: &Type, got: String| {
+            format!(
+                "Type Mismatch in arg {} of '{}': expected {}, got {}",
+                i + 1,
+                call.func_name,
+                Self::type_display(expected),
+                got
+            )
+        };
+        let mut type_params = HashMap::new();
+        // `assert_eq(len(xs), 3)`: a literal passed as a bare type parameter
+        // takes the type the other arguments give it.
+        let mut literal_params = Vec::new();
+        for (i, (arg_expr, param)) in call.args.iter().zip(arg_types).enumerate() {
+            let site = self.site(&format!("argument {} of '{}'", i + 1, call.func_name));
+            if !Self::has_type_params(param) {
+                let inferred = self.infer_expr(arg_expr, scope, loop_depth, Some(param))?;
+                if !self.unify(param, &inferred, &site)? {
+                    let got = self.display(&inferred) + &self.conversion_hint(param, &inferred);
+                    return Err(mismatch(i, param, got));
                 }
-                Statement::Const(c) => {
-                    let _ =
-                        self.check_binding(&c.name, &c.ty, &c.value, &block_scope, loop_depth)?;
-                    Ok(Type::Path("()".to_string()))
+                continue;
+            }
+
+            let inferred = self.resolve(&self.infer_expr(arg_expr, scope, loop_depth, None)?);
+            if let Type::Path(name) = param
+                && std_api::is_type_param(name)
+                && Self::is_integer_var(&inferred)
+            {
+                literal_params.push((i, name.clone(), inferred, site));
+            } else {
+                // Literals inside a tuple cannot stay open in a type parameter.
+                self.default_integer(&inferred, &site)?;
+                let inferred = self.resolve(&inferred);
+                if !Self::bind_type_params(param, &inferred, &mut type_params) {
+                    return Err(mismatch(i, param, self.display(&inferred)));
                 }
-                Statement::If(stmt) => {
-                    let cond_ty =
-                        self.infer_expr_with_loop(&stmt.condition, &block_scope, loop_depth)?;
-                    if !Self::types_equal(&cond_ty, &Type::Path("bool".to_string())) {
-                        return Err(format!(
-                            "If condition must be bool, got {}",
-                            Self::type_display(&cond_ty)
-                        ));
-                    }
-                    let _ = self.infer_block_expr_with_loop(
-                        &stmt.then_block,
-                        &block_scope,
-                        loop_depth,
-                    )?;
-                    if let Some(else_block) = &stmt.else_block {
-                        let _ =
-                            self.infer_block_expr_with_loop(else_block, &block_scope, loop_depth)?;
+            }
+        }
+        for (i, name, var, site) in literal_params {
+            match type_params.get(&name) {
+                Some(bound) => {
+                    let bound = Type::Path(bound.clone());
+                    if !self.unify(&bound, &var, &site)? {
+                        return Err(mismatch(i, &bound, self.display(&var)));
                     }
-                    Ok(Type::Path("()".to_string()))
-                }
-                Statement::For(stmt) => {
-                    let start_ty =
-                        self.infer_expr_with_loop(&stmt.start, &block_scope, loop_depth)?;
-                    let end_ty = self.infer_expr_with_loop(&stmt.end, &block_scope, loop_depth)?;
-                    let loop_var_ty =
-                        Self::infer_for_loop_var_type(&stmt.start, &start_ty, &stmt.end, &end_ty)?;
-                    let mut loop_scope = block_scope.clone();
-                    loop_scope.insert(stmt.var_name.clone(), loop_var_ty);
-                    self.check_block(&stmt.body, &mut loop_scope, loop_depth + 1)?;
-                    Ok(Type::Path("()".to_string()))
                 }
-                Statement::Break | Statement::Continue => {
-                    if loop_depth == 0 {
-                        Err("break/continue can only be used inside for-loops".to_string())
-                    } else {
-                        Ok(Type::Path("()".to_string()))
+                None => {
+                    if let Type::Path(var) = var {
+                        type_params.insert(name, var);
                     }
                 }
-                Statement::Expr(e) => self.infer_expr_with_loop(e, &block_scope, loop_depth),
             }
-        } else {
-            Ok(Type::Path("()".to_string()))
         }
-    }
 
-    fn literal_kind(expr: &Expression) -> Option<LiteralKind> {
-        match expr {
-            Expression::Literal(Literal::Integer(_)) => Some(LiteralKind::Integer),
-            Expression::Literal(Literal::String(_)) => Some(LiteralKind::String),
-            _ => None,
+        let Some(ret) = ret_type else {
+            return Ok(Type::Path("()".to_string()));
+        };
+        if !Self::has_type_params(ret) {
+            return Ok(ret.clone());
+        }
+        if !matches!(ret, Type::Path(name) if std_api::is_type_param(name)) {
+            // An open literal cannot be placed inside a generic type.
+            let site = self.site(&format!("the result of '{}'", call.func_name));
+            for bound in type_params.values_mut() {
+                let var = Type::Path(bound.clone());
+                self.default_integer(&var, &site)?;
+                *bound = Self::type_display(&self.resolve(&var));
+            }
         }
+        Self::substitute_type_params(ret, &type_params).ok_or_else(|| {
+            format!(
+                "Cannot infer type parameters of '{}' from its arguments",
+                call.func_name
+            )
+        })
     }
 
     fn check_binding(
@@ -330,64 +363,77 @@ impl TypeChecker {
         name: &str,
         ann: &Option<Type>,
         value: &Expression,
+        line: usize,
         scope: &HashMap<String, Type>,
         loop_depth: usize,
     ) -> Result<Type, String> {
-        let rhs_type = self.infer_expr_with_loop(value, scope, loop_depth)?;
-        if let Some(ann) = ann {
-            self.validate_type(ann)?;
-            if !Self::types_equal(ann, &rhs_type) {
-                return Err(format!(
-                    "Type Mismatch: Variable '{}' declared as {} but assigned {}",
-                    name,
-                    Self::type_display(ann),
-                    Self::type_display(&rhs_type)
-                ));
-            }
+        self.line.set(line);
+        let Some(ann) = ann else {
+            return self.infer_expr(value, scope, loop_depth, None);
+        };
+        self.validate_type(ann)?;
+        let rhs_type = self.infer_expr(value, scope, loop_depth, Some(ann))?;
+        if !self.unify(ann, &rhs_type, &self.site(&format!("the type of '{name}'")))? {
+            return Err(format!(
+                "Type Mismatch: Variable '{}' declared as {} but assigned {}{}",
+                name,
+                Self::type_display(ann),
+                self.display(&rhs_type),
+                self.conversion_hint(ann, &rhs_type)
+            ));
         }
-        Ok(rhs_type)
+        Ok(ann.clone())
     }
 
-    fn infer_for_loop_var_type(
-        start_expr: &Expression,
-        start_ty: &Type,
-        end_expr: &Expression,
-        end_ty: &Type,
+    /// Type of the loop variable: both bounds share one integer type.
+    fn infer_for_range(
+        &self,
+        stmt: &ForStatement,
+        scope: &HashMap<String, Type>,
+        loop_depth: usize,
     ) -> Result<Type, String> {
-        if !Self::is_compatible_int_target(start_ty) || !Self::is_compatible_int_target(end_ty) {
+        let start_ty = self.infer_expr(&stmt.start, scope, loop_depth, None)?;
+        let end_ty = self.infer_expr(&stmt.end, scope, loop_depth, Some(&start_ty))?;
+        if !self.is_integer(&start_ty) || !self.is_integer(&end_ty) {
             return Err(format!(
                 "For range bounds must be integers, got {} and {}",
-                Self::type_display(start_ty),
-                Self::type_display(end_ty)
+                self.display(&start_ty),
+                self.display(&end_ty)
             ));
         }
-
-        if Self::types_equal(start_ty, end_ty) {
-            return Ok(start_ty.clone());
+        let site = format!("the range of 'for {}'", stmt.var_name);
+        if !self.unify(&start_ty, &end_ty, &site)? {
+            return Err(format!(
+                "For range type mismatch: {} vs {}. Use matching integer types or integer literals.",
+                self.display(&start_ty),
+                self.display(&end_ty)
+            ));
         }
+        Ok(start_ty)
+    }
 
-        let start_is_int_literal =
-            matches!(Self::literal_kind(start_expr), Some(LiteralKind::Integer));
-        let end_is_int_literal = matches!(Self::literal_kind(end_expr), Some(LiteralKind::Integer));
-
-        if start_is_int_literal {
-            return Ok(end_ty.clone());
-        }
-        if end_is_int_literal {
-            return Ok(start_ty.clone());
+    /// Element types of a tuple type, also one spelled as a path.
+    fn tuple_elements_of(&self, ty: &Type) -> Option<Vec<Type>> {
+        match self.resolve(ty) {
+            Type::Tuple(elements) => Some(elements),
+            Type::Path(name) => match Self::type_from_path(&name) {
+                Type::Tuple(elements) => Some(elements),
+                _ => None,
+            },
+            _ => None,
         }
+    }
 
-        Err(format!(
-            "For range type mismatch: {} vs {}. Use matching integer types or integer literals.",
-            Self::type_display(start_ty),
-            Self::type_display(end_ty)
-        ))
+    /// An integer type or an integer literal whose type is still open.
+    fn is_integer(&self, ty: &Type) -> bool {
+        let ty = self.resolve(ty);
+        Self::is_integer_type(&ty) || Self::is_integer_var(&ty)
     }
 
     fn is_printable_type(ty: &Type) -> bool {
         match ty {
             Type::Ref { inner, .. } => Self::is_printable_type(inner),
-            Type::RawPtr(_) => false,
+            Type::RawPtr(_) | Type::Tuple(_) | Type::Fn { .. } => false,
             Type::Path(name) => matches!(
                 name.as_str(),
                 "String"
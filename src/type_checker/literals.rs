@@ -0,0 +1,302 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Integer literals whose type is decided by how they are used.
+//!
+//! A literal checked against a known integer type simply takes that type.
+//! Otherwise it gets an inference variable, shown as `{integer}`: the first
+//! use that expects a concrete integer type fixes it, and using two variables
+//! together merges them. Every use is recorded as a constraint site, so a
+//! later conflicting use can name both. Variables still open when a function
+//! has been checked default to `i32`.
+
+use crate::ast::Type;
+use crate::std_api::integer_range;
+
+use super::TypeChecker;
+
+const VAR_PREFIX: &str = "{integer#";
+const DEFAULT_INTEGER: &str = "i32";
+
+struct IntVar {
+    /// Where the first literal of this variable appears: " at line N", or
+    /// empty when the line is unknown.
+    origin: String,
+    /// Smallest and largest literal flowing into the variable.
+    min: i64,
+    max: i64,
+    parent: Option<usize>,
+    /// Fixed type and the site that fixed it.
+    bound: Option<(String, String)>,
+}
+
+/// Inference variables of the function being checked.
+#[derive(Default)]
+pub(super) struct IntVars {
+    vars: Vec<IntVar>,
+}
+
+impl IntVars {
+    pub(super) fn fresh(&mut self, value: i64, origin: String) -> Type {
+        self.vars.push(IntVar {
+            origin,
+            min: value,
+            max: value,
+            parent: None,
+            bound: None,
+        });
+        Type::Path(format!("{VAR_PREFIX}{}}}", self.vars.len() - 1))
+    }
+
+    pub(super) fn id(ty: &Type) -> Option<usize> {
+        let Type::Path(name) = ty else {
+            return None;
+        };
+        name.strip_prefix(VAR_PREFIX)?
+            .strip_suffix('}')?
+            .parse()
+            .ok()
+    }
+
+    fn root(&self, mut id: usize) -> usize {
+        while let Some(parent) = self.vars[id].parent {
+            id = parent;
+        }
+        id
+    }
+
+    /// The fixed type of a variable, or `None` while it is open.
+    pub(super) fn bound_type(&self, id: usize) -> Option<Type> {
+        let (ty, _) = self.vars[self.root(id)].bound.as_ref()?;
+        Some(Type::Path(ty.clone()))
+    }
+
+    fn bind(&mut self, id: usize, ty: &str, site: &str) -> Result<(), String> {
+        let root = self.root(id);
+        if let Some((bound, bound_site)) = &self.vars[root].bound {
+            if same_type(bound, ty) {
+                return Ok(());
+            }
+            return Err(Self::conflict(
+                &self.vars[root].origin,
+                (bound, bound_site),
+                (ty, site),
+            ));
+        }
+        self.check_fits(root, ty)?;
+        self.vars[root].bound = Some((ty.to_string(), site.to_string()));
+        Ok(())
+    }
+
+    /// Merges two variables; `false` if they are already fixed to different
+    /// types.
+    fn merge(&mut self, a: usize, b: usize) -> Result<bool, String> {
+        let (a, b) = (self.root(a), self.root(b));
+        if a == b {
+            return Ok(true);
+        }
+        // The root keeps the fixed type, if any.
+        let (root, child) = if self.vars[a].bound.is_none() {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        if let (Some((root_ty, _)), Some((child_ty, _))) =
+            (&self.vars[root].bound, &self.vars[child].bound)
+            && !same_type(root_ty, child_ty)
+        {
+            return Ok(false);
+        }
+        let (min, max) = (self.vars[child].min, self.vars[child].max);
+        let var = &mut self.vars[root];
+        var.min = var.min.min(min);
+        var.max = var.max.max(max);
+        self.vars[child].parent = Some(root);
+        if let Some((ty, _)) = self.vars[root].bound.clone() {
+            self.check_fits(root, &ty)?;
+        }
+        Ok(true)
+    }
+
+    /// Fixes every open variable to `i32`; called once a function is checked.
+    pub(super) fn finish(&mut self) -> Result<(), String> {
+        for id in 0..self.vars.len() {
+            if self.vars[id].parent.is_none() && self.vars[id].bound.is_none() {
+                self.check_fits(id, DEFAULT_INTEGER)
+                    .map_err(|e| format!("{e} (the default integer type); annotate its type"))?;
+            }
+        }
+        self.vars.clear();
+        Ok(())
+    }
+
+    fn check_fits(&self, id: usize, ty: &str) -> Result<(), String> {
+        let var = &self.vars[id];
+        check_literal_fits(var.min, ty, &var.origin)?;
+        check_literal_fits(var.max, ty, &var.origin)
+    }
+
+    fn conflict(origin: &str, first: (&str, &str), second: (&str, &str)) -> String {
+        format!(
+            "Conflicting types for the integer literal{origin}: {} expects {}, but {} expects {}",
+            first.1, first.0, second.1, second.0
+        )
+    }
+}
+
+fn same_type(a: &str, b: &str) -> bool {
+    TypeChecker::canonicalize_type_path(a) == TypeChecker::canonicalize_type_path(b)
+}
+
+fn check_literal_fits(value: i64, ty: &str, origin: &str) -> Result<(), String> {
+    match integer_range(ty) {
+        Some((lo, hi)) if !(lo..=hi).contains(&i128::from(value)) => Err(format!(
+            "Integer literal {value}{origin} does not fit in {ty}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl TypeChecker {
+    /// Type of an integer literal checked against `expected`.
+    pub(super) fn integer_literal_type(
+        &self,
+        value: i64,
+        expected: Option<&Type>,
+    ) -> Result<Type, String> {
+        let origin = match self.line.get() {
+            0 => String::new(),
+            line => format!(" at line {line}"),
+        };
+        if let Some(expected) = expected.filter(|ty| Self::is_integer_type(ty)) {
+            check_literal_fits(value, &Self::type_display(expected), &origin)?;
+            return Ok(expected.clone());
+        }
+        Ok(self.literals.borrow_mut().fresh(value, origin))
+    }
+
+    /// Records that `actual` is used where `expected` is required, at `site`.
+    /// `Ok(false)` is an ordinary mismatch for the caller to report; an
+    /// integer literal fixed to two different types is an error here.
+    pub(super) fn unify(&self, expected: &Type, actual: &Type, site: &str) -> Result<bool, String> {
+        match (expected, actual) {
+            (
+                Type::Ref {
+                    mutable: lm,
+                    inner: l,
+                },
+                Type::Ref {
+                    mutable: rm,
+                    inner: r,
+                },
+            ) => return Ok(lm == rm && self.unify(l, r, site)?),
+            (Type::RawPtr(l), Type::RawPtr(r)) => return self.unify(l, r, site),
+            (Type::Tuple(l), Type::Tuple(r)) => {
+                if l.len() != r.len() {
+                    return Ok(false);
+                }
+                for (l, r) in l.iter().zip(r) {
+                    if !self.unify(l, r, site)? {
+                        return Ok(false);
+                    }
+                }
+                return Ok(true);
+            }
+            // A safe function may stand in for a raw one and a plain
+            // function for a closure, not the reverse.
+            (
+                Type::Fn {
+                    raw: l_raw,
+                    closure: l_closure,
+                    params: l,
+                    ret: l_ret,
+                },
+                Type::Fn {
+                    raw: r_raw,
+                    closure: r_closure,
+                    params: r,
+                    ret: r_ret,
+                },
+            ) => {
+                return Ok((*l_raw || !r_raw)
+                    && (*l_closure || !r_closure)
+                    && l.len() == r.len()
+                    && l.iter().zip(r).all(|(l, r)| Self::types_equal(l, r))
+                    && Self::types_equal(l_ret, r_ret));
+            }
+            _ => {}
+        }
+
+        let mut vars = self.literals.borrow_mut();
+        let (var, other) = match (IntVars::id(expected), IntVars::id(actual)) {
+            (Some(a), Some(b)) => return vars.merge(a, b),
+            (Some(var), None) => (var, actual),
+            (None, Some(var)) => (var, expected),
+            (None, None) => {
+                drop(vars);
+                return Ok(Self::types_equal(
+                    &self.resolve(expected),
+                    &self.resolve(actual),
+                ));
+            }
+        };
+        if !Self::is_integer_type(other) {
+            return Ok(false);
+        }
+        vars.bind(var, &Self::type_display(other), site)?;
+        Ok(true)
+    }
+
+    /// `ty` with fixed variables replaced by their type.
+    pub(super) fn resolve(&self, ty: &Type) -> Type {
+        match ty {
+            Type::Ref { mutable, inner } => Type::Ref {
+                mutable: *mutable,
+                inner: Box::new(self.resolve(inner)),
+            },
+            Type::RawPtr(inner) => Type::RawPtr(Box::new(self.resolve(inner))),
+            Type::Tuple(elements) => {
+                Type::Tuple(elements.iter().map(|ty| self.resolve(ty)).collect())
+            }
+            Type::Fn { .. } => ty.clone(),
+            Type::Path(_) => IntVars::id(ty)
+                .and_then(|id| self.literals.borrow().bound_type(id))
+                .unwrap_or_else(|| ty.clone()),
+        }
+    }
+
+    /// Fixes an open variable to `i32` at `site`, e.g. before it is placed
+    /// inside a generic type.
+    pub(super) fn default_integer(&self, ty: &Type, site: &str) -> Result<(), String> {
+        if let Type::Tuple(elements) = ty {
+            return elements
+                .iter()
+                .try_for_each(|element| self.default_integer(element, site));
+        }
+        if let Some(id) = IntVars::id(ty) {
+            self.literals.borrow_mut().bind(id, DEFAULT_INTEGER, site)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn is_integer_var(ty: &Type) -> bool {
+        IntVars::id(ty).is_some()
+    }
+
+    /// How `ty` appears in diagnostics: fixed variables show their type.
+    pub(super) fn display(&self, ty: &Type) -> String {
+        Self::type_display(&self.resolve(ty))
+    }
+
+    /// Where the constraint being checked comes from, for conflict reports.
+    pub(super) fn site(&self, what: &str) -> String {
+        match self.line.get() {
+            0 => what.to_string(),
+            line => format!("{what} (line {line})"),
+        }
+    }
+}
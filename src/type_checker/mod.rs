@@ -5,8 +5,14 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+mod casts;
 mod checker;
+mod closures;
+mod enums;
+mod fn_values;
 mod infer;
+mod literals;
+mod results;
 mod types;
 
 pub use checker::TypeChecker;
@@ -0,0 +1,359 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use crate::ast::*;
+use crate::std_api;
+use std::collections::HashMap;
+
+use super::TypeChecker;
+
+/// Which parts of a value are Results that must be checked.
+#[derive(Debug, Clone, PartialEq)]
+enum MustUse {
+    No,
+    Yes,
+    /// A tuple with a must-use Result in some element.
+    Tuple(Vec<MustUse>),
+}
+
+impl MustUse {
+    fn tuple(elements: Vec<MustUse>) -> Self {
+        if elements.iter().any(MustUse::any) {
+            MustUse::Tuple(elements)
+        } else {
+            MustUse::No
+        }
+    }
+
+    fn any(&self) -> bool {
+        *self != MustUse::No
+    }
+
+    fn element(&self, index: usize) -> MustUse {
+        match self {
+            MustUse::Tuple(elements) => elements.get(index).cloned().unwrap_or(MustUse::No),
+            _ => MustUse::No,
+        }
+    }
+}
+
+/// The bindings of one function, with uses resolved by lexical scope so a
+/// shadowed Result is not mistaken for a later binding of the same name.
+#[derive(Default)]
+struct Bindings {
+    scopes: Vec<HashMap<String, usize>>,
+    /// Name, which parts of it must be checked, and whether it was read.
+    entries: Vec<(String, MustUse, bool)>,
+}
+
+impl Bindings {
+    fn declare(&mut self, name: &str, must_use: MustUse) {
+        self.entries.push((name.to_string(), must_use, false));
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), self.entries.len() - 1);
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .copied()
+    }
+
+    fn mark_used(&mut self, name: &str) {
+        if let Some(index) = self.lookup(name) {
+            self.entries[index].2 = true;
+        }
+    }
+
+    fn must_use(&self, name: &str) -> MustUse {
+        self.lookup(name)
+            .map_or(MustUse::No, |index| self.entries[index].1.clone())
+    }
+
+    fn first_unused(&self) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(_, must_use, used)| must_use.any() && !*used)
+            .map(|(name, _, _)| name.as_str())
+    }
+}
+
+impl TypeChecker {
+    /// Results carrying a `MemoryError` must be bound and read. Dropping one
+    /// would silently ignore a failed memory operation.
+    pub(super) fn check_results_handled(&self, func: &Function) -> Result<(), String> {
+        let mut bindings = Bindings::default();
+        bindings.scopes.push(HashMap::new());
+        for arg in &func.args {
+            bindings.declare(&arg.name, MustUse::No);
+        }
+        self.check_block_results(&func.body, func.ret_type.is_some(), &mut bindings)?;
+        match bindings.first_unused() {
+            Some(name) => Err(format!(
+                "Unhandled Result: '{name}' holds a MemoryError result that is never checked."
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn check_block_results(
+        &self,
+        block: &Block,
+        tail_used: bool,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        bindings.scopes.push(HashMap::new());
+        let result = self.check_statements_results(&block.statements, tail_used, bindings);
+        bindings.scopes.pop();
+        result
+    }
+
+    fn check_statements_results(
+        &self,
+        statements: &[Statement],
+        tail_used: bool,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        let count = statements.len();
+        for (idx, stmt) in statements.iter().enumerate() {
+            match stmt {
+                Statement::Let(l) => {
+                    self.check_binding_result(&l.name, &l.ty, &l.value, bindings)?
+                }
+                Statement::LetTuple(l) => self.check_tuple_binding_result(l, bindings)?,
+                Statement::Const(c) => {
+                    self.check_binding_result(&c.name, &c.ty, &c.value, bindings)?
+                }
+                Statement::If(stmt) => {
+                    self.check_expr_results(&stmt.condition, true, bindings)?;
+                    match &stmt.pattern {
+                        Some(pattern) => {
+                            self.check_arm_results(pattern, &stmt.then_block, false, bindings)?
+                        }
+                        None => self.check_block_results(&stmt.then_block, false, bindings)?,
+                    }
+                    if let Some(else_block) = &stmt.else_block {
+                        self.check_block_results(else_block, false, bindings)?;
+                    }
+                }
+                Statement::For(stmt) => {
+                    self.check_expr_results(&stmt.start, true, bindings)?;
+                    self.check_expr_results(&stmt.end, true, bindings)?;
+                    bindings.scopes.push(HashMap::new());
+                    bindings.declare(&stmt.var_name, MustUse::No);
+                    let result =
+                        self.check_statements_results(&stmt.body.statements, false, bindings);
+                    bindings.scopes.pop();
+                    result?;
+                }
+                Statement::Break | Statement::Continue => {}
+                Statement::Expr(e) => {
+                    let value_used = tail_used && idx + 1 == count;
+                    self.check_expr_results(e, value_used, bindings)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_expr_results(
+        &self,
+        expr: &Expression,
+        value_used: bool,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        if !value_used && let Some(name) = self.must_use_source(expr) {
+            return Err(format!(
+                "Unhandled Result: the result of '{name}' is discarded. Bind it and check it with result_is_ok()/result_unwrap()."
+            ));
+        }
+        match expr {
+            Expression::Variable(name) => {
+                bindings.mark_used(name);
+                Ok(())
+            }
+            Expression::Block(block) => self.check_block_results(block, value_used, bindings),
+            Expression::Call(call) => {
+                for arg in &call.args {
+                    self.check_expr_results(arg, true, bindings)?;
+                }
+                Ok(())
+            }
+            Expression::Binary { left, right, .. } => {
+                self.check_expr_results(left, true, bindings)?;
+                self.check_expr_results(right, true, bindings)
+            }
+            Expression::Ref { expr, .. }
+            | Expression::Cast { expr, .. }
+            | Expression::Field { expr, .. } => self.check_expr_results(expr, true, bindings),
+            // The elements are used exactly when the tuple is.
+            Expression::Tuple(elements) => elements
+                .iter()
+                .try_for_each(|element| self.check_expr_results(element, value_used, bindings)),
+            Expression::Variant { args, .. } => args
+                .iter()
+                .try_for_each(|arg| self.check_expr_results(arg, true, bindings)),
+            Expression::Match { value, arms } => {
+                self.check_expr_results(value, true, bindings)?;
+                arms.iter().try_for_each(|arm| {
+                    self.check_arm_results(&arm.pattern, &arm.body, value_used, bindings)
+                })
+            }
+            // Parameters are checked by the caller, like function arguments.
+            Expression::Closure(closure) => {
+                bindings.scopes.push(HashMap::new());
+                for param in &closure.params {
+                    bindings.declare(&param.name, MustUse::No);
+                }
+                let result = self.check_expr_results(&closure.body, true, bindings);
+                bindings.scopes.pop();
+                result
+            }
+            Expression::Literal(_) => Ok(()),
+        }
+    }
+
+    /// A `match` arm or `if let` body; a binding must be checked if its
+    /// payload type is a Result.
+    fn check_arm_results(
+        &self,
+        pattern: &Pattern,
+        body: &Block,
+        tail_used: bool,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        bindings.scopes.push(HashMap::new());
+        if let Pattern::Variant {
+            enum_name,
+            variant,
+            bindings: names,
+        } = pattern
+        {
+            let payload = self
+                .enums
+                .get(enum_name)
+                .and_then(|def| def.variant(variant))
+                .map_or(&[][..], |variant| variant.payload.as_slice());
+            for (index, name) in names.iter().enumerate() {
+                let must_use = payload
+                    .get(index)
+                    .map_or(MustUse::No, |ty| self.type_must_use(ty));
+                bindings.declare(name, must_use);
+            }
+        }
+        let result = self.check_statements_results(&body.statements, tail_used, bindings);
+        bindings.scopes.pop();
+        result
+    }
+
+    /// The value is checked before `name` is bound, so it still reads any
+    /// binding that `name` shadows. Rebinding a Result hands the duty to
+    /// check it to the new name.
+    fn check_binding_result(
+        &self,
+        name: &str,
+        ann: &Option<Type>,
+        value: &Expression,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        let must_use = self.declared_must_use(ann, value, bindings);
+        self.check_expr_results(value, true, bindings)?;
+        bindings.declare(name, must_use);
+        Ok(())
+    }
+
+    /// Like `check_binding_result`, per element of the annotation or of the
+    /// value's type.
+    fn check_tuple_binding_result(
+        &self,
+        stmt: &LetTupleStatement,
+        bindings: &mut Bindings,
+    ) -> Result<(), String> {
+        let must_use = self.declared_must_use(&stmt.ty, &stmt.value, bindings);
+        self.check_expr_results(&stmt.value, true, bindings)?;
+        for (index, name) in stmt.names.iter().enumerate() {
+            bindings.declare(name, must_use.element(index));
+        }
+        Ok(())
+    }
+
+    fn declared_must_use(
+        &self,
+        ann: &Option<Type>,
+        value: &Expression,
+        bindings: &Bindings,
+    ) -> MustUse {
+        match ann.as_ref().map(|ty| self.type_must_use(ty)) {
+            Some(must_use) if must_use.any() => must_use,
+            _ => self.value_must_use(value, bindings),
+        }
+    }
+
+    /// Which parts of `value` are must-use Results: call results by the
+    /// declared return type, bindings by what they were bound to.
+    fn value_must_use(&self, value: &Expression, bindings: &Bindings) -> MustUse {
+        match value {
+            Expression::Call(call) => match self.functions.get(&call.func_name) {
+                Some((_, Some(ret))) => self.type_must_use(ret),
+                _ => MustUse::No,
+            },
+            Expression::Variable(name) => bindings.must_use(name),
+            Expression::Tuple(elements) => MustUse::tuple(
+                elements
+                    .iter()
+                    .map(|element| self.value_must_use(element, bindings))
+                    .collect(),
+            ),
+            Expression::Field { expr, index } => {
+                self.value_must_use(expr, bindings).element(*index)
+            }
+            Expression::Block(block) => match block.statements.last() {
+                Some(Statement::Expr(tail)) => self.value_must_use(tail, bindings),
+                _ => MustUse::No,
+            },
+            _ => MustUse::No,
+        }
+    }
+
+    fn type_must_use(&self, ty: &Type) -> MustUse {
+        match ty {
+            _ if Self::is_must_use(ty) => MustUse::Yes,
+            Type::Tuple(elements) => MustUse::tuple(
+                elements
+                    .iter()
+                    .map(|element| self.type_must_use(element))
+                    .collect(),
+            ),
+            _ => MustUse::No,
+        }
+    }
+
+    /// Name of the call whose must-use result `expr` evaluates to, looking
+    /// through the tail of block expressions.
+    fn must_use_source<'a>(&self, expr: &'a Expression) -> Option<&'a str> {
+        match expr {
+            Expression::Call(call) => {
+                let (_, ret) = self.functions.get(&call.func_name)?;
+                ret.as_ref()
+                    .is_some_and(Self::is_must_use)
+                    .then_some(call.func_name.as_str())
+            }
+            Expression::Block(block) => match block.statements.last() {
+                Some(Statement::Expr(tail)) => self.must_use_source(tail),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_must_use(ty: &Type) -> bool {
+        std_api::is_must_use_type(&Self::canonicalize_type_path(&Self::type_display(ty)))
+    }
+}
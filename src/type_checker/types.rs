@@ -7,12 +7,14 @@
 
 use crate::ast::*;
 use crate::std_api;
+use std::collections::HashMap;
 
 use super::TypeChecker;
 
 impl TypeChecker {
     pub(super) fn type_display(ty: &Type) -> String {
         match ty {
+            Type::Path(_) if Self::is_integer_var(ty) => "{integer}".to_string(),
             Type::Path(s) => s.clone(),
             Type::RawPtr(inner) => format!("*{}", Self::type_display(inner)),
             Type::Ref { mutable, inner } => {
@@ -22,6 +24,40 @@ impl TypeChecker {
                     format!("&{}", Self::type_display(inner))
                 }
             }
+            Type::Tuple(elements) => format!(
+                "({})",
+                elements
+                    .iter()
+                    .map(Self::type_display)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Type::Fn {
+                raw,
+                closure,
+                params,
+                ret,
+            } => format!(
+                "{}{}({}) -> {}",
+                if *raw { "raw " } else { "" },
+                if *closure { "closure" } else { "fn" },
+                params
+                    .iter()
+                    .map(Self::type_display)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::type_display(ret)
+            ),
+        }
+    }
+
+    /// The type of a reference to a function with this signature.
+    pub(super) fn fn_type(raw: bool, args: &[Type], ret: Option<&Type>) -> Type {
+        Type::Fn {
+            raw,
+            closure: false,
+            params: args.to_vec(),
+            ret: Box::new(ret.cloned().unwrap_or_else(|| Type::Path("()".to_string()))),
         }
     }
 
@@ -38,15 +74,38 @@ impl TypeChecker {
                     inner: r,
                 },
             ) => lm == rm && Self::types_equal(l, r),
+            (Type::Tuple(l), Type::Tuple(r)) => {
+                l.len() == r.len() && l.iter().zip(r).all(|(l, r)| Self::types_equal(l, r))
+            }
+            (
+                Type::Fn {
+                    raw: l_raw,
+                    closure: l_closure,
+                    params: l,
+                    ret: l_ret,
+                },
+                Type::Fn {
+                    raw: r_raw,
+                    closure: r_closure,
+                    params: r,
+                    ret: r_ret,
+                },
+            ) => {
+                l_raw == r_raw
+                    && l_closure == r_closure
+                    && l.len() == r.len()
+                    && l.iter().zip(r).all(|(l, r)| Self::types_equal(l, r))
+                    && Self::types_equal(l_ret, r_ret)
+            }
             (Type::Path(l), Type::Path(r)) => {
                 Self::canonicalize_type_path(l) == Self::canonicalize_type_path(r)
             }
-            (Type::Ref { .. }, Type::Path(p)) => {
+            (Type::Ref { .. } | Type::Tuple(_) | Type::Fn { .. }, Type::Path(p)) => {
                 let left = Self::canonicalize_type_path(&Self::type_display(lhs));
                 let right = Self::canonicalize_type_path(p);
                 left == right
             }
-            (Type::Path(p), Type::Ref { .. }) => {
+            (Type::Path(p), Type::Ref { .. } | Type::Tuple(_) | Type::Fn { .. }) => {
                 let left = Self::canonicalize_type_path(p);
                 let right = Self::canonicalize_type_path(&Self::type_display(rhs));
                 left == right
@@ -75,26 +134,184 @@ impl TypeChecker {
             return format!("[{}]", Self::canonicalize_type_path(inner));
         }
 
-        if let Some(start) = name.find('<') {
-            if name.ends_with('>') {
-                let base = name[..start].trim();
-                let inner = &name[start + 1..name.len() - 1];
-                if let Ok(args) = Self::split_generic_args(inner) {
-                    let normalized_args = args
-                        .iter()
-                        .map(|arg| Self::canonicalize_type_path(arg))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    let normalized_base = std_api::normalize_type_name(base);
-                    return format!("{normalized_base}<{normalized_args}>");
-                }
+        if let Some(elements) = Self::tuple_elements(name) {
+            let elements = elements
+                .iter()
+                .map(|element| Self::canonicalize_type_path(element))
+                .collect::<Vec<_>>();
+            return format!("({})", elements.join(", "));
+        }
+        if let Some((head, params, ret)) = Self::fn_signature(name) {
+            let params = params
+                .iter()
+                .map(|param| Self::canonicalize_type_path(param))
+                .collect::<Vec<_>>();
+            return format!(
+                "{head}({}) -> {}",
+                params.join(", "),
+                Self::canonicalize_type_path(ret)
+            );
+        }
+
+        if let Some(start) = name.find('<')
+            && name.ends_with('>')
+        {
+            let base = name[..start].trim();
+            let inner = &name[start + 1..name.len() - 1];
+            if let Ok(args) = Self::split_generic_args(inner) {
+                let normalized_args = args
+                    .iter()
+                    .map(|arg| Self::canonicalize_type_path(arg))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let normalized_base = std_api::normalize_type_name(base);
+                return format!("{normalized_base}<{normalized_args}>");
             }
         }
 
         std_api::normalize_type_name(name)
     }
 
-    pub(super) fn is_compatible_int_target(ty: &Type) -> bool {
+    pub(super) fn has_type_params(ty: &Type) -> bool {
+        match ty {
+            Type::Path(name) => Self::path_idents(name).any(std_api::is_type_param),
+            Type::RawPtr(inner) | Type::Ref { inner, .. } => Self::has_type_params(inner),
+            Type::Tuple(elements) => elements.iter().any(Self::has_type_params),
+            Type::Fn { params, ret, .. } => {
+                params.iter().any(Self::has_type_params) || Self::has_type_params(ret)
+            }
+        }
+    }
+
+    /// Matches `actual` against a builtin signature type containing type
+    /// parameters, recording what each parameter stands for.
+    pub(super) fn bind_type_params(
+        expected: &Type,
+        actual: &Type,
+        bindings: &mut HashMap<String, String>,
+    ) -> bool {
+        let expected = Self::canonicalize_type_path(&Self::type_display(expected));
+        let actual = Self::canonicalize_type_path(&Self::type_display(actual));
+        Self::bind_type_path(&expected, &actual, bindings)
+    }
+
+    fn bind_type_path(
+        expected: &str,
+        actual: &str,
+        bindings: &mut HashMap<String, String>,
+    ) -> bool {
+        if std_api::is_type_param(expected) {
+            return match bindings.get(expected) {
+                Some(bound) => bound == actual,
+                None => {
+                    bindings.insert(expected.to_string(), actual.to_string());
+                    true
+                }
+            };
+        }
+        match (
+            Self::parse_generic_type(expected),
+            Self::parse_generic_type(actual),
+        ) {
+            (Ok(Some((expected_base, expected_args))), Ok(Some((actual_base, actual_args)))) => {
+                expected_base == actual_base
+                    && expected_args.len() == actual_args.len()
+                    && expected_args
+                        .iter()
+                        .zip(&actual_args)
+                        .all(|(e, a)| Self::bind_type_path(e, a, bindings))
+            }
+            _ => expected == actual,
+        }
+    }
+
+    /// Replaces bound type parameters in `ty`; `None` if one is unbound.
+    pub(super) fn substitute_type_params(
+        ty: &Type,
+        bindings: &HashMap<String, String>,
+    ) -> Option<Type> {
+        let Type::Path(name) = ty else {
+            return Some(ty.clone());
+        };
+        let mut out = String::new();
+        let mut ident = String::new();
+        for ch in name.chars().chain(std::iter::once(' ')) {
+            if ch.is_alphanumeric() || ch == '_' {
+                ident.push(ch);
+                continue;
+            }
+            if std_api::is_type_param(&ident) {
+                out.push_str(bindings.get(&ident)?);
+            } else {
+                out.push_str(&ident);
+            }
+            ident.clear();
+            out.push(ch);
+        }
+        out.pop();
+        Some(Self::type_from_path(&out))
+    }
+
+    /// `Path("(A, B)")` or `Path("fn(A) -> R")`, as produced by substituting
+    /// a type parameter or spelled in a builtin signature, rebuilt as a
+    /// `Type::Tuple` or `Type::Fn`.
+    pub(super) fn type_from_path(name: &str) -> Type {
+        if let Some(elements) = Self::tuple_elements(name) {
+            return Type::Tuple(elements.into_iter().map(Self::type_from_path).collect());
+        }
+        if let Some((head, params, ret)) = Self::fn_signature(name) {
+            return Type::Fn {
+                raw: head.starts_with("raw "),
+                closure: head.ends_with("closure"),
+                params: params.into_iter().map(Self::type_from_path).collect(),
+                ret: Box::new(Self::type_from_path(ret)),
+            };
+        }
+        Type::Path(name.to_string())
+    }
+
+    /// Head, parameter and return types of a function type spelled as a
+    /// path, `[raw ]fn(A, B) -> R` or `[raw ]closure(A, B) -> R`.
+    fn fn_signature(name: &str) -> Option<(&str, Vec<&str>, &str)> {
+        let open = name.find('(')?;
+        let head = &name[..open];
+        if !matches!(head, "fn" | "raw fn" | "closure" | "raw closure") {
+            return None;
+        }
+        let rest = &name[open + 1..];
+        let mut depth = 0usize;
+        let close = rest.char_indices().find_map(|(idx, ch)| match ch {
+            '<' | '(' => {
+                depth += 1;
+                None
+            }
+            ')' if depth == 0 => Some(idx),
+            '>' if rest[..idx].ends_with('-') => None,
+            '>' | ')' => {
+                depth = depth.saturating_sub(1);
+                None
+            }
+            _ => None,
+        })?;
+        let params = Self::split_generic_args(&rest[..close]).ok()?;
+        let ret = rest[close + 1..].trim().strip_prefix("->")?.trim();
+        Some((head, params, ret))
+    }
+
+    /// Element types of a tuple spelled as a path, `(A, B)`.
+    fn tuple_elements(name: &str) -> Option<Vec<&str>> {
+        let inner = name.strip_prefix('(')?.strip_suffix(')')?;
+        let elements = Self::split_generic_args(inner).ok()?;
+        (elements.len() >= 2).then_some(elements)
+    }
+
+    pub(super) fn path_idents(name: &str) -> impl Iterator<Item = &str> {
+        name.split(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+            .filter(|part| !part.is_empty())
+    }
+
+    /// A concrete integer type; see `is_integer_var` for literals.
+    pub(super) fn is_integer_type(ty: &Type) -> bool {
         matches!(
             ty,
             Type::Path(name)
@@ -106,22 +323,20 @@ impl TypeChecker {
         )
     }
 
-    pub(super) fn is_compatible_integer_return(block: &Block, expected: &Type) -> bool {
-        if !Self::is_compatible_int_target(expected) {
-            return false;
-        }
-
-        matches!(
-            block.statements.last(),
-            Some(Statement::Expr(Expression::Literal(Literal::Integer(_))))
-        )
-    }
-
     pub(super) fn validate_type(&self, ty: &Type) -> Result<(), String> {
         match ty {
             Type::RawPtr(inner) => self.validate_type(inner),
             Type::Ref { inner, .. } => self.validate_type(inner),
             Type::Path(name) => self.validate_type_path(name),
+            Type::Tuple(elements) => elements
+                .iter()
+                .try_for_each(|element| self.validate_type(element)),
+            Type::Fn { params, ret, .. } => {
+                for param in params {
+                    self.validate_type(param)?;
+                }
+                self.validate_type(ret)
+            }
         }
     }
 
@@ -150,6 +365,17 @@ impl TypeChecker {
                 .ok_or_else(|| format!("Malformed type '{name}'"))?;
             return self.validate_type_path(inner);
         }
+        if let Some(elements) = Self::tuple_elements(name) {
+            return elements
+                .into_iter()
+                .try_for_each(|element| self.validate_type_path(element));
+        }
+        if let Some((_, params, ret)) = Self::fn_signature(name) {
+            for param in params {
+                self.validate_type_path(param)?;
+            }
+            return self.validate_type_path(ret);
+        }
         if let Some((base, args)) = Self::parse_generic_type(name)? {
             let canonical_base = std_api::normalize_type_name(base);
             if canonical_base != "core::types::Option" && canonical_base != "core::types::Result" {
@@ -184,7 +410,7 @@ impl TypeChecker {
         }
     }
 
-    fn parse_generic_type(name: &str) -> Result<Option<(&str, Vec<&str>)>, String> {
+    pub(super) fn parse_generic_type(name: &str) -> Result<Option<(&str, Vec<&str>)>, String> {
         let Some(start) = name.find('<') else {
             return Ok(None);
         };
@@ -203,8 +429,10 @@ impl TypeChecker {
         let mut start = 0usize;
         for (idx, ch) in input.char_indices() {
             match ch {
-                '<' => depth += 1,
-                '>' => {
+                '<' | '(' => depth += 1,
+                // The arrow of a function type.
+                '>' if input[..idx].ends_with('-') => {}
+                '>' | ')' => {
                     if depth == 0 {
                         return Err(format!("Malformed generic args '{input}'"));
                     }
@@ -5,6 +5,9 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
+use crate::core::memory;
+use crate::core::memory::raw::RawPtr;
+use crate::core::memory::safe::{HighPtr, ValidatedPtr};
 use std::marker::PhantomData;
 
 pub trait SafetyLevel {
@@ -26,22 +29,37 @@ impl SafetyLevel for ValidatedLevel {
     const LEVEL: u8 = 1;
 }
 
+/// A value tagged with the safety level it was produced at.
+///
+/// Generated code keeps values from `core::memory::raw` as `Raw<T>`, and
+/// values from `validate_raw`/`into_high` as `Validated<T>`/`High<T>`.
+/// Reading a `Raw<T>` is `unsafe`. Any value may enter as `Raw<T>`, but only
+/// a `ValidatedPtr` or `HighPtr` can be tagged directly at a higher level;
+/// other values get there through [`validate_raw`].
 #[derive(Debug)]
 pub struct Typed<T, S: SafetyLevel> {
     value: T,
     _safety: PhantomData<S>,
 }
 
+impl<T: Clone, S: SafetyLevel> Clone for Typed<T, S> {
+    fn clone(&self) -> Self {
+        Self::wrap(self.value.clone())
+    }
+}
+
+impl<T: Copy, S: SafetyLevel> Copy for Typed<T, S> {}
+
 impl<T, S: SafetyLevel> Typed<T, S> {
-    pub fn new(value: T) -> Self {
+    fn wrap(value: T) -> Self {
         Self {
             value,
             _safety: PhantomData,
         }
     }
 
-    pub fn unwrap(self) -> T {
-        self.value
+    pub fn level(&self) -> u8 {
+        S::LEVEL
     }
 }
 
@@ -49,14 +67,91 @@ pub type High<T> = Typed<T, HighLevel>;
 pub type Raw<T> = Typed<T, RawLevel>;
 pub type Validated<T> = Typed<T, ValidatedLevel>;
 
+impl<T> Raw<T> {
+    pub fn new(value: T) -> Self {
+        Self::wrap(value)
+    }
+}
+
+impl Validated<ValidatedPtr> {
+    /// A `ValidatedPtr` has already passed validation.
+    pub fn new(ptr: ValidatedPtr) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl High<HighPtr> {
+    /// A `HighPtr` has already been promoted.
+    pub fn new(ptr: HighPtr) -> Self {
+        Self::wrap(ptr)
+    }
+}
+
+impl<T> High<T> {
+    pub fn unwrap(self) -> T {
+        self.value
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
 impl<T> Validated<T> {
+    pub fn unwrap(self) -> T {
+        self.value
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
     pub fn into_high(self) -> High<T> {
-        High::new(self.value)
+        High::wrap(self.value)
+    }
+}
+
+impl<T> Raw<T> {
+    /// # Safety
+    /// The value has not been validated; the caller takes responsibility
+    /// for using it correctly.
+    pub unsafe fn unwrap(self) -> T {
+        self.value
+    }
+
+    /// # Safety
+    /// See [`Raw::unwrap`].
+    pub unsafe fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// # Safety
+    /// See [`Raw::unwrap`].
+    pub unsafe fn get_mut(&mut self) -> &mut T {
+        &mut self.value
     }
 }
 
 pub fn validate_raw<T>(raw: Raw<T>) -> Validated<T> {
-    Validated::new(raw.value)
+    Validated::wrap(raw.value)
+}
+
+/// Typed form of `core::memory::safe::validate_raw` used by generated code.
+pub fn validate_raw_ptr(raw: Raw<RawPtr>) -> Validated<ValidatedPtr> {
+    Validated::new(memory::safe::validate_raw(raw.value))
+}
+
+/// Typed form of `core::memory::safe::into_high` used by generated code.
+pub fn into_high_ptr(validated: Validated<ValidatedPtr>) -> High<HighPtr> {
+    High::new(memory::safe::into_high(validated.value))
 }
 
 pub fn example_usage() {
@@ -0,0 +1,116 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use safe_lang::core::memory::safe;
+use safe_lang::core::memory::tracking::AllocationTable;
+use std::sync::Arc;
+
+#[test]
+fn test_allocation_table_insert_get_remove() {
+    let table = AllocationTable::new();
+    assert!(table.is_empty());
+    assert_eq!(table.insert(0x1000, 16), None);
+    assert_eq!(table.insert(0x2000, 32), None);
+    assert_eq!(table.get(0x1000), Some(16));
+    assert_eq!(table.get(0x2000), Some(32));
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.remove(0x1000), Some(16));
+    assert_eq!(table.get(0x1000), None);
+    assert_eq!(table.remove(0x1000), None);
+    assert_eq!(table.len(), 1);
+}
+
+#[test]
+fn test_allocation_table_cache_sees_reused_address() {
+    let table = AllocationTable::new();
+    table.insert(0x4000, 8);
+    assert_eq!(table.get(0x4000), Some(8));
+    table.remove(0x4000);
+    table.insert(0x4000, 64);
+    assert_eq!(table.get(0x4000), Some(64));
+    table.insert(0x4000, 4);
+    assert_eq!(table.get(0x4000), Some(4));
+}
+
+#[test]
+fn test_allocation_table_cache_is_per_table() {
+    let first = AllocationTable::new();
+    let second = AllocationTable::new();
+    first.insert(0x8000, 8);
+    assert_eq!(first.get(0x8000), Some(8));
+    assert_eq!(second.get(0x8000), None);
+}
+
+#[test]
+fn test_allocation_table_removal_is_seen_by_other_threads() {
+    let table = Arc::new(AllocationTable::new());
+    table.insert(0x10000, 8);
+    let reader = Arc::clone(&table);
+    std::thread::spawn(move || assert_eq!(reader.get(0x10000), Some(8)))
+        .join()
+        .unwrap();
+
+    table.remove(0x10000);
+    let reader = Arc::clone(&table);
+    std::thread::spawn(move || assert_eq!(reader.get(0x10000), None))
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_allocation_table_concurrent_inserts_and_removes() {
+    let table = Arc::new(AllocationTable::new());
+    let handles: Vec<_> = (0..8)
+        .map(|thread| {
+            let table = Arc::clone(&table);
+            std::thread::spawn(move || {
+                for i in 0..1000 {
+                    let addr = (thread * 1000 + i + 1) * 16;
+                    table.insert(addr, i + 1);
+                    assert_eq!(table.get(addr), Some(i + 1));
+                    if i % 2 == 0 {
+                        assert_eq!(table.remove(addr), Some(i + 1));
+                        assert_eq!(table.get(addr), None);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(table.len(), 8 * 500);
+}
+
+#[test]
+fn test_buffers_used_from_many_threads_stay_bounds_checked() {
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let ptr = safe::allocate_buffer(32);
+                for offset in 0..32 {
+                    safe::write_u8(ptr, offset, offset as u8);
+                }
+                let sum: usize = (0..32).map(|o| safe::read_u8(ptr, o) as usize).sum();
+                safe::deallocate_buffer(ptr);
+                sum
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), (0..32).sum::<usize>());
+    }
+}
+
+#[test]
+#[should_panic(expected = "high ptr is invalid")]
+fn test_freed_buffer_is_rejected_after_cached_lookup() {
+    let ptr = safe::allocate_buffer(8);
+    safe::write_u8(ptr, 0, 1);
+    safe::deallocate_buffer(ptr);
+    safe::read_u8(ptr, 0);
+}
@@ -0,0 +1,105 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use safe_lang::core::memory::allocator::{
+    self, Allocator, ArenaAllocator, PoolAllocator, SystemAllocator,
+};
+use safe_lang::core::memory::{debug, safe};
+
+#[test]
+fn test_system_allocator_returns_zeroed_blocks() {
+    let alloc = SystemAllocator;
+    let ptr = alloc.allocate(16);
+    assert!(!ptr.is_null());
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, 16) };
+    assert!(bytes.iter().all(|b| *b == 0));
+    unsafe { alloc.deallocate(ptr, 16) };
+}
+
+#[test]
+fn test_arena_allocator_is_bounded_and_reuses_top_block() {
+    let arena = ArenaAllocator::new(32);
+    let a = arena.allocate(16);
+    let b = arena.allocate(16);
+    assert!(!a.is_null() && !b.is_null());
+    assert!(arena.allocate(1).is_null());
+
+    unsafe { arena.deallocate(b, 16) };
+    assert_eq!(arena.used(), 16);
+    let c = arena.allocate(8);
+    assert_eq!(c, b);
+
+    unsafe {
+        arena.deallocate(a, 16);
+        arena.deallocate(c, 8);
+    }
+    assert_eq!(arena.used(), 0);
+}
+
+#[test]
+fn test_arena_allocator_zeroes_reused_space() {
+    let arena = ArenaAllocator::new(8);
+    let a = arena.allocate(8);
+    unsafe {
+        std::ptr::write_bytes(a, 0xaa, 8);
+        arena.deallocate(a, 8);
+    }
+    let b = arena.allocate(8);
+    let bytes = unsafe { std::slice::from_raw_parts(b, 8) };
+    assert!(bytes.iter().all(|v| *v == 0));
+}
+
+#[test]
+fn test_pool_allocator_limits_block_size_and_count() {
+    let pool = PoolAllocator::new(8, 2);
+    assert!(pool.allocate(9).is_null());
+
+    let a = pool.allocate(8);
+    let b = pool.allocate(4);
+    assert!(!a.is_null() && !b.is_null());
+    assert_ne!(a, b);
+    assert!(pool.allocate(1).is_null());
+    assert_eq!(pool.available_blocks(), 0);
+
+    unsafe { pool.deallocate(a, 8) };
+    assert_eq!(pool.allocate(2), a);
+}
+
+#[test]
+#[should_panic(expected = "pool allocator does not own this block")]
+fn test_pool_allocator_rejects_foreign_block() {
+    let pool = PoolAllocator::new(8, 1);
+    let other = PoolAllocator::new(8, 1);
+    let ptr = other.allocate(8);
+    unsafe { pool.deallocate(ptr, 8) };
+}
+
+#[test]
+fn test_installed_allocator_backs_runtime_buffers() {
+    // Debug memory mode adds canaries around each block and keeps freed
+    // blocks in quarantine instead of returning them.
+    let guard = if debug::enabled() {
+        2 * debug::CANARY_LEN
+    } else {
+        0
+    };
+    assert!(allocator::use_arena_allocator(64 + 2 * guard));
+    assert_eq!(allocator::current().name(), "arena");
+    assert!(!allocator::use_pool_allocator(8, 8));
+
+    let high = safe::allocate_buffer(48);
+    let raw = unsafe { safe_lang::core::memory::raw::alloc(16) };
+    let overflow = std::panic::catch_unwind(|| safe::allocate_buffer(1));
+    assert!(overflow.is_err());
+
+    unsafe { safe_lang::core::memory::raw::deallocate(raw) };
+    safe::deallocate_buffer(high);
+    if !debug::enabled() {
+        let again = safe::allocate_buffer(64);
+        safe::deallocate_buffer(again);
+    }
+}
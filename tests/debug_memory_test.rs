@@ -0,0 +1,102 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+#![cfg(feature = "debug-memory")]
+
+use safe_lang::core::memory::debug::{self, CANARY_LEN, POISON_BYTE};
+use safe_lang::core::memory::{raw, safe};
+use std::panic;
+
+fn panic_message(result: std::thread::Result<()>) -> String {
+    let payload = result.expect_err("expected a panic");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_debug_mode_blocks_are_zeroed_and_usable() {
+    assert!(debug::enabled());
+    let ptr = safe::allocate_buffer(16);
+    assert!((0..16).all(|offset| safe::read_u8(ptr, offset) == 0));
+    safe::write_u64_le(ptr, 8, u64::MAX);
+    assert_eq!(safe::read_u64_le(ptr, 8), u64::MAX);
+    safe::deallocate_buffer(ptr);
+}
+
+#[test]
+#[should_panic(expected = "canary after block")]
+fn test_write_past_end_is_caught_on_deallocate() {
+    unsafe {
+        let ptr = raw::alloc(8);
+        *ptr.as_ptr().add(8) = 0;
+        raw::deallocate(ptr);
+    }
+}
+
+#[test]
+#[should_panic(expected = "canary before block")]
+fn test_write_before_start_is_caught_on_deallocate() {
+    unsafe {
+        let ptr = raw::alloc(8);
+        *ptr.as_ptr().sub(1) = 0;
+        raw::deallocate(ptr);
+    }
+}
+
+#[test]
+fn test_corruption_report_names_allocation_site() {
+    let message = panic_message(panic::catch_unwind(|| unsafe {
+        let ptr = raw::alloc(4);
+        *ptr.as_ptr().add(4 + CANARY_LEN - 1) = 0;
+        raw::deallocate(ptr);
+    }));
+    assert!(
+        message.contains("allocated at tests/debug_memory_test.rs"),
+        "{message}"
+    );
+    assert!(
+        message.contains("freed at tests/debug_memory_test.rs"),
+        "{message}"
+    );
+}
+
+#[test]
+fn test_freed_blocks_are_poisoned_and_quarantined() {
+    unsafe {
+        let ptr = raw::alloc(8);
+        let addr = ptr.as_ptr();
+        raw::deallocate(ptr);
+        assert!(debug::quarantined_blocks() > 0);
+        let freed = std::slice::from_raw_parts(addr, 8);
+        assert!(freed.iter().all(|b| *b == POISON_BYTE));
+    }
+}
+
+#[test]
+fn test_write_after_free_is_reported_by_heap_check() {
+    unsafe {
+        let ptr = raw::alloc(8);
+        let addr = ptr.as_ptr();
+        raw::deallocate(ptr);
+        *addr.add(3) = 7;
+        let report = debug::verify_heap().expect_err("write after free should be reported");
+        assert!(report.contains("written after free"), "{report}");
+        assert!(report.contains("tests/debug_memory_test.rs"), "{report}");
+        *addr.add(3) = POISON_BYTE;
+    }
+}
+
+#[test]
+#[should_panic(expected = "high ptr is invalid (freed block of 8 bytes, allocated at")]
+fn test_use_after_free_names_freed_block() {
+    let ptr = safe::allocate_buffer(8);
+    safe::deallocate_buffer(ptr);
+    safe::read_u8(ptr, 0);
+}
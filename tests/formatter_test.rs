@@ -0,0 +1,169 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+use safe_lang::formatter::format_source;
+use safe_lang::lexer;
+use std::fs;
+use std::path::Path;
+
+/// Formats `input` to exactly `expected`, then checks that formatting the
+/// result again changes nothing.
+fn assert_formats(input: &str, expected: &str) {
+    let formatted = format_source(input).expect("format");
+    assert_eq!(formatted, expected);
+    assert_eq!(format_source(&formatted).expect("reformat"), formatted);
+}
+
+#[test]
+fn test_format_examples_are_canonical_and_idempotent() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut count = 0;
+    for entry in fs::read_dir(&dir).expect("read examples") {
+        let path = entry.expect("entry").path();
+        if path.extension().is_none_or(|ext| ext != "safe") {
+            continue;
+        }
+        let source = fs::read_to_string(&path).expect("read example");
+        let formatted = format_source(&source)
+            .unwrap_or_else(|e| panic!("{} should format: {e}", path.display()));
+        assert_eq!(formatted, source, "{} is not formatted", path.display());
+        assert_eq!(format_source(&formatted).expect("reformat"), formatted);
+        count += 1;
+    }
+    assert!(count > 0, "no examples found");
+}
+
+#[test]
+fn test_format_canonical_layout_keeps_comments() {
+    let input = r#"// header
+
+import "util.safe"
+alias   len=string_len
+alias Res<T> = core::types::Result<T,i32>
+safe   fn   main( ) {
+  let high_a:usize=len(&"abc")   // trailing
+     /* block */
+
+
+  if high_a==3 { printl(1) } else if high_a<2 {printl(2)} else {
+  // only comment
+  }
+  for i in 0..=3 { if i == 2 { break } }
+  unsafe { let raw_p = raw_alloc(4)
+  }
+}
+raw fn f(raw_p: *u8, high_s: &mut [u8]) -> Option<u8> {}
+"#;
+    let expected = r#"// header
+
+import "util.safe"
+alias len = string_len
+alias Res<T> = core::types::Result<T, i32>
+
+safe fn main() {
+    let high_a: usize = len(&"abc") // trailing
+    /* block */
+
+    if high_a == 3 {
+        printl(1)
+    } else if high_a < 2 {
+        printl(2)
+    } else {
+        // only comment
+    }
+    for i in 0..=3 {
+        if i == 2 {
+            break
+        }
+    }
+    unsafe {
+        let raw_p = raw_alloc(4)
+    }
+}
+
+raw fn f(raw_p: *u8, high_s: &mut [u8]) -> Option<u8> {}
+"#;
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_wraps_long_calls_and_signatures() {
+    let input = "safe fn configure(high_first_setting: usize, high_second_setting: usize, high_third_setting: usize) {\n\
+                 let high_total = combine_settings(high_first_setting, high_second_setting, lookup(high_third, 1))\n}\n";
+    let expected = "safe fn configure(\n    high_first_setting: usize,\n    high_second_setting: usize,\n    high_third_setting: usize\n) {\n    let high_total = combine_settings(\n        high_first_setting,\n        high_second_setting,\n        lookup(high_third, 1)\n    )\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_keeps_comments_inside_parameters_and_arguments() {
+    let input = "safe fn add(high_a: i32, // first\n    high_b: i32) -> i32 {\n    high_a\n}\n\nsafe fn main() {\n    let high_x = add(1, /* inner */ 2)\n    let high_y = add(1 /* one */, 2) // tail\n    let high_z = high_x == // same\n    high_y\n}\n";
+    let expected = "safe fn add(\n    high_a: i32, // first\n    high_b: i32\n) -> i32 {\n    high_a\n}\n\nsafe fn main() {\n    let high_x = add(1, /* inner */ 2)\n    let high_y = add(1 /* one */, 2) // tail\n    let high_z = high_x == // same\n        high_y\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_keeps_every_comment_between_the_same_tokens() {
+    let input = "// a\nsafe fn f(/* b */ high_x: u8 /* c */, high_y: u8 // d\n) /* e */ -> u8 {\n    let high_t = ( // f\n    high_x, /* g */ high_y)\n    // h\n    g(high_t.0 /* i */) // j\n    /* k */\n}\n";
+    let formatted = format_source(input).expect("format");
+    let comments = |text: &str| {
+        let (tokens, comments) = lexer::tokenize_with_comments(text).expect("lex");
+        comments
+            .into_iter()
+            .map(|comment| {
+                let before = tokens
+                    .iter()
+                    .filter(|token| token.span.start < comment.span.start)
+                    .count();
+                (before, comment.text)
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(comments(&formatted), comments(input), "{formatted}");
+    assert_eq!(format_source(&formatted).expect("reformat"), formatted);
+}
+
+#[test]
+fn test_format_rejects_unparsable_source() {
+    let err = format_source("safe fn main( {\n").expect_err("invalid source");
+    assert!(err.contains("Parsing failed"), "{err}");
+}
+
+#[test]
+fn test_format_puts_attributes_on_their_own_lines() {
+    let input = "#[ doc( \"Entry\" ) ]   #[allow(rule5 ,rule4)] safe fn main() {\n    #[allow(rule5)] let count = 1\n}\n";
+    let expected = "#[doc(\"Entry\")]\n#[allow(rule5, rule4)]\nsafe fn main() {\n    #[allow(rule5)]\n    let count = 1\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_tuples_and_casts() {
+    let input = "safe fn pair( high_n:u8 )->( u8,usize ) {\n    let ( high_a,high_b )=( high_n , 2 )\n    let high_c = high_a as u64\n    let high_d = pair( high_a ) . 1\n    ( high_a , checked_cast ::< usize >( high_b ) )\n}\n";
+    let expected = "safe fn pair(high_n: u8) -> (u8, usize) {\n    let (high_a, high_b) = (high_n, 2)\n    let high_c = high_a as u64\n    let high_d = pair(high_a).1\n    (high_a, checked_cast::<usize>(high_b))\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_enums_match_and_if_let() {
+    let input = "enum Packet { Ping , Data( u16 ) }\nsafe fn size(high_p: Packet) -> u16 {\n    if let Packet::Data( high_n ) = high_p { }\n    match high_p { Packet::Ping => { 0 } _ => { 1 } }\n}\n";
+    let expected = "enum Packet {\n    Ping,\n    Data(u16)\n}\n\nsafe fn size(high_p: Packet) -> u16 {\n    if let Packet::Data(high_n) = high_p {}\n    match high_p {\n        Packet::Ping => {\n            0\n        }\n        _ => {\n            1\n        }\n    }\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_closures() {
+    let input = "safe fn test() {\n    let high_f = | high_x:u8 ,high_y |high_x==high_y\n    let high_g = ||1\n}\n";
+    let expected = "safe fn test() {\n    let high_f = |high_x: u8, high_y| high_x == high_y\n    let high_g = || 1\n}\n";
+    assert_formats(input, expected);
+}
+
+#[test]
+fn test_format_function_types() {
+    let input =
+        "raw fn apply( raw_f:raw  fn( u8,bool )->u8 , raw_g: fn() )->fn(u8)->u8 {\n    raw_g\n}\n";
+    let expected = "raw fn apply(raw_f: raw fn(u8, bool) -> u8, raw_g: fn()) -> fn(u8) -> u8 {\n    raw_g\n}\n";
+    assert_formats(input, expected);
+}
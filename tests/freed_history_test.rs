@@ -0,0 +1,42 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+// Debug memory mode quarantines freed blocks, so no address is reused.
+#![cfg(not(feature = "debug-memory"))]
+
+// Its own test binary: the release history is global, and the pool
+// allocator must be installed before the first allocation.
+
+use safe_lang::core::memory::MemoryError;
+use safe_lang::core::memory::allocator;
+use safe_lang::core::memory::safe::{
+    FREED_HISTORY, allocate_buffer, deallocate_buffer, try_deallocate_buffer,
+};
+
+#[test]
+fn test_reused_address_is_remembered_for_a_full_history() {
+    // The pool hands back the block released last.
+    assert!(allocator::use_pool_allocator(8, FREED_HISTORY + 1));
+
+    let first = allocate_buffer(8);
+    let others = (1..FREED_HISTORY)
+        .map(|_| allocate_buffer(8))
+        .collect::<Vec<_>>();
+    deallocate_buffer(first);
+    let again = allocate_buffer(8);
+    assert_eq!(again.addr(), first.addr());
+    deallocate_buffer(again);
+
+    // Fills the history up to its bound with the reused address oldest.
+    for high in others {
+        deallocate_buffer(high);
+    }
+    assert_eq!(
+        try_deallocate_buffer(again).unwrap_err(),
+        MemoryError::DoubleFree
+    );
+}
@@ -5,9 +5,9 @@
 // This software is licensed under the MIT License.
 // See: https://opensource.org/licenses/MIT
 
-use safe_lang::ast::{Expression, Item, Statement};
+use safe_lang::ast::{Expression, Item, Statement, Struct, StructField, Type};
 use safe_lang::lexer;
-use safe_lang::molding::Molder;
+use safe_lang::molding::{AliasFile, Molder, RuleConfig, RuleLevel};
 use safe_lang::parser;
 
 fn run_molding_output(code: &str) -> Result<safe_lang::ast::SourceFile, String> {
@@ -22,6 +22,13 @@ fn run_molding_output(code: &str) -> Result<safe_lang::ast::SourceFile, String>
     Ok(molder.get_output().clone())
 }
 
+fn parse(code: &str) -> safe_lang::ast::SourceFile {
+    let tokens = lexer::tokenize(code).expect("lex");
+    let (rest, source) = parser::parse(&tokens).expect("parse");
+    assert!(rest.is_empty(), "unconsumed tokens");
+    source
+}
+
 fn run_molding(code: &str) -> Result<(), String> {
     let tokens = lexer::tokenize(code).map_err(|e| format!("Lex: {e}"))?;
     let (rest, source) = parser::parse(&tokens).map_err(|_| "Parse failed".to_string())?;
@@ -88,32 +95,153 @@ safe fn test() {
 }
 
 #[test]
-fn test_molding_rejects_duplicate_names_across_scopes() {
+fn test_molding_allows_shadowing_in_the_same_block_and_across_functions() {
     let code = r#"
-safe fn test() {
+safe fn first() {
     let high_x: i32 = 1
-    unsafe {
-        let raw_y = 2
+    let high_x: String = "ok"
+}
+
+safe fn second(high_x: i32) {
+    let high_x = 2
+    for high_i in 0..3 {
+    }
+    for high_i in 0..3 {
     }
-    let high_x: i32 = 3
 }
 "#;
 
-    let err = run_molding(code).expect_err("global uniqueness should reject duplicate variable");
-    assert!(err.contains("Rule 4 Violation"));
+    run_molding(code).expect("re-binding a name of the same scope is shadowing");
 }
 
 #[test]
-fn test_molding_rejects_duplicate_names_with_different_types() {
+fn test_molding_rejects_duplicate_parameters() {
     let code = r#"
+safe fn test(high_x: i32, high_x: i32) {
+}
+"#;
+
+    let err = run_molding(code).expect_err("parameters must be distinct");
+    assert!(err.contains("Parameter 'high_x' is declared more than once in 'test'"));
+}
+
+#[test]
+fn test_molding_checks_each_destructured_name() {
+    let prefix = r#"
 safe fn test() {
-    let high_x: i32 = 1
-    let high_x: String = "ok"
+    let (high_tag, count) = (1, 2)
+}
+"#;
+    let err = run_molding(prefix).expect_err("every element needs a prefix");
+    assert!(err.contains("Variable 'count' outside unsafe must start with 'high_'"));
+
+    let repeated = r#"
+safe fn test() {
+    let (high_a, high_a) = (1, 2)
 }
 "#;
+    let err = run_molding(repeated).expect_err("names must be distinct");
+    assert!(
+        err.contains("'high_a' is bound more than once in one 'let'"),
+        "{err}"
+    );
 
-    let err = run_molding(code).expect_err("duplicate name should be rejected regardless of type");
-    assert!(err.contains("Rule 4 Violation"));
+    let validated = r#"
+safe fn test() {
+    unsafe {
+        let raw_p = raw_alloc(4)
+        let (validated_p, raw_n) = (validate_raw(raw_p), 4)
+    }
+}
+"#;
+    run_molding(validated).expect("Rule 6 looks at the element's own value");
+}
+
+#[test]
+fn test_molding_checks_enum_payloads_and_pattern_bindings() {
+    let err = run_molding("enum Packet {\n    Raw(*u8),\n}\n")
+        .expect_err("raw payloads are unsafe types");
+    assert!(err.contains("Rule 3 Violation: Unsafe type '*u8'"), "{err}");
+    let rules = RuleConfig {
+        allow_safety_rules: true,
+        ..RuleConfig::default()
+    };
+    Molder::new(parse("#[allow(rule3)]\nenum Packet {\n    Raw(*u8),\n}\n"))
+        .with_rules(rules)
+        .mold()
+        .expect("allow(rule3) on the enum");
+
+    let prefix = r#"
+enum Packet {
+    Data(u16),
+}
+
+safe fn test() {
+    match Packet::Data(1) {
+        Packet::Data(len) => {}
+    }
+}
+"#;
+    let err = run_molding(prefix).expect_err("pattern bindings need a prefix");
+    assert!(err.contains("Variable 'len' outside unsafe must start with 'high_'"));
+
+    let repeated = r#"
+enum Pair {
+    Both(u8, u8),
+}
+
+safe fn test() {
+    if let Pair::Both(high_a, high_a) = Pair::Both(1, 2) {}
+}
+"#;
+    let err = run_molding(repeated).expect_err("names must be distinct");
+    assert!(
+        err.contains("'high_a' is bound more than once in one pattern"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_molding_treats_raw_fn_types_as_unsafe() {
+    let err = run_molding("safe fn apply(high_f: raw fn(u8) -> u8) {}\n")
+        .expect_err("raw fn types are unsafe types");
+    assert!(
+        err.contains("Rule 3 Violation: Unsafe type 'raw fn(u8) -> u8'"),
+        "{err}"
+    );
+    let err = run_molding("alias Cb = raw fn(u8)\n\nsafe fn apply(high_f: Cb) {}\n")
+        .expect_err("through a type alias");
+    assert!(err.contains("(via alias 'Cb' at line 1)"), "{err}");
+
+    run_molding("safe fn apply(high_f: fn(u8) -> u8) -> u8 {\n    high_f(1)\n}\n")
+        .expect("safe fn types are allowed");
+    run_molding("raw fn apply(raw_f: raw fn(u8) -> u8) -> u8 {\n    raw_f(1)\n}\n")
+        .expect("raw fn types inside a raw fn");
+}
+
+#[test]
+fn test_molding_checks_closure_parameters() {
+    let err = run_molding("safe fn test() {\n    let high_f = |x: u8| x\n}\n")
+        .expect_err("closure parameters follow Rule 5");
+    assert!(
+        err.contains("Rule 5 Violation: Variable 'x' outside unsafe must start with 'high_'."),
+        "{err}"
+    );
+    let err = run_molding("safe fn test() {\n    let high_f = |high_p: RawPtr| 1\n}\n")
+        .expect_err("closure parameter types follow Rule 3");
+    assert!(
+        err.contains("Rule 3 Violation: Unsafe type 'core::memory::raw::RawPtr'"),
+        "{err}"
+    );
+    let err = run_molding("safe fn test() {\n    let high_f = |high_x: u8, high_x: u8| 1\n}\n")
+        .expect_err("duplicate closure parameters");
+    assert!(
+        err.contains("Parameter 'high_x' is declared more than once in a closure."),
+        "{err}"
+    );
+
+    run_molding("safe fn test() {\n    let high_f = |high_x: u8| high_x\n    let high_x = 1\n}\n")
+        .expect("a parameter is scoped to its closure");
 }
 
 #[test]
@@ -158,6 +286,57 @@ safe fn test() {
     assert!(run_molding(code).is_ok());
 }
 
+#[test]
+fn test_molding_allows_validating_calls_for_validated_bindings() {
+    let code = r#"
+raw fn is_packet(raw_pkt: RawPtr, raw_len: usize) -> bool {
+    raw_read(raw_pkt, 0) == 1
+}
+
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_res = validate_raw_with(raw_x, is_packet)
+        let validated_x = validation_unwrap(validated_res)
+        let validated_sized = validate_raw_min_len(raw_x, 4)
+        let high_x = into_high(validated_x)
+    }
+}
+"#;
+
+    assert!(run_molding(code).is_ok());
+}
+
+#[test]
+fn test_molding_rejects_validation_unwrap_of_raw_value() {
+    let code = r#"
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_x = validation_unwrap(raw_x)
+    }
+}
+"#;
+
+    let err = run_molding(code).expect_err("validation_unwrap needs a validated_ source");
+    assert!(err.contains("validation_unwrap() must use a validated_ value"));
+}
+
+#[test]
+fn test_molding_rejects_validated_binding_from_other_call() {
+    let code = r#"
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_x = raw_read(raw_x, 0)
+    }
+}
+"#;
+
+    let err = run_molding(code).expect_err("validated_ must come from a validating call");
+    assert!(err.contains("Rule 6 Violation"));
+}
+
 #[test]
 fn test_molding_normalizes_builtin_calls_to_fully_qualified_names() {
     let code = r#"
@@ -202,3 +381,391 @@ safe fn test() {
     };
     assert_eq!(raw_write_call.func_name, "core::memory::raw::write");
 }
+
+#[test]
+fn test_molding_rule_levels_downgrade_or_silence_violations() {
+    let code = r#"
+safe fn test() {
+    let y = 3
+}
+"#;
+
+    let err = Molder::new(parse(code))
+        .mold()
+        .expect_err("default is error");
+    assert!(err.contains("Rule 5 Violation"));
+
+    let rules = RuleConfig {
+        rule5: RuleLevel::Warn,
+        ..RuleConfig::default()
+    };
+    let mut molder = Molder::new(parse(code)).with_rules(rules);
+    molder.mold().expect("warn does not fail molding");
+    let warnings = molder.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("Rule 5 Violation"));
+
+    let rules = RuleConfig {
+        rule5: RuleLevel::Off,
+        ..RuleConfig::default()
+    };
+    let mut molder = Molder::new(parse(code)).with_rules(rules);
+    molder.mold().expect("off does not fail molding");
+    assert!(molder.warnings().is_empty());
+}
+
+#[test]
+fn test_molding_shadowing_lint_is_off_by_default() {
+    let code = r#"
+safe fn test() {
+    let high_x = 1
+    if true {
+        let high_x = "inner"
+    }
+}
+"#;
+
+    Molder::new(parse(code))
+        .mold()
+        .expect("shadowing an enclosing binding is allowed by default");
+
+    let rules = RuleConfig {
+        rule4: RuleLevel::Warn,
+        ..RuleConfig::default()
+    };
+    let mut molder = Molder::new(parse(code)).with_rules(rules);
+    molder.mold().expect("warn does not fail molding");
+    let warnings = molder.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(
+        warnings[0].contains(
+            "Rule 4 Violation: Variable 'high_x' shadows a binding of an enclosing block."
+        )
+    );
+}
+
+#[test]
+fn test_molding_loads_configured_alias_files() {
+    let dir = std::env::temp_dir().join(format!("safe_alias_files_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create dir");
+    let aliases = dir.join("aliases.safe");
+    std::fs::write(
+        &aliases,
+        "// project aliases\nalias alloc = allocate_buffer\n",
+    )
+    .expect("write");
+
+    let code = r#"
+safe fn test() {
+    let high_buf = alloc(4)
+}
+"#;
+    let mut molder = Molder::new(parse(code)).with_alias_files(vec![AliasFile::new(aliases)]);
+    molder.mold().expect("alias file should load");
+    let Item::Function(func) = &molder.get_output().items[0] else {
+        panic!("expected function");
+    };
+    let Statement::Let(binding) = &func.body.statements[0] else {
+        panic!("expected let");
+    };
+    let Expression::Call(call) = &binding.value else {
+        panic!("expected call");
+    };
+    assert_eq!(call.func_name, "core::memory::safe::allocate_buffer");
+
+    let missing = dir.join("missing.safe");
+    let err = Molder::new(parse(code))
+        .with_alias_files(vec![AliasFile::new(missing)])
+        .mold()
+        .expect_err("configured alias files must exist");
+    assert!(err.contains("Failed to read alias file"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_molding_namespaced_alias_files_and_diagnostics() {
+    let dir = std::env::temp_dir().join(format!("safe_alias_namespaces_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create dir");
+    let mem = dir.join("mem.safe");
+    let text = dir.join("text.safe");
+    std::fs::write(
+        &mem,
+        "alias alloc = allocate_buffer\nalias free = deallocate_buffer\n",
+    )
+    .expect("write mem");
+    std::fs::write(&text, "\nalias len = string_len\n").expect("write text");
+
+    let code = r#"
+safe fn test() {
+    let high_buf = mem::alloc(4)
+    mem::free(high_buf)
+    let high_n = text::len(&"ab")
+}
+"#;
+    let files = vec![
+        AliasFile::namespaced(&mem, "mem"),
+        AliasFile::namespaced(&text, "text"),
+    ];
+    let output = {
+        let mut molder = Molder::new(parse(code)).with_alias_files(files);
+        molder.mold().expect("namespaced aliases should resolve");
+        molder.get_output().clone()
+    };
+    let Item::Function(func) = &output.items[0] else {
+        panic!("expected function");
+    };
+    let Statement::Expr(Expression::Call(call)) = &func.body.statements[1] else {
+        panic!("expected call");
+    };
+    assert_eq!(call.func_name, "core::memory::safe::deallocate_buffer");
+
+    let err = Molder::new(parse(code))
+        .with_alias_files(vec![AliasFile::new(&mem), AliasFile::new(&mem)])
+        .mold()
+        .expect_err("same file twice without namespaces");
+    let first = format!("{}:1", mem.display());
+    assert!(err.contains("Duplicate alias 'alloc'"), "{err}");
+    assert!(err.contains(&format!("first defined at {first}")), "{err}");
+
+    std::fs::write(&text, "alias len = string_len\nalias bad len = x\n").expect("rewrite");
+    let err = Molder::new(parse(code))
+        .with_alias_files(vec![AliasFile::new(&text)])
+        .mold()
+        .expect_err("invalid alias line");
+    assert!(err.contains(&format!("Invalid alias syntax at {}:2", text.display())));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_molding_expands_type_aliases_in_signatures_and_bindings() {
+    let code = r#"
+alias Buf = HighPtr
+alias Len = usize
+
+safe fn fill(high_buf: Buf, high_len: Len) -> Buf {
+    let high_copy: Buf = high_buf
+    let high_wide = high_len as Len
+    let high_narrow = checked_cast::<Len>(high_wide)
+    high_copy
+}
+"#;
+    let output = run_molding_output(code).expect("type aliases should expand");
+    let Item::Function(func) = &output.items[0] else {
+        panic!("expected function");
+    };
+    assert_eq!(
+        func.args[0].ty,
+        Type::Path("core::memory::safe::HighPtr".to_string())
+    );
+    assert_eq!(func.args[1].ty, Type::Path("usize".to_string()));
+    assert_eq!(
+        func.ret_type,
+        Some(Type::Path("core::memory::safe::HighPtr".to_string()))
+    );
+    let Statement::Let(binding) = &func.body.statements[0] else {
+        panic!("expected let");
+    };
+    assert_eq!(
+        binding.ty,
+        Some(Type::Path("core::memory::safe::HighPtr".to_string()))
+    );
+    let Statement::Let(cast) = &func.body.statements[1] else {
+        panic!("expected let");
+    };
+    let Expression::Cast { ty, .. } = &cast.value else {
+        panic!("expected cast");
+    };
+    assert_eq!(*ty, Type::Path("usize".to_string()));
+    let Statement::Let(call) = &func.body.statements[2] else {
+        panic!("expected let");
+    };
+    let Expression::Call(call) = &call.value else {
+        panic!("expected call");
+    };
+    assert_eq!(call.type_args, vec![Type::Path("usize".to_string())]);
+
+    let err = run_molding("alias Raw = RawPtr\nsafe fn leak(high_p: Raw) {}\n")
+        .expect_err("alias does not hide unsafe types");
+    assert!(err.contains("Rule 3 Violation"));
+
+    let err = run_molding("alias A = B\nalias B = A\nsafe fn f(high_a: A) {}\n")
+        .expect_err("type alias cycle");
+    assert!(
+        err.contains("Alias cycle detected") && err.contains("line "),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_molding_expands_generic_type_aliases() {
+    let code = r#"
+alias Bytes = core::types::List
+alias Res<T> = core::types::Result<T, i32>
+alias Pair<A, B> = core::types::Result<A, Res<B>>
+
+safe fn parse(high_input: Bytes) -> Res<Res<u8>> {
+    let high_nested: Pair<u8, Bytes> = probe()
+    probe()
+}
+"#;
+    // Structs have no source syntax yet; add one to the parsed file.
+    let mut source = parse(code);
+    let field = |name: &str, ty: &str| StructField {
+        name: name.to_string(),
+        ty: Type::Path(ty.to_string()),
+    };
+    source.items.insert(
+        0,
+        Item::Struct(Struct {
+            name: "Packet".to_string(),
+            fields: vec![field("high_body", "Bytes"), field("high_status", "Res<u8>")],
+            attributes: Vec::new(),
+        }),
+    );
+    let mut molder = Molder::new(source);
+    molder.mold().expect("generic aliases should expand");
+    let output = molder.get_output();
+    let Item::Struct(def) = &output.items[0] else {
+        panic!("expected struct");
+    };
+    let path = |s: &str| Type::Path(s.to_string());
+    assert_eq!(def.fields[0].ty, path("core::types::List"));
+    assert_eq!(def.fields[1].ty, path("core::types::Result<u8, i32>"));
+
+    let Item::Function(func) = &output.items[1] else {
+        panic!("expected function");
+    };
+    assert_eq!(func.args[0].ty, path("core::types::List"));
+    assert_eq!(
+        func.ret_type,
+        Some(path(
+            "core::types::Result<core::types::Result<u8, i32>, i32>"
+        ))
+    );
+    let Statement::Let(binding) = &func.body.statements[0] else {
+        panic!("expected let");
+    };
+    assert_eq!(
+        binding.ty,
+        Some(path(
+            "core::types::Result<u8, core::types::Result<core::types::List, i32>>"
+        ))
+    );
+
+    let err =
+        run_molding("alias Res<T> = core::types::Result<T, i32>\nsafe fn f() -> Res<u8, u8> {}\n")
+            .expect_err("wrong number of generic arguments");
+    assert!(
+        err.contains("Type alias 'Res' expects 1 generic argument(s), found 2 (defined at line 1)"),
+        "{err}"
+    );
+    let err =
+        run_molding("alias Res<T> = core::types::Result<T, i32>\nsafe fn f(high_r: Res) {}\n")
+            .expect_err("generic alias needs arguments");
+    assert!(err.contains("found 0"), "{err}");
+    let err = run_molding("alias Res<T, T> = core::types::Result<T, T>\n")
+        .expect_err("duplicate generic parameter");
+    assert!(err.contains("Duplicate generic parameter 'T'"), "{err}");
+}
+
+#[test]
+fn test_molding_generic_alias_cannot_hide_unsafe_types() {
+    let code = "alias Wrap<T> = core::types::Option<T>\nalias Raw = RawPtr\n\
+                safe fn leak(high_p: &Raw) {}\n";
+    let err = run_molding(code).expect_err("unsafe alias target in safe signature");
+    assert!(
+        err.contains("Rule 3 Violation") && err.contains("via alias 'Raw' at line 2"),
+        "{err}"
+    );
+
+    let err = run_molding("alias Ptr<T> = *T\nsafe fn leak(high_p: Ptr<u8>) {}\n")
+        .expect_err("pointer alias in safe signature");
+    assert!(
+        err.contains("'*u8'") && err.contains("via alias 'Ptr'"),
+        "{err}"
+    );
+
+    run_molding("alias Raw = RawPtr\nraw fn keep(raw_p: Raw) {}\n")
+        .expect("raw functions may use unsafe aliases");
+}
+
+#[test]
+fn test_molding_loads_generic_aliases_from_files() {
+    let dir = std::env::temp_dir().join(format!("safe_alias_generic_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create dir");
+    let file = dir.join("types.safe");
+    std::fs::write(&file, "alias Res<T> = core::types::Result<T, i32>\n").expect("write");
+
+    let mut molder = Molder::new(parse("safe fn f(high_r: ty::Res<u8>) {}\n"))
+        .with_alias_files(vec![AliasFile::namespaced(&file, "ty")]);
+    molder.mold().expect("generic file alias should expand");
+    let Item::Function(func) = &molder.get_output().items[0] else {
+        panic!("expected function");
+    };
+    assert_eq!(
+        func.args[0].ty,
+        Type::Path("core::types::Result<u8, i32>".to_string())
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_molding_allow_attribute_suppresses_rules_in_scope() {
+    let code = r#"
+#[allow(rule5)]
+safe fn lenient() {
+    let count = 1
+}
+
+safe fn strict() {
+    #[allow(rule4, rule5)]
+    if true {
+        let total = 1
+        let total = 2
+    }
+    let loose = 3
+}
+"#;
+    let err = run_molding(code).expect_err("allow ends with its statement");
+    assert_eq!(
+        err,
+        "Rule 5 Violation: Variable 'loose' outside unsafe must start with 'high_'."
+    );
+
+    let output = run_molding_output("#[test]\nsafe fn check() {}\n").expect("mold");
+    let Item::Function(func) = &output.items[0] else {
+        panic!("expected function");
+    };
+    assert!(func.is_test);
+}
+
+#[test]
+fn test_molding_rejects_unknown_and_misplaced_attributes() {
+    let err = run_molding("#[cold]\nsafe fn main() {}\n").expect_err("unknown");
+    assert_eq!(
+        err,
+        "Attribute Error: Unknown attribute '#[cold]' at line 1"
+    );
+
+    let err = run_molding("alias a = allocate_buffer\n#[inline]\nalias b = a\n")
+        .expect_err("inline on alias");
+    assert_eq!(
+        err,
+        "Attribute Error: '#[inline]' cannot be used on an alias (line 2)"
+    );
+
+    let err = run_molding("#[allow(rule9)]\nsafe fn main() {}\n").expect_err("unknown rule");
+    assert!(err.contains("Unknown rule 'rule9'"), "{err}");
+
+    let err = run_molding("safe fn main() {\n    #[allow(rule6)]\n    let high_x = 1\n}\n")
+        .expect_err("rule6 needs the opt-in");
+    assert!(err.contains("needs `allow_safety_rules = true`"), "{err}");
+
+    assert_eq!(
+        run_molding("safe fn main() {\n    #[allow(rule5)]\n    printl(\"x\")\n}\n"),
+        Err("Unconsumed tokens".to_string())
+    );
+}
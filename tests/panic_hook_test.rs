@@ -0,0 +1,49 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Owns the process-wide panic hook, so it lives in its own test binary.
+
+use safe_lang::core;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn test_expect_panic_only_silences_its_own_thread() {
+    panic::set_hook(Box::new(|_| {
+        REPORTED.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    let barrier = Arc::new(Barrier::new(9));
+    let quiet: Vec<_> = (0..8)
+        .map(|_| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                for _ in 0..50 {
+                    core::testing::expect_panic(|| panic!("quiet"), "main.safe:1");
+                }
+            })
+        })
+        .collect();
+    barrier.wait();
+    for _ in 0..50 {
+        let _ = panic::catch_unwind(|| panic!("loud"));
+    }
+    for handle in quiet {
+        handle.join().expect("no failed expectation");
+    }
+
+    // Every panic outside `expect_panic` reached the hook, none inside did,
+    // and the hook is still in place afterwards.
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 50);
+    let _ = panic::catch_unwind(|| panic!("loud"));
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 51);
+}
@@ -42,6 +42,128 @@ fn test_validate_raw_into_high_roundtrip() {
     core::memory::safe::deallocate_buffer(high);
 }
 
+#[test]
+fn test_validate_raw_checked_reports_memory_errors() {
+    use core::memory::MemoryError;
+    use core::memory::safe::{validate_raw_checked, validate_raw_min_len};
+
+    let raw = unsafe { core::memory::raw::alloc(4) };
+    unsafe { core::memory::raw::write(raw, 0, 0x7f) };
+
+    let tagged = validate_raw_checked(raw, |bytes| bytes[0] == 0x7f);
+    assert!(tagged.is_ok());
+    let wrong_tag = validate_raw_checked(raw, |bytes| bytes[0] == 0x01);
+    assert_eq!(wrong_tag.unwrap_err(), MemoryError::Rejected);
+    assert!(validate_raw_min_len(raw, 4).is_ok());
+    assert_eq!(
+        validate_raw_min_len(raw, 5).unwrap_err(),
+        MemoryError::Rejected
+    );
+
+    let high = into_high(tagged.unwrap());
+    assert_eq!(
+        validate_raw_min_len(raw, 1).unwrap_err(),
+        MemoryError::Untracked
+    );
+    core::memory::safe::deallocate_buffer(high);
+}
+
+#[test]
+#[should_panic(expected = "validation_unwrap on a failed validation")]
+fn test_validation_unwrap_panics_on_failed_validation() {
+    let raw = unsafe { core::memory::raw::alloc(4) };
+    let rejected = core::memory::safe::validate_raw_min_len(raw, 5);
+    core::memory::safe::validation_unwrap(rejected);
+}
+
+unsafe fn is_length_prefixed(raw: core::memory::raw::RawPtr, size: usize) -> bool {
+    unsafe { core::memory::raw::read(raw, 0) as usize + 1 == size }
+}
+
+#[test]
+fn test_raw_validate_with_runs_predicate_on_pointer_and_size() {
+    let raw = unsafe { core::memory::raw::alloc(4) };
+    unsafe { core::memory::raw::write(raw, 0, 2) };
+    let rejected = unsafe { core::memory::raw::validate_with(raw, is_length_prefixed) };
+    assert_eq!(
+        core::memory::safe::validation_error(&rejected),
+        core::memory::MemoryError::Rejected
+    );
+
+    unsafe { core::memory::raw::write(raw, 0, 3) };
+    let accepted = unsafe { core::memory::raw::validate_with(raw, is_length_prefixed) };
+    assert!(core::memory::safe::validation_is_ok(&accepted));
+    let high = into_high(core::memory::safe::validation_unwrap(accepted));
+    core::memory::safe::deallocate_buffer(high);
+}
+
+#[test]
+fn test_try_memory_apis_report_errors_instead_of_panicking() {
+    use core::memory::MemoryError;
+    use core::memory::safe::{
+        try_allocate_buffer, try_deallocate_buffer, try_read_u8, try_write_u8,
+    };
+
+    assert_eq!(try_allocate_buffer(0).unwrap_err(), MemoryError::ZeroSize);
+
+    let high = try_allocate_buffer(2).unwrap();
+    assert!(try_write_u8(high, 1, 9).is_ok());
+    assert_eq!(try_read_u8(high, 1).unwrap(), 9);
+    assert_eq!(try_read_u8(high, 2).unwrap_err(), MemoryError::OutOfBounds);
+    assert_eq!(
+        try_write_u8(high, usize::MAX, 0).unwrap_err(),
+        MemoryError::Overflow
+    );
+
+    assert!(try_deallocate_buffer(high).is_ok());
+    assert_eq!(
+        try_deallocate_buffer(high).unwrap_err(),
+        MemoryError::DoubleFree
+    );
+    assert_eq!(try_read_u8(high, 0).unwrap_err(), MemoryError::Untracked);
+    assert_eq!(core::memory::memory_error_code(MemoryError::DoubleFree), 5);
+}
+
+#[test]
+fn test_released_addresses_are_remembered_up_to_a_bound() {
+    use core::memory::safe::{FREED_HISTORY, allocate_buffer, deallocate_buffer, remembered_frees};
+
+    let buffers = (0..FREED_HISTORY + 16)
+        .map(|_| allocate_buffer(8))
+        .collect::<Vec<_>>();
+    for high in buffers {
+        deallocate_buffer(high);
+    }
+    assert!(remembered_frees() <= FREED_HISTORY);
+}
+
+#[test]
+fn test_try_result_can_be_checked_then_unwrapped() {
+    use core::memory::safe::{try_allocate_buffer, try_deallocate_buffer};
+    use core::types::{result_is_ok, result_unwrap};
+
+    // Generated code passes a SAFE binding by value to each accessor.
+    let res = try_allocate_buffer(4);
+    assert!(result_is_ok(res));
+    let high = result_unwrap(res);
+    assert!(result_is_ok(try_deallocate_buffer(high)));
+}
+
+#[test]
+fn test_try_validate_raw_and_into_high() {
+    use core::memory::safe::{try_deallocate_buffer, try_into_high, try_validate_raw};
+
+    let raw = unsafe { core::memory::raw::alloc(1) };
+    let validated = try_validate_raw(raw).unwrap();
+    let high = try_into_high(validated).unwrap();
+    assert_eq!(raw.addr(), high.addr());
+    assert!(try_deallocate_buffer(high).is_ok());
+    assert_eq!(
+        try_into_high(validated).unwrap_err(),
+        core::memory::MemoryError::Untracked
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_deallocate_buffer_releases_allocation() {
@@ -49,3 +171,214 @@ fn test_deallocate_buffer_releases_allocation() {
     core::memory::safe::deallocate_buffer(ptr);
     core::memory::safe::deallocate_buffer(ptr);
 }
+
+#[test]
+fn test_high_typed_reads_and_writes_respect_endianness() {
+    let ptr = core::memory::safe::allocate_buffer(8);
+    core::memory::safe::write_u16_le(ptr, 0, 0x1234);
+    core::memory::safe::write_u16_be(ptr, 2, 0x1234);
+    assert_eq!(core::memory::safe::read_u8(ptr, 0), 0x34);
+    assert_eq!(core::memory::safe::read_u8(ptr, 2), 0x12);
+    assert_eq!(core::memory::safe::read_u16_le(ptr, 0), 0x1234);
+    assert_eq!(core::memory::safe::read_u16_be(ptr, 2), 0x1234);
+
+    core::memory::safe::write_u32_be(ptr, 4, 0xdead_beef);
+    assert_eq!(core::memory::safe::read_u32_be(ptr, 4), 0xdead_beef);
+    assert_eq!(core::memory::safe::read_u32_le(ptr, 4), 0xefbe_adde);
+
+    core::memory::safe::write_u64_le(ptr, 0, 0x0102_0304_0506_0708);
+    assert_eq!(
+        core::memory::safe::read_u64_le(ptr, 0),
+        0x0102_0304_0506_0708
+    );
+    assert_eq!(
+        core::memory::safe::read_u64_be(ptr, 0),
+        0x0807_0605_0403_0201
+    );
+    core::memory::safe::deallocate_buffer(ptr);
+}
+
+#[test]
+#[should_panic(expected = "high ptr range out of bounds")]
+fn test_high_typed_read_rejects_partial_overlap_with_end() {
+    let ptr = core::memory::safe::allocate_buffer(3);
+    core::memory::safe::read_u32_le(ptr, 0);
+}
+
+#[test]
+fn test_high_copy_and_fill() {
+    let src = core::memory::safe::allocate_buffer(4);
+    let dst = core::memory::safe::allocate_buffer(6);
+    core::memory::safe::fill(src, 0, 4, 7);
+    core::memory::safe::copy_from(dst, 2, src, 0, 4);
+    assert_eq!(core::memory::safe::read_u8(dst, 1), 0);
+    assert_eq!(core::memory::safe::read_u32_le(dst, 2), 0x0707_0707);
+
+    core::memory::safe::write_u8(dst, 0, 9);
+    core::memory::safe::copy_to(dst, 0, src, 3, 1);
+    assert_eq!(core::memory::safe::read_u8(src, 3), 9);
+
+    core::memory::safe::deallocate_buffer(src);
+    core::memory::safe::deallocate_buffer(dst);
+}
+
+#[test]
+#[should_panic(expected = "high ptr range out of bounds")]
+fn test_high_copy_rejects_source_overrun() {
+    let src = core::memory::safe::allocate_buffer(2);
+    let dst = core::memory::safe::allocate_buffer(8);
+    core::memory::safe::copy_from(dst, 0, src, 1, 2);
+}
+
+#[test]
+#[should_panic(expected = "high ptr is invalid")]
+fn test_high_zero_length_copy_rejects_freed_source() {
+    let src = core::memory::safe::allocate_buffer(2);
+    let dst = core::memory::safe::allocate_buffer(2);
+    core::memory::safe::deallocate_buffer(src);
+    core::memory::safe::copy_from(dst, 0, src, 0, 0);
+}
+
+#[test]
+fn test_zero_length_copy_and_fill_accept_the_end_of_a_buffer() {
+    let ptr = core::memory::safe::allocate_buffer(2);
+    core::memory::safe::copy_from(ptr, 2, ptr, 0, 0);
+    core::memory::safe::fill(ptr, 2, 0, 1);
+    core::memory::safe::deallocate_buffer(ptr);
+}
+
+#[test]
+#[should_panic(expected = "raw::fill ptr is invalid")]
+fn test_raw_zero_length_fill_rejects_freed_pointer() {
+    unsafe {
+        let ptr = core::memory::raw::alloc(2);
+        core::memory::raw::deallocate(ptr);
+        core::memory::raw::fill(ptr, 0, 0, 1);
+    }
+}
+
+#[test]
+fn test_raw_typed_access_copy_and_fill() {
+    unsafe {
+        let a = core::memory::raw::alloc(8);
+        let b = core::memory::raw::alloc(8);
+        core::memory::raw::write_u64_be(a, 0, 0x1122_3344_5566_7788);
+        assert_eq!(core::memory::raw::read(a, 0), 0x11);
+        assert_eq!(core::memory::raw::read_u16_be(a, 6), 0x7788);
+        assert_eq!(core::memory::raw::read_u32_le(a, 0), 0x4433_2211);
+
+        core::memory::raw::fill(b, 0, 8, 0xff);
+        core::memory::raw::copy_to(a, 2, b, 4, 2);
+        assert_eq!(core::memory::raw::read_u32_be(b, 4), 0x3344_ffff);
+
+        core::memory::raw::write_u16_le(b, 0, 0xabcd);
+        core::memory::raw::copy_from(b, 1, b, 0, 2);
+        assert_eq!(core::memory::raw::read_u16_le(b, 1), 0xabcd);
+
+        core::memory::raw::deallocate(a);
+        core::memory::raw::deallocate(b);
+    }
+}
+
+#[test]
+#[should_panic(expected = "raw::write_u32_le offset out of bounds")]
+fn test_raw_typed_write_rejects_out_of_bounds() {
+    let ptr = unsafe { core::memory::raw::alloc(4) };
+    unsafe {
+        core::memory::raw::write_u32_le(ptr, 1, 0);
+    }
+}
+
+#[test]
+fn test_high_view_accessors_are_relative_to_view() {
+    let ptr = core::memory::safe::allocate_buffer(8);
+    let whole = core::memory::safe::view_of(ptr);
+    assert_eq!(core::memory::safe::view_len(whole), 8);
+
+    let header = core::memory::safe::view_head(whole, 2);
+    let payload = core::memory::safe::view_tail(whole, 2);
+    assert_eq!(payload.offset(), 2);
+    assert_eq!(payload.len(), 6);
+
+    core::memory::safe::view_write_u16_be(header, 0, 0x0102);
+    core::memory::safe::view_write_u32_le(payload, 0, 0xaabb_ccdd);
+    assert_eq!(core::memory::safe::read_u8(ptr, 0), 0x01);
+    assert_eq!(core::memory::safe::read_u8(ptr, 2), 0xdd);
+    assert_eq!(core::memory::safe::view_read_u8(payload, 3), 0xaa);
+
+    let inner = core::memory::safe::view_sub(payload, 1, 2);
+    assert_eq!(core::memory::safe::view_read_u16_le(inner, 0), 0xbbcc);
+
+    core::memory::safe::view_fill(inner, 0);
+    assert_eq!(
+        core::memory::safe::view_read_u32_le(payload, 0),
+        0xaa00_00dd
+    );
+
+    core::memory::safe::view_copy_from(payload, 4, header);
+    assert_eq!(core::memory::safe::read_u16_be(ptr, 6), 0x0102);
+    core::memory::safe::deallocate_buffer(ptr);
+}
+
+#[test]
+#[should_panic(expected = "high view range out of bounds")]
+fn test_high_view_rejects_read_past_view_end() {
+    let ptr = core::memory::safe::allocate_buffer(8);
+    let header = core::memory::safe::view_range(ptr, 0, 2);
+    core::memory::safe::view_read_u8(header, 2);
+}
+
+#[test]
+#[should_panic(expected = "high view range out of bounds")]
+fn test_high_view_sub_cannot_widen() {
+    let ptr = core::memory::safe::allocate_buffer(8);
+    let payload = core::memory::safe::view_range(ptr, 4, 2);
+    core::memory::safe::view_sub(payload, 0, 4);
+}
+
+#[test]
+#[should_panic(expected = "high ptr range out of bounds")]
+fn test_high_view_range_must_fit_allocation() {
+    let ptr = core::memory::safe::allocate_buffer(4);
+    core::memory::safe::view_range(ptr, 2, 3);
+}
+
+#[test]
+#[should_panic(expected = "high ptr is invalid")]
+fn test_high_view_detects_released_allocation() {
+    let ptr = core::memory::safe::allocate_buffer(4);
+    let view = core::memory::safe::view_of(ptr);
+    core::memory::safe::deallocate_buffer(ptr);
+    core::memory::safe::view_read_u8(view, 0);
+}
+
+#[test]
+fn test_typed_promotion_chain_wraps_runtime_pointers() {
+    use safe_lang::type_system::{Raw, into_high_ptr, validate_raw_ptr};
+
+    let raw = Raw::new(unsafe { core::memory::raw::alloc(2) });
+    let addr = unsafe { raw.get() }.addr();
+    let validated = validate_raw_ptr(raw);
+    assert_eq!(validated.level(), 1);
+    let high = into_high_ptr(validated);
+    assert_eq!(high.level(), 2);
+    let copy = high;
+    assert_eq!(copy.get().addr(), addr);
+    core::memory::safe::deallocate_buffer(high.unwrap());
+}
+
+#[test]
+fn test_testing_assertions_report_safe_locations() {
+    core::testing::assert_eq(2, 2, "main.safe:3");
+    core::testing::expect_panic(
+        || core::testing::assert(false, "main.safe:4"),
+        "main.safe:5",
+    );
+
+    let err = std::panic::catch_unwind(|| core::testing::expect_panic(|| 1, "main.safe:6"))
+        .expect_err("no panic inside");
+    assert_eq!(
+        err.downcast_ref::<String>().map(String::as_str),
+        Some("expected a panic at main.safe:6")
+    );
+}
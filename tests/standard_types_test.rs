@@ -6,15 +6,15 @@
 // See: https://opensource.org/licenses/MIT
 
 use safe_lang::core::types::{
-    List, String as SafeString, list_get_u8, list_is_empty, list_len, list_new, list_push_bytes,
-    list_push_u8, string_append_bytes, string_clear, string_clear_with_capacity, string_clone,
-    string_concat, string_contains, string_ends_with, string_eq, string_from_list,
-    string_insert_bytes, string_is_empty, string_len, string_list_get, string_list_is_empty,
-    string_list_len, string_new, string_pop, string_pop_n, string_push, string_push_bytes,
-    string_push_str, string_remove, string_remove_range, string_replace, string_split_all,
-    string_split_found, string_split_left, string_split_n, string_split_once, string_split_right,
-    string_starts_with, string_substr, string_to_list, string_trim, string_trim_end,
-    string_trim_start,
+    List, Option, String as SafeString, checked_cast, list_get_u8, list_is_empty, list_len,
+    list_new, list_push_bytes, list_push_u8, saturating_cast, string_append_bytes, string_clear,
+    string_clear_with_capacity, string_clone, string_concat, string_contains, string_ends_with,
+    string_eq, string_from_list, string_insert_bytes, string_is_empty, string_len, string_list_get,
+    string_list_is_empty, string_list_len, string_new, string_pop, string_pop_n, string_push,
+    string_push_bytes, string_push_str, string_remove, string_remove_range, string_replace,
+    string_split_all, string_split_found, string_split_left, string_split_n, string_split_once,
+    string_split_right, string_starts_with, string_substr, string_to_list, string_trim,
+    string_trim_end, string_trim_start, wrapping_cast,
 };
 
 #[test]
@@ -201,3 +201,16 @@ fn test_safe_string_push_bytes_insert_remove_range_and_clear_cap() {
     string_clear_with_capacity(&mut s);
     assert!(string_is_empty(&s));
 }
+
+#[test]
+fn test_integer_casts() {
+    assert_eq!(checked_cast::<u8, i32>(255), Option::Some(255));
+    assert_eq!(checked_cast::<u8, i32>(256), Option::None);
+    assert_eq!(checked_cast::<i8, u64>(u64::MAX), Option::None);
+    assert_eq!(wrapping_cast::<u8, i32>(300), 44);
+    assert_eq!(wrapping_cast::<i8, u8>(200), -56);
+    assert_eq!(wrapping_cast::<u64, i64>(-1), u64::MAX);
+    assert_eq!(saturating_cast::<i8, i32>(-300), i8::MIN);
+    assert_eq!(saturating_cast::<u32, i64>(-5), 0);
+    assert_eq!(saturating_cast::<usize, u8>(7), 7);
+}
@@ -84,6 +84,400 @@ safe fn test() {
     assert!(run_pipeline(code).is_err());
 }
 
+#[test]
+fn test_integer_literal_takes_type_from_later_use() {
+    let code = r#"
+safe fn test() {
+    let high_n = 4
+    let high_buf = allocate_buffer(high_n)
+    let high_len: usize = high_n
+    for high_i in 0..high_n {
+        buffer_write_u8(high_buf, high_i, 7)
+    }
+    deallocate_buffer(high_buf)
+}
+"#;
+    let result = run_pipeline(code);
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_unconstrained_integer_literal_defaults_to_i32() {
+    let ok = r#"
+safe fn test() {
+    let high_x = 7
+    let high_y: i32 = high_x
+}
+"#;
+    assert!(run_pipeline(ok).is_ok());
+
+    let code = r#"
+safe fn test() {
+    let high_x = 3000000000
+}
+"#;
+    let err = run_pipeline(code).expect_err("literal does not fit the default");
+    assert!(err.contains("Integer literal 3000000000 at line 3 does not fit in i32"));
+}
+
+#[test]
+fn test_conflicting_integer_literal_uses_name_both_sites() {
+    let code = r#"
+safe fn test() {
+    let high_n = 4
+    let high_buf = allocate_buffer(high_n)
+    let high_byte: u8 = high_n
+}
+"#;
+    let err = run_pipeline(code).expect_err("usize and u8 conflict");
+    assert!(err.contains("integer literal at line 3"), "{err}");
+    assert!(
+        err.contains("allocate_buffer' (line 4) expects usize"),
+        "{err}"
+    );
+    assert!(
+        err.contains("the type of 'high_byte' (line 5) expects u8"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_integer_literal_out_of_range_is_error() {
+    let code = r#"
+safe fn test() {
+    let high_byte: u8 = 256
+}
+"#;
+    let err = run_pipeline(code).expect_err("256 is not a u8");
+    assert!(err.contains("does not fit in u8"));
+}
+
+#[test]
+fn test_implicit_integer_narrowing_is_error() {
+    let code = r#"
+safe fn test(high_wide: i32) {
+    let high_byte: u8 = high_wide
+}
+"#;
+    let err = run_pipeline(code).expect_err("i32 does not convert to u8");
+    assert!(err.contains("declared as u8 but assigned i32"), "{err}");
+    assert!(err.contains("use checked_cast, wrapping_cast or saturating_cast"));
+}
+
+#[test]
+fn test_as_cast_allows_widening_only() {
+    let widening = r#"
+safe fn test(high_byte: u8) -> i64 {
+    high_byte as i64
+}
+"#;
+    assert!(run_pipeline(widening).is_ok());
+
+    let narrowing = r#"
+safe fn test(high_wide: u16) -> i8 {
+    high_wide as i8
+}
+"#;
+    let err = run_pipeline(narrowing).expect_err("u16 as i8 is lossy");
+    assert!(
+        err.contains("Narrowing cast from u16 to i8 may lose data"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_pointer_sized_casts_are_lossless_at_32_and_64_bits() {
+    let widening = r#"
+safe fn test(high_len: usize, high_small: u32) -> u64 {
+    let high_wide: usize = high_small as usize
+    high_len as u64
+}
+"#;
+    assert!(run_pipeline(widening).is_ok());
+
+    for (from, to) in [
+        ("u64", "usize"),
+        ("usize", "u32"),
+        ("i64", "isize"),
+        ("u32", "isize"),
+    ] {
+        let code = format!("safe fn test(high_x: {from}) -> {to} {{\n    high_x as {to}\n}}\n");
+        let err = run_pipeline(&code).expect_err("lossy on some target");
+        assert!(
+            err.contains(&format!("Narrowing cast from {from} to {to} may lose data")),
+            "{err}"
+        );
+    }
+
+    let literal = r#"
+safe fn test() -> usize {
+    5000000000
+}
+"#;
+    let err = run_pipeline(literal).expect_err("does not fit in 32 bits");
+    assert!(err.contains("does not fit in usize"), "{err}");
+}
+
+#[test]
+fn test_cast_functions_take_the_target_type_argument() {
+    let code = r#"
+safe fn test(high_wide: i64) -> u8 {
+    let high_checked: Option<u8> = checked_cast::<u8>(high_wide)
+    let high_wrapped: u8 = wrapping_cast::<u8>(high_wide)
+    saturating_cast::<u8>(high_wide)
+}
+"#;
+    assert!(run_pipeline(code).is_ok());
+
+    let missing = r#"
+safe fn test(high_wide: i64) -> u8 {
+    saturating_cast(high_wide)
+}
+"#;
+    let err = run_pipeline(missing).expect_err("no target type");
+    assert!(err.contains("takes one type argument"), "{err}");
+}
+
+#[test]
+fn test_tuples_destructure_and_index() {
+    let code = r#"
+safe fn split(high_len: usize) -> (u8, usize) {
+    (7, high_len)
+}
+
+safe fn test() -> bool {
+    let (high_tag, high_used) = split(3)
+    let high_byte: u8 = high_tag
+    let high_nested: (u8, (bool, i64)) = (1, (true, -5))
+    high_nested.1.0
+}
+"#;
+    run_pipeline(code).expect("tuples type check");
+
+    let too_many = r#"
+safe fn test() {
+    let (high_a, high_b, high_c) = (1, 2)
+}
+"#;
+    let err = run_pipeline(too_many).expect_err("two elements, three names");
+    assert!(
+        err.contains("Cannot destructure ({integer}, {integer}) into 3 names"),
+        "{err}"
+    );
+
+    let out_of_range = r#"
+safe fn test() {
+    let high_pair = (1, true)
+    let high_x = high_pair.2
+}
+"#;
+    let err = run_pipeline(out_of_range).expect_err("no third element");
+    assert!(err.contains("Tuple index 2 out of range"), "{err}");
+}
+
+#[test]
+fn test_enums_match_and_if_let() {
+    let code = r#"
+enum PacketKind {
+    Ping,
+    Data(u16),
+    Error(i32, bool),
+}
+
+safe fn code(high_kind: PacketKind) -> i32 {
+    match high_kind {
+        PacketKind::Ping => { 0 }
+        PacketKind::Data(high_len) => { high_len as i32 }
+        PacketKind::Error(high_code, _) => { high_code }
+    }
+}
+
+safe fn test() {
+    let high_code = code(PacketKind::Data(512))
+    if let PacketKind::Error(high_c, high_fatal) = PacketKind::Error(-1, true) {
+        let high_flag: bool = high_fatal
+    }
+}
+"#;
+    run_pipeline(code).expect("enums type check");
+
+    let enum_k = "enum K {\n    A,\n    B(u8),\n}\n";
+    let cases = [
+        (
+            "safe fn test() {\n    match K::A {\n        K::A => {}\n    }\n}\n",
+            "Non-exhaustive match on K: missing K::B",
+        ),
+        (
+            "safe fn test() {\n    match K::A {\n        _ => {}\n        K::A => {}\n    }\n}\n",
+            "Unreachable match arm 'K::A'",
+        ),
+        (
+            "safe fn test() {\n    let high_k = K::B(300)\n}\n",
+            "Integer literal 300 at line 7 does not fit in u8",
+        ),
+        (
+            "safe fn test() {\n    let high_k = K::C\n}\n",
+            "Enum 'K' has no variant 'C'",
+        ),
+        (
+            "safe fn test() {\n    match K::A {\n        K::B => {}\n        _ => {}\n    }\n}\n",
+            "Pattern 'K::B' binds 0 value(s), but the variant has 1",
+        ),
+        (
+            "safe fn test() {\n    let high_same = K::A == K::A\n}\n",
+            "Values of enum 'K' cannot be compared",
+        ),
+        (
+            "safe fn test() -> bool {\n    match K::A {\n        K::A => { true }\n        K::B(high_b) => { high_b }\n    }\n}\n",
+            "Match arms have different types: bool vs u8",
+        ),
+    ];
+    for (body, expected) in cases {
+        let err = run_pipeline(&format!("{enum_k}\n{body}")).expect_err(expected);
+        assert!(err.contains(expected), "{err}");
+    }
+}
+
+#[test]
+fn test_function_values_and_indirect_calls() {
+    let code = r#"
+safe fn double(high_x: u8) -> u8 {
+    high_x
+}
+
+raw fn peek(raw_x: u8) -> u8 {
+    raw_x
+}
+
+safe fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+raw fn apply_raw(raw_f: raw fn(u8) -> u8, raw_x: u8) -> u8 {
+    raw_f(raw_x)
+}
+
+safe fn test() {
+    let high_f: fn(u8) -> u8 = double
+    let high_v = apply(high_f, 7)
+    unsafe {
+        let raw_p = peek
+        let raw_v: u8 = raw_p(1)
+        let raw_w = apply_raw(double, 2)
+    }
+}
+"#;
+    run_pipeline(code).expect("function values type check");
+
+    let defs = "safe fn one(high_x: u8) -> u8 {\n    high_x\n}\n\nraw fn peek(raw_x: u8) -> u8 {\n    raw_x\n}\n\n";
+    let cases = [
+        (
+            "safe fn test() {\n    let high_f = peek\n}\n",
+            "'high_f' cannot hold a raw function (raw fn(u8) -> u8)",
+        ),
+        (
+            "safe fn test() {\n    let high_f: fn(u8) -> u8 = peek\n}\n",
+            "declared as fn(u8) -> u8 but assigned raw fn(u8) -> u8",
+        ),
+        (
+            "safe fn test() {\n    let high_x = 1\n    let high_y = high_x(2)\n}\n",
+            "'high_x' is not a function: it has type {integer}",
+        ),
+        (
+            "safe fn test() {\n    let high_f = one\n    let high_y = high_f(300)\n}\n",
+            "Integer literal 300 at line 11 does not fit in u8",
+        ),
+        (
+            "safe fn test() {\n    let high_f = one\n    let high_y = high_f()\n}\n",
+            "Arg count mismatch for 'high_f': expected 1, got 0",
+        ),
+        (
+            "safe fn test() {\n    let high_p = allocate_buffer\n}\n",
+            "Builtin function 'allocate_buffer' cannot be used as a value",
+        ),
+        (
+            "safe fn test() {\n    let high_same = one == one\n}\n",
+            "Function values cannot be compared: fn(u8) -> u8",
+        ),
+    ];
+    for (body, expected) in cases {
+        let err = run_pipeline(&format!("{defs}{body}")).expect_err(expected);
+        assert!(err.contains(expected), "{err}");
+    }
+}
+
+#[test]
+fn test_closures_and_capture_modes() {
+    let code = r#"
+safe fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+raw fn peek(raw_x: u8) -> u8 {
+    raw_x
+}
+
+safe fn test() {
+    let high_table = list_new()
+    let high_limit: u8 = 10
+    let high_small = list_filter_u8(&high_table, |high_x| high_x < high_limit)
+    let high_lookup = |high_i: usize| list_get_u8(&high_table, high_i)
+    let high_first = high_lookup(0)
+    let high_same = apply(|high_x| high_x, 1)
+    let high_id: fn(u8) -> u8 = |high_x| high_x
+    let high_mapped = list_map_u8(&high_table, high_id)
+    unsafe {
+        let raw_v: u8 = 1
+        let raw_f = |high_x: u8| high_x == raw_v
+        let raw_hit = raw_f(1)
+    }
+    let high_g = |high_x: u8| unsafe { peek(high_x) }
+    let high_peeked = apply(high_g, 1)
+}
+"#;
+    run_pipeline(code).expect("closures type check");
+
+    let defs = "safe fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {\n    high_f(high_x)\n}\n\nraw fn peek(raw_x: u8) -> u8 {\n    raw_x\n}\n\n";
+    let cases = [
+        (
+            "safe fn test() {\n    let high_s = string_from_list(&list_new())\n    let high_f = |high_x: u8| high_s\n}\n",
+            "Closure moves 'high_s' of non-Copy type core::types::String; capture it by reference with '&high_s'",
+        ),
+        (
+            "safe fn test() {\n    let high_l = list_new()\n    let high_f = |high_x: u8| list_push_u8(&mut high_l, high_x)\n}\n",
+            "Closures cannot capture 'high_l' by mutable reference",
+        ),
+        (
+            "safe fn test() {\n    let high_f = |high_x| high_x\n}\n",
+            "Cannot infer the type of closure parameter 'high_x'; annotate it",
+        ),
+        (
+            "safe fn test() {\n    let high_y: u8 = 2\n    let high_v = apply(|high_x| high_y, 1)\n}\n",
+            "expected fn(u8) -> u8, got closure(u8) -> u8 (a closure that captures bindings cannot be used as a plain 'fn')",
+        ),
+        (
+            "safe fn test() {\n    let high_l = list_new()\n    unsafe {\n        let raw_v: u8 = 1\n        let raw_n = list_len(&list_map_u8(&high_l, |high_x| raw_v))\n    }\n}\n",
+            "expected closure(u8) -> u8, got raw closure(u8) -> u8",
+        ),
+        (
+            "safe fn test() {\n    let high_h = unsafe {\n        let raw_v: u8 = 1\n        |high_x: u8| high_x == raw_v\n    }\n}\n",
+            "'high_h' cannot hold a raw function (raw closure(u8) -> bool)",
+        ),
+        (
+            "safe fn test() {\n    unsafe {\n        let raw_n = apply(|high_x: u8| peek(high_x), 1)\n    }\n}\n",
+            "Closure calls raw function 'peek' outside an unsafe block of its body, so it is a raw closure and cannot be used as fn(u8) -> u8",
+        ),
+        (
+            "safe fn test() {\n    for high_i in 0..3 {\n        let high_f = |high_x: u8| unsafe {\n            break\n        }\n    }\n}\n",
+            "break/continue can only be used inside for-loops",
+        ),
+    ];
+    for (body, expected) in cases {
+        let err = run_pipeline(&format!("{defs}{body}")).expect_err(expected);
+        assert!(err.contains(expected), "{err}");
+    }
+}
+
 #[test]
 fn test_return_type_mismatch_is_error() {
     let code = r#"
@@ -145,6 +539,212 @@ safe fn test() {
     assert!(run_pipeline(code).is_ok());
 }
 
+#[test]
+fn test_typed_buffer_access_builtins() {
+    let code = r#"
+safe fn test() {
+    let high_buf = allocate_buffer(8)
+    buffer_write_u32_be(high_buf, 0, 7)
+    let high_word: u32 = buffer_read_u32_be(high_buf, 0)
+    buffer_fill(high_buf, 4, 4, 0)
+    buffer_copy_from(high_buf, 4, high_buf, 0, 4)
+    unsafe {
+        let raw_buf = raw_alloc(8)
+        raw_write_u16_le(raw_buf, 0, 513)
+        let raw_half: u16 = raw_read_u16_le(raw_buf, 0)
+        raw_fill(raw_buf, 2, 6, 255)
+        raw_deallocate(raw_buf)
+    }
+    deallocate_buffer(high_buf)
+}
+"#;
+    assert!(run_pipeline(code).is_ok());
+}
+
+#[test]
+fn test_typed_buffer_read_rejects_wrong_width() {
+    let code = r#"
+safe fn test() {
+    let high_buf = allocate_buffer(8)
+    let high_word: u16 = buffer_read_u32_le(high_buf, 0)
+}
+"#;
+    let err = run_pipeline(code).expect_err("width mismatch should fail");
+    assert!(err.contains("Type Mismatch"));
+}
+
+#[test]
+fn test_high_view_builtins() {
+    let code = r#"
+safe fn parse_header(high_hdr: HighView) -> u16 {
+    view_read_u16_be(high_hdr, 0)
+}
+
+safe fn test() {
+    let high_buf = allocate_buffer(8)
+    let high_all = view_of(high_buf)
+    let high_header = view_head(high_all, 2)
+    let high_payload = view_tail(high_all, 2)
+    let high_kind = parse_header(high_header)
+    view_fill(high_payload, 0)
+    deallocate_buffer(high_buf)
+}
+"#;
+    assert!(run_pipeline(code).is_ok());
+}
+
+#[test]
+fn test_validate_raw_with_accepts_raw_fn_predicate() {
+    let code = r#"
+raw fn is_packet(raw_pkt: RawPtr, raw_len: usize) -> bool {
+    raw_read(raw_pkt, 0) == 1
+}
+
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_res = validate_raw_with(raw_x, is_packet)
+        if validation_is_ok(&validated_res) {
+            let validated_x = validation_unwrap(validated_res)
+            let high_x = into_high(validated_x)
+        } else {
+            let raw_code: i32 = memory_error_code(validation_error(&validated_res))
+        }
+    }
+}
+"#;
+    assert!(run_pipeline(code).is_ok());
+}
+
+#[test]
+fn test_validate_raw_with_rejects_predicate_with_wrong_signature() {
+    let code = r#"
+raw fn is_packet(raw_pkt: RawPtr) -> bool {
+    raw_read(raw_pkt, 0) == 1
+}
+
+safe fn test() {
+    unsafe {
+        let raw_x = raw_alloc(4)
+        let validated_res = validate_raw_with(raw_x, is_packet)
+    }
+}
+"#;
+    let err = run_pipeline(code).expect_err("predicate signature mismatch should fail");
+    assert!(err.contains("fn(core::memory::raw::RawPtr, usize) -> bool"));
+}
+
+#[test]
+fn test_try_memory_results_are_checked() {
+    let code = r#"
+safe fn test() {
+    let high_res = try_allocate_buffer(4)
+    if result_is_ok(high_res) {
+        let high_buf = result_unwrap(high_res)
+        let high_byte: Result<u8, MemoryError> = try_read_u8(high_buf, 9)
+        if result_is_err(high_byte) {
+            let high_code: i32 = memory_error_code(result_unwrap_err(high_byte))
+        }
+        let high_freed = try_deallocate_buffer(high_buf)
+        if result_is_err(high_freed) {
+            print("free failed")
+        }
+    }
+}
+"#;
+    let result = run_pipeline(code);
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_rebound_and_destructured_results_are_checked_through_the_new_names() {
+    let code = r#"
+safe fn mk() -> (Result<HighPtr, MemoryError>, u8) {
+    (try_allocate_buffer(4), 1)
+}
+
+safe fn test() {
+    let high_res = try_allocate_buffer(4)
+    let high_moved = high_res
+    let high_ok = result_is_ok(high_moved)
+    let high_pair = (try_allocate_buffer(4), 1)
+    let (high_first, high_n) = high_pair
+    let high_first_ok = result_is_ok(high_first)
+    let (high_made, high_m) = mk()
+    let high_made_ok = result_is_ok(high_made)
+}
+"#;
+    let result = run_pipeline(code);
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_discarded_try_result_is_error() {
+    let code = r#"
+safe fn test() {
+    let high_buf = allocate_buffer(4)
+    try_deallocate_buffer(high_buf)
+}
+"#;
+    let err = run_pipeline(code).expect_err("discarded result should fail");
+    assert!(err.contains("Unhandled Result"));
+    assert!(err.contains("try_deallocate_buffer"));
+}
+
+#[test]
+fn test_unchecked_try_binding_is_error() {
+    let code = r#"
+safe fn test() {
+    let high_buf = allocate_buffer(4)
+    let high_done = try_write_u8(high_buf, 0, 1)
+}
+"#;
+    let err = run_pipeline(code).expect_err("unchecked result should fail");
+    assert!(err.contains("'high_done'"));
+}
+
+#[test]
+fn test_shadowed_try_binding_is_still_unchecked() {
+    let code = r#"
+safe fn test() {
+    let high_buf = allocate_buffer(4)
+    let high_done = try_write_u8(high_buf, 0, 1)
+    let high_done = 1
+    if high_done == 1 {
+    }
+}
+"#;
+    let err = run_pipeline(code).expect_err("the shadowed result is never read");
+    assert!(err.contains("'high_done'"));
+}
+
+#[test]
+fn test_block_bindings_shadow_only_until_the_block_ends() {
+    let code = r#"
+safe fn test() {
+    let high_x = "outer"
+    if true {
+        let high_x = 1
+        let high_y: i32 = high_x
+    }
+    let high_len: usize = string_len(&high_x)
+}
+"#;
+    let result = run_pipeline(code);
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_result_accessor_type_mismatch_is_error() {
+    let code = r#"
+safe fn test() {
+    let high_res = try_allocate_buffer(4)
+    let high_byte: u8 = result_unwrap(high_res)
+}
+"#;
+    assert!(run_pipeline(code).is_err());
+}
+
 #[test]
 fn test_unknown_type_is_error() {
     let code = r#"
@@ -304,3 +904,21 @@ safe fn test() {
 "#;
     assert!(run_pipeline(code).is_ok());
 }
+
+#[test]
+fn test_test_functions_take_no_arguments() {
+    let ok = r#"
+test fn lengths() {
+    assert_eq(string_len(&"ab"), 2)
+}
+"#;
+    assert!(run_pipeline(ok).is_ok());
+
+    let code = r#"
+test fn lengths(high_n: usize) {
+    assert(true)
+}
+"#;
+    let err = run_pipeline(code).expect_err("test fn with arguments");
+    assert!(err.contains("Test function 'lengths' must take no arguments and return nothing"));
+}
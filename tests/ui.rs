@@ -0,0 +1,139 @@
+// cyan-cs
+//
+// Copyright (c) 2026 cyan-cs
+//
+// This software is licensed under the MIT License.
+// See: https://opensource.org/licenses/MIT
+
+//! Golden-file tests for compiler diagnostics and generated code.
+//!
+//! Every `tests/ui/**/<name>.safe` is built with the real `safe build` in a
+//! scratch project. Its stderr is compared with `<name>.stderr` and, when the
+//! build succeeds, the generated Rust with `<name>.rs`. A missing snapshot
+//! means "expected empty". An optional `<name>.toml` becomes the scratch
+//! project's `Safe.toml`.
+//!
+//! `cargo test --test ui -- --bless` rewrites the snapshots instead.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+const UI_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui");
+
+struct Outcome {
+    stderr: String,
+    generated: String,
+}
+
+fn main() -> ExitCode {
+    let bless = env::args().any(|arg| arg == "--bless");
+    let filter = env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--"))
+        .unwrap_or_default();
+
+    let mut cases = Vec::new();
+    collect_cases(Path::new(UI_DIR), &mut cases);
+    cases.sort();
+    cases.retain(|case| case.to_string_lossy().contains(&filter));
+
+    let scratch = env::temp_dir().join(format!("safe_ui_{}", std::process::id()));
+    let mut failed = Vec::new();
+    for case in &cases {
+        let name = case.strip_prefix(UI_DIR).unwrap_or(case).display();
+        let outcome = build(case, &scratch.join(case.file_stem().expect("case name")));
+        let snapshots = [
+            (case.with_extension("stderr"), outcome.stderr),
+            (case.with_extension("rs"), outcome.generated),
+        ];
+        if bless {
+            for (path, actual) in &snapshots {
+                write_snapshot(path, actual);
+            }
+            println!("blessed {name}");
+        } else if let Some(diff) = snapshots
+            .iter()
+            .find_map(|(path, actual)| compare(path, actual))
+        {
+            println!("ui {name} ... FAILED\n{diff}");
+            failed.push(name.to_string());
+        } else {
+            println!("ui {name} ... ok");
+        }
+    }
+    let _ = fs::remove_dir_all(&scratch);
+
+    println!(
+        "\nui result: {} passed; {} failed",
+        cases.len() - failed.len(),
+        failed.len()
+    );
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        println!("rerun with `cargo test --test ui -- --bless` to accept the new output");
+        ExitCode::FAILURE
+    }
+}
+
+fn collect_cases(dir: &Path, cases: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("read {}: {e}", dir.display()));
+    for entry in entries {
+        let path = entry.expect("dir entry").path();
+        if path.is_dir() {
+            collect_cases(&path, cases);
+        } else if path.extension().is_some_and(|ext| ext == "safe") {
+            cases.push(path);
+        }
+    }
+}
+
+/// Builds `case` as the entry of a fresh project in `dir`. Paths under `dir`
+/// are replaced by `$DIR` in stderr.
+fn build(case: &Path, dir: &Path) -> Outcome {
+    let _ = fs::remove_dir_all(dir);
+    fs::create_dir_all(dir).expect("create scratch project");
+    let entry = dir.join(case.file_name().expect("case file"));
+    fs::copy(case, &entry).expect("copy case");
+    let manifest = fs::read_to_string(case.with_extension("toml")).unwrap_or_default();
+    fs::write(dir.join("Safe.toml"), manifest).expect("write Safe.toml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_safe"))
+        .arg("build")
+        .arg(&entry)
+        .current_dir(dir)
+        .output()
+        .expect("run safe build");
+
+    let dir = dir.canonicalize().expect("canonical scratch dir");
+    let stderr = String::from_utf8_lossy(&output.stderr).replace(&*dir.to_string_lossy(), "$DIR");
+    let generated = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("Build successful: "))
+        .map(|path| fs::read_to_string(path).expect("read generated Rust"))
+        .unwrap_or_default();
+    Outcome { stderr, generated }
+}
+
+/// `None` if `path` holds `actual` (a missing file holds ""), otherwise both
+/// versions for the report.
+fn compare(path: &Path, actual: &str) -> Option<String> {
+    let expected = fs::read_to_string(path).unwrap_or_default();
+    if expected == actual {
+        return None;
+    }
+    Some(format!(
+        "--- expected {}\n{expected}--- actual\n{actual}---",
+        path.display()
+    ))
+}
+
+fn write_snapshot(path: &Path, actual: &str) {
+    if actual.is_empty() {
+        let _ = fs::remove_file(path);
+    } else {
+        fs::write(path, actual).unwrap_or_else(|e| panic!("write {}: {e}", path.display()));
+    }
+}
@@ -0,0 +1,11 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn main() {
+    let high_x = 1;
+    for high_i in 0..3 {
+        let high_x = high_i;
+    }
+}
+
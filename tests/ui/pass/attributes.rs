@@ -0,0 +1,17 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+/// Length of a fixed name.
+#[inline]
+pub fn name_len() -> usize {
+    safe_lang::core::types::string_len(&safe_lang::core::types::String::from("safe"))
+}
+
+pub fn main() {
+    let len = name_len();
+    if true {
+        let len = name_len();
+    }
+}
+
@@ -0,0 +1,13 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn main() {
+    let high_big: i32 = 300;
+    let high_small: u8 = 7;
+    let high_wide = <u64 as std::convert::TryFrom<_>>::try_from(high_small).expect("lossless integer cast");
+    let high_checked = safe_lang::core::types::checked_cast::<u8, _>(high_big);
+    { safe_lang::core::types::print_any(&(safe_lang::core::types::option_is_some(high_checked))); std::println!(); };
+    { safe_lang::core::types::print_any(&(safe_lang::core::types::wrapping_cast::<u8, _>(high_big))); safe_lang::core::types::print_any(&(safe_lang::core::types::String::from(" "))); safe_lang::core::types::print_any(&(safe_lang::core::types::saturating_cast::<i8, _>(high_big))); safe_lang::core::types::print_any(&(safe_lang::core::types::String::from(" "))); safe_lang::core::types::print_any(&(high_wide)); std::println!(); };
+}
+
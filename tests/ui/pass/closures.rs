@@ -0,0 +1,29 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn keep_below(high_xs: &safe_lang::core::types::List, high_limit: u8) -> safe_lang::core::types::List {
+    safe_lang::core::types::list_filter_u8(high_xs, move |high_x| high_x < high_limit)
+}
+
+pub fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+pub fn main() {
+    let high_table = safe_lang::core::types::string_to_list(&safe_lang::core::types::String::from("abcdef"));
+    let high_lookup = { let high_table = &high_table; move |high_i: u8| safe_lang::core::types::option_unwrap_u8(safe_lang::core::types::list_get_u8(high_table, <usize as std::convert::TryFrom<_>>::try_from(high_i).expect("lossless integer cast"))) };
+    { safe_lang::core::types::print_any(&(high_lookup(1))); std::println!(); };
+    let high_picked = safe_lang::core::types::list_map_u8(&safe_lang::core::types::string_to_list(&safe_lang::core::types::String::from("ab")), { let high_lookup = &high_lookup; move |high_c| high_lookup(2) });
+    { safe_lang::core::types::print_any(&(safe_lang::core::types::list_len(&high_picked))); std::println!(); };
+    { safe_lang::core::types::print_any(&(safe_lang::core::types::list_len(&keep_below(&high_table, 100)))); std::println!(); };
+    { safe_lang::core::types::print_any(&(apply(move |high_x| high_x, 9))); std::println!(); };
+    let high_is_b: fn(u8) -> bool = move |high_x| high_x == 98;
+    { safe_lang::core::types::print_any(&(safe_lang::core::types::list_len(&safe_lang::core::types::list_filter_u8(&high_table, high_is_b)))); std::println!(); };
+    unsafe {
+        let raw_v: u8 = 7;
+        let raw_f = move |high_x: u8| high_x == raw_v;
+        { safe_lang::core::types::print_any(&(raw_f(7))); std::println!(); }
+    };
+}
+
@@ -0,0 +1,44 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub enum PacketKind {
+    Ping,
+    Data(u16),
+    Error(i32, bool),
+}
+
+pub fn code(high_kind: PacketKind) -> i32 {
+    match high_kind {
+        PacketKind::Ping => {
+            0
+        }
+        PacketKind::Data(high_len) => {
+            <i32 as std::convert::TryFrom<_>>::try_from(high_len).expect("lossless integer cast")
+        }
+        PacketKind::Error(high_code, _) => {
+            high_code
+        }
+    }
+}
+
+pub fn main() {
+    let high_kind = PacketKind::Data(512);
+    { safe_lang::core::types::print_any(&(code(high_kind))); std::println!(); };
+    { safe_lang::core::types::print_any(&(code(PacketKind::Error(-3, true)))); std::println!(); };
+    if let PacketKind::Data(high_len) = PacketKind::Data(7) {
+        { safe_lang::core::types::print_any(&(safe_lang::core::types::String::from("data "))); safe_lang::core::types::print_any(&(high_len)); std::println!(); };
+    } else {
+        { safe_lang::core::types::print_any(&(safe_lang::core::types::String::from("other"))); std::println!(); };
+    }
+    for high_i in 0..3 {
+        match PacketKind::Ping {
+            PacketKind::Ping => {
+                break;
+            }
+            _ => {
+            }
+        };
+    }
+}
+
@@ -0,0 +1,45 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn double(high_x: u8) -> u8 {
+    high_x
+}
+
+pub fn is_small(high_x: u8) -> bool {
+    high_x < 10
+}
+
+pub unsafe fn peek(raw_p: safe_lang::core::memory::raw::RawPtr) -> u8 {
+    safe_lang::core::memory::raw::read(raw_p, 0)
+}
+
+pub fn apply(high_f: fn(u8) -> u8, high_x: u8) -> u8 {
+    high_f(high_x)
+}
+
+pub fn count_if(high_pred: fn(u8) -> bool, high_limit: u8) -> u8 {
+    let high_buf = safe_lang::type_system::High::new(safe_lang::core::memory::safe::allocate_buffer(1));
+    for high_i in 0..high_limit {
+        if high_pred(high_i) {
+            safe_lang::core::memory::safe::write_u8(high_buf.unwrap(), 0, high_i);
+        }
+    }
+    safe_lang::core::memory::safe::read_u8(high_buf.unwrap(), 0)
+}
+
+pub fn main() {
+    let high_f = double;
+    { safe_lang::core::types::print_any(&(apply(high_f, 7))); std::println!(); };
+    { safe_lang::core::types::print_any(&(count_if(is_small, 20))); std::println!(); };
+    let high_g: fn(u8) -> bool = is_small;
+    { safe_lang::core::types::print_any(&(high_g(3))); std::println!(); };
+    unsafe {
+        let raw_p = safe_lang::type_system::Raw::new(safe_lang::core::memory::raw::alloc(1));
+        safe_lang::core::memory::raw::write(raw_p.unwrap(), 0, 42);
+        let raw_peek = peek;
+        let raw_v = raw_peek(raw_p.unwrap());
+        { safe_lang::core::types::print_any(&(raw_v)); std::println!(); }
+    };
+}
+
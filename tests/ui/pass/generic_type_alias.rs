@@ -0,0 +1,8 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn first(high_r: safe_lang::core::types::Result<u8, i32>) -> bool {
+    safe_lang::core::types::result_is_ok_u8_i32(high_r)
+}
+
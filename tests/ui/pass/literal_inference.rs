@@ -0,0 +1,17 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn main() {
+    let high_n = 4;
+    let high_buf = safe_lang::type_system::High::new(safe_lang::core::memory::safe::allocate_buffer(high_n));
+    for high_i in 0..high_n {
+        safe_lang::core::memory::safe::write_u8(high_buf.unwrap(), high_i, 1);
+    }
+    safe_lang::core::memory::safe::deallocate_buffer(high_buf.unwrap());
+}
+
+pub fn len() -> usize {
+    2
+}
+
@@ -0,0 +1,17 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn demo() {
+    let high_size: usize = safe_lang::core::types::string_len(&safe_lang::core::types::String::from("abcdefgh"));
+    let high_buf = safe_lang::type_system::High::new(safe_lang::core::memory::safe::allocate_buffer(high_size));
+    unsafe {
+        let raw_ptr = safe_lang::type_system::Raw::new(safe_lang::core::memory::raw::alloc(high_size));
+        safe_lang::core::memory::raw::write(raw_ptr.unwrap(), 0, 65);
+        safe_lang::core::memory::raw::write(raw_ptr.unwrap(), 1, 66);
+        let validated_ptr = safe_lang::type_system::validate_raw_ptr(raw_ptr);
+        let high_from_raw = safe_lang::type_system::into_high_ptr(validated_ptr);
+    };
+    safe_lang::core::memory::safe::deallocate_buffer(high_buf.unwrap());
+}
+
@@ -0,0 +1,10 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn main() {
+    let high_ptr = unsafe {
+        safe_lang::type_system::Raw::new(safe_lang::core::memory::raw::alloc(4))
+    };
+}
+
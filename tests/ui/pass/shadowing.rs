@@ -0,0 +1,14 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn main() {
+    let high_x = 1;
+    let high_x = safe_lang::core::types::String::from("now a string");
+    if true {
+        let high_x = 2;
+        { safe_lang::core::types::print_any(&(high_x)); std::println!(); };
+    }
+    { safe_lang::core::types::print_any(&(high_x)); std::println!(); };
+}
+
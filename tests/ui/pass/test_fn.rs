@@ -0,0 +1,14 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn two() -> usize {
+    safe_lang::core::types::string_len(&safe_lang::core::types::String::from("ab"))
+}
+
+#[test]
+pub fn two_is_two() {
+    safe_lang::core::testing::assert_eq(two(), 2, "test_fn.safe:6");
+    safe_lang::core::testing::expect_panic(|| safe_lang::core::memory::safe::read_u8(safe_lang::core::memory::safe::allocate_buffer(1), 4), "test_fn.safe:7");
+}
+
@@ -0,0 +1,14 @@
+// Generated SAFE? Code
+// This is a Rust transpilation of SAFE? source
+// Requires the `safe_lang` runtime crate.
+
+pub fn read_tag(high_len: usize) -> (u8, usize) {
+    (7, high_len)
+}
+
+pub fn main() {
+    let (high_tag, high_used) = read_tag(3);
+    let high_pair: (u8, (bool, i64)) = (high_tag, (true, -5));
+    { safe_lang::core::types::print_any(&(high_tag)); safe_lang::core::types::print_any(&(safe_lang::core::types::String::from(" "))); safe_lang::core::types::print_any(&(high_used)); safe_lang::core::types::print_any(&(safe_lang::core::types::String::from(" "))); safe_lang::core::types::print_any(&(high_pair.1.1)); std::println!(); };
+}
+